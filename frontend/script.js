@@ -176,7 +176,17 @@ class NFTAuctionApp {
 
         try {
             // Create the extrinsic
-            const tx = this.api.tx.template.listNftForAuction(collectionId, itemId);
+            // No reserve, increment, duration, buy-now price or bidder gate, settled in the native currency
+            const settings = {
+                reservePrice: null,
+                minIncrement: null,
+                duration: null,
+                buyNowPrice: null,
+                access: null,
+            };
+            const tx = this.api.tx.template.listNftForAuction(
+                collectionId, itemId, settings, { Native: null }
+            );
 
             // Sign and send transaction
             const hash = await tx.signAndSend(this.account.address, { 
@@ -217,10 +227,10 @@ class NFTAuctionApp {
                     const [collectionId, itemId] = key.args;
                     
                     // Calculate time left
-                    const startBlock = auctionInfo.start_block;
-                    const timeoutBlocks = this.api.consts.template.auctionTimeoutBlocks;
+                    const startBlock = auctionInfo.startBlock.toNumber();
+                    const endBlock = auctionInfo.endBlock.toNumber();
                     const currentBlock = this.currentBlock || 0;
-                    const blocksLeft = Math.max(0, (startBlock + timeoutBlocks) - currentBlock);
+                    const blocksLeft = Math.max(0, endBlock - currentBlock);
                     const timeLeft = this.calculateTimeLeft(blocksLeft);
 
                     auctions.push({
@@ -41,6 +41,7 @@ where
         AccountId,
         Balance,
         solochain_template_runtime::BlockNumber,
+        solochain_template_runtime::AssetId,
     >,
     P: TransactionPool + 'static,
 {
@@ -27,6 +27,9 @@ pub mod pallet {
         #[pallet::constant]
         type MaxHardwareHistoryEntries: Get<u32>;
 
+        /// Origin that clears the hardware history
+        type AdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
         type PalletId: Get<PalletId>;
 
         type WeightInfo: WeightInfo;
@@ -138,11 +141,11 @@ pub mod pallet {
             Ok(())
         }
 
-        /// Clear hardware history (root only)
+        /// Clear hardware history (admin origin only)
         #[pallet::call_index(1)]
         #[pallet::weight(T::WeightInfo::clear_hardware_history())]
         pub fn clear_hardware_history(origin: OriginFor<T>) -> DispatchResult {
-            ensure_root(origin)?;
+            T::AdminOrigin::ensure_origin(origin)?;
 
             let history = Self::hardware_history();
             let count = history.len() as u32;
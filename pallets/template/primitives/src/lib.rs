@@ -1,8 +1,11 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use codec::{Encode, Decode, MaxEncodedLen, DecodeWithMemTracking};
-use frame_support::pallet_prelude::RuntimeDebug;
-use sp_runtime::{BoundedVec, traits::ConstU32};
+use frame_support::{
+    pallet_prelude::RuntimeDebug, CloneNoBound, DefaultNoBound, EqNoBound, PartialEqNoBound,
+    RuntimeDebugNoBound,
+};
+use sp_runtime::{BoundedVec, Permill, traits::{ConstU32, Get}};
 use scale_info::TypeInfo;
 
 #[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
@@ -17,8 +20,349 @@ use scale_info::TypeInfo;
     MaxEncodedLen,
     TypeInfo,
 )]
-pub struct BatchListingInfo<CollectionId, ItemId, Balance, BlockNumber> {
+pub struct BatchListingInfo<CollectionId, ItemId, Balance, BlockNumber, AssetId> {
     pub nfts: BoundedVec<(CollectionId, ItemId), ConstU32<10>>,
     pub min_bid: Option<Balance>,
     pub custom_timeout: Option<BlockNumber>,
+    pub asset: SettlementAsset<AssetId>,
+}
+
+/// What bids are placed and sales are paid in.
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[derive(
+    Clone,
+    Copy,
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    Eq,
+    PartialEq,
+    RuntimeDebug,
+    MaxEncodedLen,
+    TypeInfo,
+)]
+pub enum SettlementAsset<AssetId> {
+    /// The native currency of the chain
+    Native,
+    /// A fungible asset of `pallet_assets`
+    Asset(AssetId),
+}
+
+/// Minimum amount by which a new bid must exceed the current highest bid.
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[derive(
+    Clone,
+    Copy,
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    Eq,
+    PartialEq,
+    RuntimeDebug,
+    MaxEncodedLen,
+    TypeInfo,
+)]
+pub enum BidIncrement<Balance> {
+    /// A fixed amount added on top of the highest bid
+    Absolute(Balance),
+    /// A percentage (0-100) of the highest bid
+    Percentage(u8),
+}
+
+/// How the price of an auction is discovered.
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[derive(
+    Clone,
+    Copy,
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    Eq,
+    PartialEq,
+    RuntimeDebug,
+    MaxEncodedLen,
+    TypeInfo,
+)]
+pub enum AuctionKind<Balance> {
+    /// Ascending bids, the highest bidder wins when the auction ends
+    English,
+    /// The price falls every block from `start_price` down to `floor_price`, the first bid
+    /// at or above the current price wins
+    Dutch {
+        start_price: Balance,
+        floor_price: Balance,
+        decay_per_block: Balance,
+    },
+    /// Bids are committed as hashes and revealed at the end, the best revealed bid wins
+    Sealed(SealedBidPricing),
+}
+
+/// What the winner of a sealed-bid auction pays.
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[derive(
+    Clone,
+    Copy,
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    Eq,
+    PartialEq,
+    RuntimeDebug,
+    MaxEncodedLen,
+    TypeInfo,
+)]
+pub enum SealedBidPricing {
+    /// The winner pays their own bid
+    FirstPrice,
+    /// The winner pays the second highest revealed bid (Vickrey)
+    SecondPrice,
+}
+
+/// Who may bid on a gated auction.
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[derive(
+    CloneNoBound,
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    EqNoBound,
+    PartialEqNoBound,
+    RuntimeDebugNoBound,
+    MaxEncodedLen,
+    TypeInfo,
+)]
+#[scale_info(skip_type_params(MaxAllowlistLen))]
+#[codec(mel_bound(AccountId: MaxEncodedLen, CollectionId: MaxEncodedLen))]
+#[cfg_attr(
+    feature = "std",
+    serde(bound(
+        serialize = "AccountId: serde::Serialize, CollectionId: serde::Serialize",
+        deserialize = "AccountId: serde::Deserialize<'de>, CollectionId: serde::Deserialize<'de>"
+    ))
+)]
+pub enum BidderAccess<AccountId, CollectionId, MaxAllowlistLen: Get<u32>>
+where
+    AccountId: Clone + Eq + core::fmt::Debug,
+    CollectionId: Clone + Eq + core::fmt::Debug,
+{
+    /// Only the listed accounts, at most `MaxAllowlistLen` of them
+    Allowlist(BoundedVec<AccountId, MaxAllowlistLen>),
+    /// Only accounts owning an NFT of the collection
+    CollectionHolders(CollectionId),
+    /// Only accounts that have a profile
+    ProfileHolders,
+}
+
+/// Optional settings of an English auction listing, all unset by default.
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[derive(
+    CloneNoBound,
+    DefaultNoBound,
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    EqNoBound,
+    PartialEqNoBound,
+    RuntimeDebugNoBound,
+    MaxEncodedLen,
+    TypeInfo,
+)]
+#[scale_info(skip_type_params(MaxAllowlistLen))]
+#[codec(mel_bound(
+    AccountId: MaxEncodedLen,
+    CollectionId: MaxEncodedLen,
+    Balance: MaxEncodedLen,
+    BlockNumber: MaxEncodedLen
+))]
+#[cfg_attr(
+    feature = "std",
+    serde(bound(
+        serialize = "AccountId: serde::Serialize, CollectionId: serde::Serialize, \
+            Balance: serde::Serialize, BlockNumber: serde::Serialize",
+        deserialize = "AccountId: serde::Deserialize<'de>, CollectionId: serde::Deserialize<'de>, \
+            Balance: serde::Deserialize<'de>, BlockNumber: serde::Deserialize<'de>"
+    ))
+)]
+pub struct AuctionSettings<AccountId, CollectionId, Balance, BlockNumber, MaxAllowlistLen: Get<u32>>
+where
+    AccountId: Clone + Eq + core::fmt::Debug,
+    CollectionId: Clone + Eq + core::fmt::Debug,
+    Balance: Clone + Eq + core::fmt::Debug,
+    BlockNumber: Clone + Eq + core::fmt::Debug,
+{
+    /// Lowest highest bid the NFT sells for
+    pub reserve_price: Option<Balance>,
+    /// Minimum raise over the highest bid
+    pub min_increment: Option<BidIncrement<Balance>>,
+    /// Blocks the auction runs for, the auction timeout if unset
+    pub duration: Option<BlockNumber>,
+    /// Price at which the NFT can be bought outright
+    pub buy_now_price: Option<Balance>,
+    /// Who may bid, anyone if unset
+    pub access: Option<BidderAccess<AccountId, CollectionId, MaxAllowlistLen>>,
+}
+
+/// An NFT listed for sale at a fixed price.
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[derive(
+    Clone,
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    Eq,
+    PartialEq,
+    RuntimeDebug,
+    MaxEncodedLen,
+    TypeInfo,
+)]
+pub struct ListingInfo<AccountId, Balance, AssetId> {
+    /// The account selling the NFT
+    pub seller: AccountId,
+    /// The price a buyer pays to take the NFT
+    pub price: Balance,
+    /// What the price is paid in
+    pub asset: SettlementAsset<AssetId>,
+}
+
+/// A standing offer for an NFT, backed by held funds of the buyer.
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[derive(
+    Clone,
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    Eq,
+    PartialEq,
+    RuntimeDebug,
+    MaxEncodedLen,
+    TypeInfo,
+)]
+pub struct OfferInfo<Balance, BlockNumber> {
+    /// The amount held for the offer
+    pub amount: Balance,
+    /// The block from which the offer can no longer be accepted
+    pub expires_at: BlockNumber,
+}
+
+/// A completed auction sale, kept in the sales history of the NFT.
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[derive(
+    Clone,
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    Eq,
+    PartialEq,
+    RuntimeDebug,
+    MaxEncodedLen,
+    TypeInfo,
+)]
+pub struct SaleRecord<AccountId, Balance, BlockNumber, AssetId> {
+    /// The account that sold the NFT
+    pub seller: AccountId,
+    /// The account that bought the NFT
+    pub buyer: AccountId,
+    /// The price the NFT sold for
+    pub price: Balance,
+    /// What the price was paid in
+    pub asset: SettlementAsset<AssetId>,
+    /// The block the sale was settled in
+    pub sold_at: BlockNumber,
+}
+
+/// Running summary of the auction sales of a collection in one settlement asset.
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[derive(
+    Clone,
+    Default,
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    Eq,
+    PartialEq,
+    RuntimeDebug,
+    MaxEncodedLen,
+    TypeInfo,
+)]
+pub struct CollectionSummary<Balance> {
+    /// The price of the latest sale
+    pub last_price: Balance,
+    /// The lowest price any NFT of the collection sold for
+    pub floor: Balance,
+    /// The sum of all sale prices
+    pub volume: Balance,
+    /// The number of sales
+    pub sales: u32,
+}
+
+/// Auction activity of an account in one settlement asset.
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[derive(
+    Clone,
+    Default,
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    Eq,
+    PartialEq,
+    RuntimeDebug,
+    MaxEncodedLen,
+    TypeInfo,
+)]
+pub struct AccountStats<Balance> {
+    /// The sum of the prices paid for NFTs won
+    pub volume_bought: Balance,
+    /// The sum of the prices NFTs were sold for
+    pub volume_sold: Balance,
+    /// The number of auctions won
+    pub auctions_won: u32,
+}
+
+/// Floor, median and volume of the auction sales of a collection over a window of blocks.
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[derive(
+    Clone,
+    Default,
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    Eq,
+    PartialEq,
+    RuntimeDebug,
+    MaxEncodedLen,
+    TypeInfo,
+)]
+pub struct CollectionStats<Balance> {
+    /// The lowest sale price
+    pub floor: Balance,
+    /// The median sale price, over the sampled prices
+    pub median: Balance,
+    /// The sum of all sale prices
+    pub volume: Balance,
+    /// The number of sales
+    pub sales: u32,
+}
+
+/// Breakdown of a sale at a given price, quoted before bidding.
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[derive(
+    Clone,
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    Eq,
+    PartialEq,
+    RuntimeDebug,
+    MaxEncodedLen,
+    TypeInfo,
+)]
+pub struct FeeQuote<Balance> {
+    /// The marketplace fee rate the seller is charged
+    pub fee_rate: Permill,
+    /// The royalty paid to the collection's recipients
+    pub royalty: Balance,
+    /// The marketplace fee
+    pub fee: Balance,
+    /// What the seller receives
+    pub payout: Balance,
 }
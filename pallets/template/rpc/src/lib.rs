@@ -8,12 +8,16 @@ use sp_runtime::BoundedVec;
 use sp_runtime::traits::ConstU32;
 use sp_runtime::{
     traits::{Block as BlockT},
-    AccountId32,
+    AccountId32, Permill,
 };
 use std::sync::Arc;
 
 pub use pallet_template_runtime_api::AuctionApi as AuctionRuntimeApi;
-pub use pallet_template_runtime_api::{AuctionInfo, BatchListingInfo};
+pub use pallet_template_runtime_api::{
+    AccountStats, AuctionInfo, AuctionKind, AuctionSettings, BatchListingInfo, BidIncrement,
+    BidderAccess, CollectionStats, CollectionSummary, FeeQuote, ListingInfo, OfferInfo,
+    SaleRecord, SealedBidPricing, SettlementAsset,
+};
 
 use solochain_template_runtime::{RuntimeCall, TemplateCall};
 
@@ -26,7 +30,7 @@ fn to_rpc_error<E: std::fmt::Display>(e: E) -> ErrorObjectOwned {
 }
 
 #[rpc(client, server)]
-pub trait AuctionApi<BlockHash, CollectionId, ItemId, AccountId, Balance, BlockNumber> {
+pub trait AuctionApi<BlockHash, CollectionId, ItemId, AccountId, Balance, BlockNumber, AssetId> {
     /// Get auction information for a specific NFT
     #[method(name = "auction_getAuctionInfo")]
     fn get_auction_info(
@@ -34,7 +38,7 @@ pub trait AuctionApi<BlockHash, CollectionId, ItemId, AccountId, Balance, BlockN
         collection_id: CollectionId,
         item_id: ItemId,
         at: Option<BlockHash>,
-    ) -> RpcResult<Option<AuctionInfo<AccountId, Balance, BlockNumber>>>;
+    ) -> RpcResult<Option<AuctionInfo<AccountId, Balance, BlockNumber, AssetId>>>;
 
     /// Get all bids for a specific NFT auction
     #[method(name = "auction_getBids")]
@@ -45,6 +49,33 @@ pub trait AuctionApi<BlockHash, CollectionId, ItemId, AccountId, Balance, BlockN
         at: Option<BlockHash>,
     ) -> RpcResult<Vec<(AccountId, Balance)>>;
 
+    /// Get the `k` highest active bids for a specific NFT auction
+    #[method(name = "auction_getTopBids")]
+    fn get_top_bids(
+        &self,
+        collection_id: CollectionId,
+        item_id: ItemId,
+        k: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<(AccountId, Balance)>>;
+
+    /// Get the NFTs of the bundle auction a specific NFT is part of
+    #[method(name = "auction_getBundleItems")]
+    fn get_bundle_items(
+        &self,
+        collection_id: CollectionId,
+        item_id: ItemId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<(CollectionId, ItemId)>>;
+
+    /// Get the bids of an account that can be claimed back, by NFT
+    #[method(name = "auction_pendingRefunds")]
+    fn pending_refunds(
+        &self,
+        account: AccountId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<((CollectionId, ItemId), SettlementAsset<AssetId>, Balance)>>;
+
     /// Check if an NFT is currently in auction
     #[method(name = "auction_isInAuction")]
     fn is_in_auction(
@@ -54,13 +85,28 @@ pub trait AuctionApi<BlockHash, CollectionId, ItemId, AccountId, Balance, BlockN
         at: Option<BlockHash>,
     ) -> RpcResult<bool>;
 
-    /// Get current fee percentage
-    #[method(name = "auction_getFeePercentage")]
-    fn get_fee_percentage(&self, at: Option<BlockHash>) -> RpcResult<u8>;
+    /// Get the marketplace fee charged unless a fee tier or the collection sets another
+    #[method(name = "auction_getMarketFee")]
+    fn get_market_fee(&self, at: Option<BlockHash>) -> RpcResult<Permill>;
 
-    /// Get accumulated fees
+    /// Get the royalty, fee and seller payout of selling an NFT at `amount`
+    #[method(name = "auction_quoteFees")]
+    fn quote_fees(
+        &self,
+        collection_id: CollectionId,
+        item_id: ItemId,
+        amount: Balance,
+        seller: AccountId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<FeeQuote<Balance>>;
+
+    /// Get the fees accumulated in a settlement asset
     #[method(name = "auction_getAccumulatedFees")]
-    fn get_accumulated_fees(&self, at: Option<BlockHash>) -> RpcResult<NumberOrHex>;
+    fn get_accumulated_fees(
+        &self,
+        asset: SettlementAsset<AssetId>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<NumberOrHex>;
 
     /// Get all active auctions
     #[method(name = "auction_getActiveAuctions")]
@@ -70,15 +116,105 @@ pub trait AuctionApi<BlockHash, CollectionId, ItemId, AccountId, Balance, BlockN
     ) -> RpcResult<
         Vec<(
             (CollectionId, ItemId),
-            AuctionInfo<AccountId, Balance, BlockNumber>,
+            AuctionInfo<AccountId, Balance, BlockNumber, AssetId>,
         )>,
     >;
 
+    /// Get the smallest bid currently accepted for an active auction
+    #[method(name = "auction_getMinNextBid")]
+    fn get_min_next_bid(
+        &self,
+        collection_id: CollectionId,
+        item_id: ItemId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<NumberOrHex>>;
+
+    /// Get the fixed-price listing of a specific NFT
+    #[method(name = "auction_getListing")]
+    fn get_listing(
+        &self,
+        collection_id: CollectionId,
+        item_id: ItemId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<ListingInfo<AccountId, Balance, AssetId>>>;
+
+    /// Get all fixed-price listings
+    #[method(name = "auction_getActiveListings")]
+    fn get_active_listings(
+        &self,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<((CollectionId, ItemId), ListingInfo<AccountId, Balance, AssetId>)>>;
+
+    /// Get all standing offers on a specific NFT
+    #[method(name = "auction_getOffers")]
+    fn get_offers(
+        &self,
+        collection_id: CollectionId,
+        item_id: ItemId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<(AccountId, OfferInfo<Balance, BlockNumber>)>>;
+
+    /// Get the latest auction sales of a specific NFT, oldest first
+    #[method(name = "auction_getSalesHistory")]
+    fn get_sales_history(
+        &self,
+        collection_id: CollectionId,
+        item_id: ItemId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<SaleRecord<AccountId, Balance, BlockNumber, AssetId>>>;
+
+    /// Get the summary of the auction sales of a collection in a settlement asset
+    #[method(name = "auction_getCollectionSummary")]
+    fn get_collection_summary(
+        &self,
+        collection_id: CollectionId,
+        asset: SettlementAsset<AssetId>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<CollectionSummary<Balance>>;
+
+    /// Get the auction volume bought and sold and auctions won by an account
+    #[method(name = "auction_getAccountStats")]
+    fn get_account_stats(
+        &self,
+        account: AccountId,
+        asset: SettlementAsset<AssetId>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<AccountStats<Balance>>;
+
+    /// Get the floor, median and volume of the auction sales of a collection settled within
+    /// the last `window` blocks, per settlement asset
+    #[method(name = "auction_collectionStats")]
+    fn collection_stats(
+        &self,
+        collection_id: CollectionId,
+        window: BlockNumber,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<(SettlementAsset<AssetId>, CollectionStats<Balance>)>>;
+
     #[method(name = "auction_listNftForAuction")]
     fn list_nft_for_auction(
         &self,
         collection_id: u32,
         item_id: u32,
+        reserve_price: Option<u128>,
+        min_increment: Option<BidIncrement<u128>>,
+        duration: Option<u32>,
+        buy_now_price: Option<u128>,
+        asset: SettlementAsset<u32>,
+        access: Option<BidderAccess<AccountId32, u32, ConstU32<100>>>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<String>;
+
+    #[method(name = "auction_listNftForDutchAuction")]
+    fn list_nft_for_dutch_auction(
+        &self,
+        collection_id: u32,
+        item_id: u32,
+        start_price: u128,
+        floor_price: u128,
+        decay_per_block: u128,
+        duration: Option<u32>,
+        asset: SettlementAsset<u32>,
         at: Option<BlockHash>,
     ) -> RpcResult<String>;
 
@@ -99,17 +235,26 @@ pub trait AuctionApi<BlockHash, CollectionId, ItemId, AccountId, Balance, BlockN
         at: Option<BlockHash>,
     ) -> RpcResult<String>;
 
-    #[method(name = "auction_setFeePercentage")]
-    fn set_fee_percentage(&self, fee: u8, at: Option<BlockHash>) -> RpcResult<String>;
+    #[method(name = "auction_buyNow")]
+    fn buy_now(
+        &self,
+        collection_id: u32,
+        item_id: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<String>;
+
+    #[method(name = "auction_setMarketFee")]
+    fn set_market_fee(&self, fee: Permill, at: Option<BlockHash>) -> RpcResult<String>;
 
+    /// Move the accumulated fees of a settlement asset into the treasury account
     #[method(name = "auction_withdrawFees")]
-    fn withdraw_fees(&self, to: AccountId32, at: Option<BlockHash>) -> RpcResult<String>;
+    fn withdraw_fees(&self, asset: SettlementAsset<u32>, at: Option<BlockHash>) -> RpcResult<String>;
 
     /// List a batch of NFTs for auction
     #[method(name = "auction_listBatchNftsForAuction")]
     fn list_batch_nfts_for_auction(
         &self,
-        batch: BatchListingInfo<CollectionId, ItemId, Balance, BlockNumber>,
+        batch: BatchListingInfo<CollectionId, ItemId, Balance, BlockNumber, AssetId>,
         at: Option<BlockHash>,
     ) -> RpcResult<String>;
 }
@@ -132,8 +277,8 @@ impl<C, M> AuctionRpc<C, M> {
     }
 }
 
-impl<C, Block, BlockHash, CollectionId, ItemId, AccountId, Balance, BlockNumber>
-    AuctionApiServer<BlockHash, CollectionId, ItemId, AccountId, Balance, BlockNumber>
+impl<C, Block, BlockHash, CollectionId, ItemId, AccountId, Balance, BlockNumber, AssetId>
+    AuctionApiServer<BlockHash, CollectionId, ItemId, AccountId, Balance, BlockNumber, AssetId>
     for AuctionRpc<C, Block>
 where
     Block: BlockT<Hash = BlockHash>,
@@ -142,17 +287,18 @@ where
     BlockNumber: Clone + std::fmt::Display + Codec + Into<u32>,
     CollectionId: Clone + std::fmt::Display + Codec + Into<u32>,
     ItemId: Clone + std::fmt::Display + Codec + Into<u32>,
+    AssetId: Clone + Codec + Into<u32>,
     C: Send + Sync + 'static,
     C: ProvideRuntimeApi<Block>,
     C: HeaderBackend<Block>,
-    C::Api: AuctionRuntimeApi<Block, CollectionId, ItemId, AccountId, Balance, BlockNumber>,
+    C::Api: AuctionRuntimeApi<Block, CollectionId, ItemId, AccountId, Balance, BlockNumber, AssetId>,
 {
     fn get_auction_info(
         &self,
         collection_id: CollectionId,
         item_id: ItemId,
         at: Option<BlockHash>,
-    ) -> RpcResult<Option<AuctionInfo<AccountId, Balance, BlockNumber>>> {
+    ) -> RpcResult<Option<AuctionInfo<AccountId, Balance, BlockNumber, AssetId>>> {
         let api = self.client.runtime_api();
         // let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
         let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
@@ -183,6 +329,57 @@ where
         })
     }
 
+    fn get_top_bids(
+        &self,
+        collection_id: CollectionId,
+        item_id: ItemId,
+        k: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<(AccountId, Balance)>> {
+        let api = self.client.runtime_api();
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let runtime_api_result = api.get_top_bids(at_hash, collection_id, item_id, k);
+        runtime_api_result.map_err(|e| {
+            ErrorObjectOwned::owned(
+                1,
+                format!("Unable to query top auction bids: {}", e),
+                None::<()>,
+            )
+        })
+    }
+
+    fn get_bundle_items(
+        &self,
+        collection_id: CollectionId,
+        item_id: ItemId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<(CollectionId, ItemId)>> {
+        let api = self.client.runtime_api();
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.get_bundle_items(at_hash, collection_id, item_id)
+            .map_err(to_rpc_error)
+    }
+
+    fn pending_refunds(
+        &self,
+        account: AccountId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<((CollectionId, ItemId), SettlementAsset<AssetId>, Balance)>> {
+        let api = self.client.runtime_api();
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let runtime_api_result = api.pending_refunds(at_hash, account);
+        runtime_api_result.map_err(|e| {
+            ErrorObjectOwned::owned(
+                1,
+                format!("Unable to query pending refunds: {}", e),
+                None::<()>,
+            )
+        })
+    }
+
     fn is_in_auction(
         &self,
         collection_id: CollectionId,
@@ -203,7 +400,7 @@ where
         })
     }
 
-    fn get_fee_percentage(&self, at: Option<BlockHash>) -> RpcResult<u8> {
+    fn get_market_fee(&self, at: Option<BlockHash>) -> RpcResult<Permill> {
         let api = self.client.runtime_api();
         // let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
         let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
@@ -218,12 +415,31 @@ where
         })
     }
 
-    fn get_accumulated_fees(&self, at: Option<BlockHash>) -> RpcResult<NumberOrHex> {
+    fn quote_fees(
+        &self,
+        collection_id: CollectionId,
+        item_id: ItemId,
+        amount: Balance,
+        seller: AccountId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<FeeQuote<Balance>> {
+        let api = self.client.runtime_api();
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.quote_fees(at_hash, collection_id, item_id, amount, seller)
+            .map_err(to_rpc_error)
+    }
+
+    fn get_accumulated_fees(
+        &self,
+        asset: SettlementAsset<AssetId>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<NumberOrHex> {
         let api = self.client.runtime_api();
         // let at = BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash));
         let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
 
-        let runtime_api_result = api.get_accumulated_fees(at_hash);
+        let runtime_api_result = api.get_accumulated_fees(at_hash, asset);
         runtime_api_result
             .map(|balance| balance.into())
             .map_err(|e| {
@@ -241,7 +457,7 @@ where
     ) -> RpcResult<
         Vec<(
             (CollectionId, ItemId),
-            AuctionInfo<AccountId, Balance, BlockNumber>,
+            AuctionInfo<AccountId, Balance, BlockNumber, AssetId>,
         )>,
     > {
         let api = self.client.runtime_api();
@@ -258,16 +474,133 @@ where
         })
     }
 
+    fn get_min_next_bid(
+        &self,
+        collection_id: CollectionId,
+        item_id: ItemId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<NumberOrHex>> {
+        let api = self.client.runtime_api();
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let runtime_api_result = api.get_min_next_bid(at_hash, collection_id, item_id);
+        runtime_api_result
+            .map(|bid| bid.map(|b| b.into()))
+            .map_err(to_rpc_error)
+    }
+
+    fn get_listing(
+        &self,
+        collection_id: CollectionId,
+        item_id: ItemId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<ListingInfo<AccountId, Balance, AssetId>>> {
+        let api = self.client.runtime_api();
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.get_listing(at_hash, collection_id, item_id)
+            .map_err(to_rpc_error)
+    }
+
+    fn get_active_listings(
+        &self,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<((CollectionId, ItemId), ListingInfo<AccountId, Balance, AssetId>)>> {
+        let api = self.client.runtime_api();
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.get_active_listings(at_hash).map_err(to_rpc_error)
+    }
+
+    fn get_offers(
+        &self,
+        collection_id: CollectionId,
+        item_id: ItemId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<(AccountId, OfferInfo<Balance, BlockNumber>)>> {
+        let api = self.client.runtime_api();
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.get_offers(at_hash, collection_id, item_id)
+            .map_err(to_rpc_error)
+    }
+
+    fn get_sales_history(
+        &self,
+        collection_id: CollectionId,
+        item_id: ItemId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<SaleRecord<AccountId, Balance, BlockNumber, AssetId>>> {
+        let api = self.client.runtime_api();
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.get_sales_history(at_hash, collection_id, item_id)
+            .map_err(to_rpc_error)
+    }
+
+    fn get_collection_summary(
+        &self,
+        collection_id: CollectionId,
+        asset: SettlementAsset<AssetId>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<CollectionSummary<Balance>> {
+        let api = self.client.runtime_api();
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.get_collection_summary(at_hash, collection_id, asset)
+            .map_err(to_rpc_error)
+    }
+
+    fn get_account_stats(
+        &self,
+        account: AccountId,
+        asset: SettlementAsset<AssetId>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<AccountStats<Balance>> {
+        let api = self.client.runtime_api();
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.get_account_stats(at_hash, account, asset)
+            .map_err(to_rpc_error)
+    }
+
+    fn collection_stats(
+        &self,
+        collection_id: CollectionId,
+        window: BlockNumber,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Vec<(SettlementAsset<AssetId>, CollectionStats<Balance>)>> {
+        let api = self.client.runtime_api();
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        api.collection_stats(at_hash, collection_id, window)
+            .map_err(to_rpc_error)
+    }
+
     fn list_nft_for_auction(
         &self,
         collection_id: u32,
         item_id: u32,
+        reserve_price: Option<u128>,
+        min_increment: Option<BidIncrement<u128>>,
+        duration: Option<u32>,
+        buy_now_price: Option<u128>,
+        asset: SettlementAsset<u32>,
+        access: Option<BidderAccess<AccountId32, u32, ConstU32<100>>>,
         at: Option<BlockHash>,
     ) -> RpcResult<String> {
         // Create the call
         let call = RuntimeCall::Template(TemplateCall::list_nft_for_auction {
             collection_id,
             item_id,
+            settings: AuctionSettings {
+                reserve_price,
+                min_increment,
+                duration,
+                buy_now_price,
+                access,
+            },
+            asset,
         });
 
         // Encode the call
@@ -277,6 +610,31 @@ where
         Ok(format!("0x{}", hex::encode(encoded)))
     }
 
+    fn list_nft_for_dutch_auction(
+        &self,
+        collection_id: u32,
+        item_id: u32,
+        start_price: u128,
+        floor_price: u128,
+        decay_per_block: u128,
+        duration: Option<u32>,
+        asset: SettlementAsset<u32>,
+        at: Option<BlockHash>,
+    ) -> RpcResult<String> {
+        let call = RuntimeCall::Template(TemplateCall::list_nft_for_dutch_auction {
+            collection_id,
+            item_id,
+            start_price,
+            floor_price,
+            decay_per_block,
+            duration,
+            asset,
+        });
+
+        let encoded = call.encode();
+        Ok(format!("0x{}", hex::encode(encoded)))
+    }
+
     fn place_bid(
         &self,
         collection_id: u32,
@@ -309,15 +667,30 @@ where
         Ok(format!("0x{}", hex::encode(encoded)))
     }
 
-    fn set_fee_percentage(&self, fee: u8, at: Option<BlockHash>) -> RpcResult<String> {
-        let call = RuntimeCall::Template(TemplateCall::set_fee_percentage { fee });
+    fn buy_now(
+        &self,
+        collection_id: u32,
+        item_id: u32,
+        at: Option<BlockHash>,
+    ) -> RpcResult<String> {
+        let call = RuntimeCall::Template(TemplateCall::buy_now {
+            collection_id,
+            item_id,
+        });
+
+        let encoded = call.encode();
+        Ok(format!("0x{}", hex::encode(encoded)))
+    }
+
+    fn set_market_fee(&self, fee: Permill, at: Option<BlockHash>) -> RpcResult<String> {
+        let call = RuntimeCall::Template(TemplateCall::set_market_fee { fee });
 
         let encoded = call.encode();
         Ok(format!("0x{}", hex::encode(encoded)))
     }
 
-    fn withdraw_fees(&self, to: AccountId32, at: Option<BlockHash>) -> RpcResult<String> {
-        let call = RuntimeCall::Template(TemplateCall::withdraw_fees { to });
+    fn withdraw_fees(&self, asset: SettlementAsset<u32>, at: Option<BlockHash>) -> RpcResult<String> {
+        let call = RuntimeCall::Template(TemplateCall::withdraw_fees { asset });
 
         let encoded = call.encode();
         Ok(format!("0x{}", hex::encode(encoded)))
@@ -325,7 +698,7 @@ where
 
     fn list_batch_nfts_for_auction(
         &self,
-        batch_info: BatchListingInfo<CollectionId, ItemId, Balance, BlockNumber>,
+        batch_info: BatchListingInfo<CollectionId, ItemId, Balance, BlockNumber, AssetId>,
         at: Option<BlockHash>,
     ) -> RpcResult<String> {
         let converted = BatchListingInfo {
@@ -343,6 +716,10 @@ where
             })?,
             min_bid: batch_info.min_bid.map(|b| b.into()),
             custom_timeout: batch_info.custom_timeout.map(|bn| bn.into()),
+            asset: match batch_info.asset {
+                SettlementAsset::Native => SettlementAsset::Native,
+                SettlementAsset::Asset(id) => SettlementAsset::Asset(id.into()),
+            },
         };
 
         let call = RuntimeCall::Template(TemplateCall::batch_list_nfts_for_auction { batch_info: converted });
@@ -2,30 +2,58 @@
 
 use codec::Codec;
 use sp_runtime::scale_info::TypeInfo;
+use sp_runtime::Permill;
 use sp_std::vec::Vec;
 
-pub use primitives::BatchListingInfo;
+pub use primitives::{
+    AccountStats, AuctionKind, AuctionSettings, BatchListingInfo, BidIncrement, BidderAccess,
+    CollectionStats, CollectionSummary, FeeQuote, ListingInfo, OfferInfo, SaleRecord,
+    SealedBidPricing, SettlementAsset,
+};
 
 sp_api::decl_runtime_apis! {
-    pub trait AuctionApi<CollectionId, ItemId, AccountId, Balance, BlockNumber> where
+    #[api_version(2)]
+    pub trait AuctionApi<CollectionId, ItemId, AccountId, Balance, BlockNumber, AssetId> where
         CollectionId: Codec,
         ItemId: Codec,
         AccountId: Codec,
         Balance: Codec,
         BlockNumber: Codec,
+        AssetId: Codec,
     {
         /// Get auction information for a specific NFT
+        #[changed_in(2)]
         fn get_auction_info(
             collection_id: CollectionId,
             item_id: ItemId,
-        ) -> Option<AuctionInfo<AccountId, Balance, BlockNumber>>;
+        ) -> Option<AuctionInfoV1<AccountId, Balance, BlockNumber>>;
 
-        /// Get all bids for a specific NFT auction
+        /// Get auction information for a specific NFT, or for the bundle auction it is part of
+        fn get_auction_info(
+            collection_id: CollectionId,
+            item_id: ItemId,
+        ) -> Option<AuctionInfo<AccountId, Balance, BlockNumber, AssetId>>;
+
+        /// Get all active bids for a specific NFT auction, highest first
         fn get_bids(
             collection_id: CollectionId,
             item_id: ItemId,
         ) -> Vec<(AccountId, Balance)>;
 
+        /// Get the `k` highest active bids for a specific NFT auction
+        fn get_top_bids(
+            collection_id: CollectionId,
+            item_id: ItemId,
+            k: u32,
+        ) -> Vec<(AccountId, Balance)>;
+
+        /// Get the NFTs of the bundle auction a specific NFT is part of, empty if it is not
+        /// part of a bundle
+        fn get_bundle_items(
+            collection_id: CollectionId,
+            item_id: ItemId,
+        ) -> Vec<(CollectionId, ItemId)>;
+
         /// Check if an NFT is currently in auction
         fn is_in_auction(
             collection_id: CollectionId,
@@ -33,13 +61,85 @@ sp_api::decl_runtime_apis! {
         ) -> bool;
 
         /// Get current fee percentage
+        #[changed_in(2)]
         fn get_fee_percentage() -> u8;
 
+        /// Get the marketplace fee charged unless a fee tier or the collection sets another
+        fn get_fee_percentage() -> Permill;
+
+        /// Get the royalty, fee and seller payout of selling an NFT at `amount`
+        fn quote_fees(
+            collection_id: CollectionId,
+            item_id: ItemId,
+            amount: Balance,
+            seller: AccountId,
+        ) -> FeeQuote<Balance>;
+
         /// Get accumulated fees
+        #[changed_in(2)]
         fn get_accumulated_fees() -> Balance;
 
+        /// Get the fees accumulated in a settlement asset
+        fn get_accumulated_fees(asset: SettlementAsset<AssetId>) -> Balance;
+
+        /// Get all active auctions
+        #[changed_in(2)]
+        fn get_active_auctions() -> Vec<((CollectionId, ItemId), AuctionInfoV1<AccountId, Balance, BlockNumber>)>;
+
         /// Get all active auctions
-        fn get_active_auctions() -> Vec<((CollectionId, ItemId), AuctionInfo<AccountId, Balance, BlockNumber>)>;
+        fn get_active_auctions() -> Vec<((CollectionId, ItemId), AuctionInfo<AccountId, Balance, BlockNumber, AssetId>)>;
+
+        /// Get the smallest bid currently accepted for an active auction
+        fn get_min_next_bid(
+            collection_id: CollectionId,
+            item_id: ItemId,
+        ) -> Option<Balance>;
+
+        /// Get the fixed-price listing of a specific NFT
+        fn get_listing(
+            collection_id: CollectionId,
+            item_id: ItemId,
+        ) -> Option<ListingInfo<AccountId, Balance, AssetId>>;
+
+        /// Get all fixed-price listings
+        fn get_active_listings() -> Vec<((CollectionId, ItemId), ListingInfo<AccountId, Balance, AssetId>)>;
+
+        /// Get all standing offers on a specific NFT, including expired ones
+        fn get_offers(
+            collection_id: CollectionId,
+            item_id: ItemId,
+        ) -> Vec<(AccountId, OfferInfo<Balance, BlockNumber>)>;
+
+        /// Get the bids of an account that can be claimed back, by NFT
+        fn pending_refunds(
+            account: AccountId,
+        ) -> Vec<((CollectionId, ItemId), SettlementAsset<AssetId>, Balance)>;
+
+        /// Get the latest auction sales of a specific NFT, oldest first
+        fn get_sales_history(
+            collection_id: CollectionId,
+            item_id: ItemId,
+        ) -> Vec<SaleRecord<AccountId, Balance, BlockNumber, AssetId>>;
+
+        /// Get the summary of the auction sales of a collection in a settlement asset
+        fn get_collection_summary(
+            collection_id: CollectionId,
+            asset: SettlementAsset<AssetId>,
+        ) -> CollectionSummary<Balance>;
+
+        /// Get the auction volume bought and sold and auctions won by an account in a
+        /// settlement asset
+        fn get_account_stats(
+            account: AccountId,
+            asset: SettlementAsset<AssetId>,
+        ) -> AccountStats<Balance>;
+
+        /// Get the floor, median and volume of the auction sales of a collection settled
+        /// within the last `window` blocks, per settlement asset
+        fn collection_stats(
+            collection_id: CollectionId,
+            window: BlockNumber,
+        ) -> Vec<(SettlementAsset<AssetId>, CollectionStats<Balance>)>;
     }
 }
 
@@ -48,7 +148,36 @@ sp_api::decl_runtime_apis! {
 #[derive(
     codec::Encode, codec::Decode, Clone, PartialEq, Eq, sp_runtime::RuntimeDebug, TypeInfo,
 )]
-pub struct AuctionInfo<AccountId, Balance, BlockNumber> {
+pub struct AuctionInfo<AccountId, Balance, BlockNumber, AssetId> {
+    pub owner: AccountId,
+    pub start_block: BlockNumber,
+    pub end_block: BlockNumber,
+    /// Blocks left until the auction auto-resolves, as of the queried block
+    pub remaining_blocks: BlockNumber,
+    pub highest_bid: Balance,
+    pub highest_bidder: Option<AccountId>,
+    pub ended: bool,
+    /// Whether the highest bid reaches the reserve price, `None` if there is no reserve.
+    /// The reserve amount itself is kept hidden.
+    pub reserve_met: Option<bool>,
+    pub min_increment: Option<BidIncrement<Balance>>,
+    /// Blocks added to the auction by late bids so far
+    pub extended_by: BlockNumber,
+    /// Price at which the NFT can currently be bought outright
+    pub buy_now_price: Option<Balance>,
+    pub kind: AuctionKind<Balance>,
+    /// Price a Dutch auction sells at as of the queried block, `None` for other auction kinds
+    pub current_price: Option<Balance>,
+    /// What bids are placed and the sale is paid in
+    pub asset: SettlementAsset<AssetId>,
+}
+
+/// Auction info structure returned by version 1 of the runtime API
+#[cfg_attr(feature = "std", derive(serde::Serialize, serde::Deserialize))]
+#[derive(
+    codec::Encode, codec::Decode, Clone, PartialEq, Eq, sp_runtime::RuntimeDebug, TypeInfo,
+)]
+pub struct AuctionInfoV1<AccountId, Balance, BlockNumber> {
     pub owner: AccountId,
     pub start_block: BlockNumber,
     pub highest_bid: Balance,
@@ -11,22 +11,27 @@ mod benchmarks {
     use super::*;
 
     use crate::pallet::Pallet as Template;
-    use frame_support::traits::Get;
+    use frame_support::traits::{EnsureOrigin, Get};
     use frame_support::{
         assert_ok,
-        traits::{Currency, Hooks},
+        traits::{
+            fungible::{Inspect, Mutate},
+            Hooks,
+        },
         pallet_prelude::Zero,
     };
     use frame_system::RawOrigin;
-    use sp_runtime::traits::{Bounded, One, StaticLookup};
+    use primitives::{AuctionSettings, SettlementAsset};
+    use sp_runtime::traits::{Bounded, StaticLookup};
+    use sp_runtime::Permill;
 
     type BalanceOf<T> =
-        <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+        <<T as Config>::Currency as Inspect<<T as frame_system::Config>::AccountId>>::Balance;
 
     const SEED: u32 = 0;
     const COLLECTION_ID: u32 = 1;
     const ITEM_ID: u32 = 1;
-    const FEE_PERCENTAGE: u8 = 5;
+    const MARKET_FEE: Permill = Permill::from_percent(5);
 
     // Helper function to create a collection and mint an NFT
     fn setup_nft<T: pallet_uniques::Config>(owner: &T::AccountId) -> (T::CollectionId, T::ItemId)
@@ -60,7 +65,7 @@ mod benchmarks {
 
     // Helper function to fund an account
     fn fund_account<T: Config>(account: &T::AccountId, amount: BalanceOf<T>) {
-        let _ = <T as Config>::Currency::make_free_balance_be(account, amount);
+        let _ = <T as Config>::Currency::set_balance(account, amount);
     }
 
     #[benchmark]
@@ -71,12 +76,14 @@ mod benchmarks {
 
         #[extrinsic_call]
         list_nft_for_auction(
-            RawOrigin::Signed(caller),
-            collection_id.clone(),
-            item_id.clone(),
-        );
-
-        assert!(InAuction::<T>::get((
+    RawOrigin::Signed(caller),
+    collection_id.clone(),
+    item_id.clone(),
+    AuctionSettings::default(),
+    SettlementAsset::Native,
+);
+
+        assert!(Template::<T>::is_in_auction((
             collection_id.clone(),
             item_id.clone()
         )));
@@ -94,7 +101,9 @@ mod benchmarks {
         assert_ok!(Template::<T>::list_nft_for_auction(
             RawOrigin::Signed(seller.clone()).into(),
             collection_id.clone(),
-            item_id.clone()
+            item_id.clone(),
+            AuctionSettings::default(),
+            SettlementAsset::Native
         ));
 
         // Create bidder with funds
@@ -127,7 +136,9 @@ mod benchmarks {
         assert_ok!(Template::<T>::list_nft_for_auction(
             RawOrigin::Signed(seller.clone()).into(),
             collection_id.clone(),
-            item_id.clone()
+            item_id.clone(),
+            AuctionSettings::default(),
+            SettlementAsset::Native
         ));
 
         // Add bidder and place bid
@@ -144,10 +155,7 @@ mod benchmarks {
         ));
 
         // Set fee percentage
-        assert_ok!(Template::<T>::set_fee_percentage(
-            RawOrigin::Root.into(),
-            FEE_PERCENTAGE
-        ));
+        MarketFee::<T>::put(MARKET_FEE);
 
         #[extrinsic_call]
         resolve_auction(
@@ -156,12 +164,12 @@ mod benchmarks {
             item_id,
         );
 
-        assert!(!InAuction::<T>::get((
+        assert!(!Template::<T>::is_in_auction((
             collection_id.clone(),
             item_id.clone()
         )));
-        let auction = Auctions::<T>::get((collection_id.clone(), item_id.clone())).unwrap();
-        assert!(auction.ended);
+        assert!(!Auctions::<T>::contains_key((collection_id.clone(), item_id.clone())));
+        assert_eq!(SalesHistory::<T>::get((collection_id.clone(), item_id.clone())).len(), 1);
         assert_eq!(
             pallet_uniques::Pallet::<T>::owner(collection_id, item_id),
             Some(bidder)
@@ -169,22 +177,22 @@ mod benchmarks {
     }
 
     #[benchmark]
-    fn set_fee_percentage() {
-        let fee = 10u8;
+    fn set_market_fee() -> Result<(), BenchmarkError> {
+        let fee = Permill::from_percent(10);
+        let origin =
+            T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
 
         #[extrinsic_call]
-        set_fee_percentage(RawOrigin::Root, fee);
+        set_market_fee(origin as T::RuntimeOrigin, fee);
 
-        assert_eq!(FeePercentage::<T>::get(), fee);
+        assert_eq!(MarketFee::<T>::get(), fee);
+        Ok(())
     }
 
     #[benchmark]
-    fn withdraw_fees() {
+    fn withdraw_fees() -> Result<(), BenchmarkError> {
         // Setup initial fee
-        assert_ok!(Template::<T>::set_fee_percentage(
-            RawOrigin::Root.into(),
-            FEE_PERCENTAGE
-        ));
+        MarketFee::<T>::put(MARKET_FEE);
 
         // Create a complete auction cycle to generate fees
         let seller: T::AccountId = whitelisted_caller();
@@ -194,7 +202,9 @@ mod benchmarks {
         assert_ok!(Template::<T>::list_nft_for_auction(
             RawOrigin::Signed(seller.clone()).into(),
             collection_id.clone(),
-            item_id.clone()
+            item_id.clone(),
+            AuctionSettings::default(),
+            SettlementAsset::Native
         ));
 
         let bidder: T::AccountId = account("bidder", 0, SEED);
@@ -216,52 +226,51 @@ mod benchmarks {
             item_id.clone()
         ));
 
-        // Create recipient for fees
-        let recipient: T::AccountId = account("recipient", 0, SEED);
+        // Withdrawn fees go to the treasury account
+        let recipient = T::TreasuryAccount::get();
+        let origin =
+            T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
 
         // For benchmark purposes, directly add funds to pallet account to match accumulated fees
-        let fees = AccumulatedFees::<T>::get();
+        let fees = AccumulatedFees::<T>::get(SettlementAsset::Native);
         assert!(
             !fees.is_zero(),
             "No fees were accumulated during the auction"
         );
 
-        let initial_balance = <T as Config>::Currency::free_balance(&recipient);
+        let initial_balance = <T as Config>::Currency::balance(&recipient);
 
 
         assert!(
             frame_system::Pallet::<T>::account_exists(&Template::<T>::account_id()),
             "Account doesn't exist"
         );
-        
-        // Manually ensure pallet account has sufficient funds for benchmark
-        <T as Config>::Currency::deposit_creating(&Template::<T>::account_id(), fees);
 
-        // Verify the pallet account has the correct balance
-        assert_eq!(
-            <T as Config>::Currency::free_balance(&Template::<T>::account_id()),
-            fees,
-            "Pallet account balance doesn't match accumulated fees"
+        // Verify the pallet account holds the accumulated fees
+        assert!(
+            <T as Config>::Currency::balance(&Template::<T>::account_id()) >= fees,
+            "Pallet account balance doesn't cover accumulated fees"
         );
 
         #[extrinsic_call]
-        withdraw_fees(RawOrigin::Root, recipient.clone());
+        withdraw_fees(origin as T::RuntimeOrigin, SettlementAsset::Native);
 
         // Verify the fees were properly transferred
-        assert_eq!(AccumulatedFees::<T>::get(), BalanceOf::<T>::zero());
         assert_eq!(
-            <T as Config>::Currency::free_balance(&recipient),
+            AccumulatedFees::<T>::get(SettlementAsset::Native),
+            BalanceOf::<T>::zero()
+        );
+        assert_eq!(
+            <T as Config>::Currency::balance(&recipient),
             initial_balance + fees
         );
+        Ok(())
     }
 
     #[benchmark]
     fn on_initialize() {
         // Setup initial fee
-        assert_ok!(Template::<T>::set_fee_percentage(
-            RawOrigin::Root.into(),
-            FEE_PERCENTAGE
-        ));
+        MarketFee::<T>::put(MARKET_FEE);
 
         // Setup NFT and auction
         let seller: T::AccountId = whitelisted_caller();
@@ -271,7 +280,9 @@ mod benchmarks {
         assert_ok!(Template::<T>::list_nft_for_auction(
             RawOrigin::Signed(seller.clone()).into(),
             collection_id.clone(),
-            item_id.clone()
+            item_id.clone(),
+            AuctionSettings::default(),
+            SettlementAsset::Native
         ));
 
         // Add bidder and place bid
@@ -290,17 +301,15 @@ mod benchmarks {
 
         // Fast forward blocks
         let auction_info = Auctions::<T>::get((collection_id.clone(), item_id.clone())).unwrap();
-        let start_block = auction_info.start_block;
-        let timeout_block = start_block + T::AuctionTimeoutBlocks::get() + One::one();
+        let timeout_block = auction_info.end_block;
 
         #[block]
         {
             Template::<T>::on_initialize(timeout_block);
         }
 
-        let auction = Auctions::<T>::get((collection_id.clone(), item_id)).unwrap();
-        assert!(auction.ended);
-        assert!(!InAuction::<T>::get((collection_id, item_id)));
+        assert!(!Auctions::<T>::contains_key((collection_id.clone(), item_id)));
+        assert!(!Template::<T>::is_in_auction((collection_id, item_id)));
     }
 
     impl_benchmark_test_suite!(Template, crate::mock::new_test_ext(), crate::mock::Test);
@@ -21,41 +21,211 @@ pub mod pallet {
     use crate::weights::WeightInfo;
     use frame_support::{
         pallet_prelude::*,
-        traits::{Currency, ExistenceRequirement, ReservableCurrency, WithdrawReasons},
+        traits::{
+            fungible, fungibles, nonfungibles::InspectEnumerable, Contains,
+            tokens::{
+                DepositConsequence, Fortitude, Precision, Preservation, Provenance, Restriction,
+            },
+        },
         transactional, PalletId,
     };
     use frame_system::pallet_prelude::*;
     use sp_runtime::traits::AccountIdConversion;
-    use sp_runtime::traits::{CheckedDiv, Zero};
-    use sp_runtime::Saturating;
+    use sp_runtime::traits::{Hash, One, Zero};
+    use sp_runtime::{Perbill, Permill, SaturatedConversion, Saturating};
     use sp_std::prelude::*;
-    use primitives::BatchListingInfo;
+    use primitives::{
+        AccountStats, AuctionKind, AuctionSettings, BatchListingInfo, BidIncrement, BidderAccess,
+        CollectionStats, CollectionSummary, FeeQuote, ListingInfo, OfferInfo, SaleRecord, SealedBidPricing,
+        SettlementAsset,
+    };
 
     type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
-    type BalanceOf<T> = <<T as Config>::Currency as Currency<AccountIdOf<T>>>::Balance;
+    type BalanceOf<T> =
+        <<T as Config>::Currency as fungible::Inspect<AccountIdOf<T>>>::Balance;
+    type SealedBidOf<T> = SealedBid<<T as frame_system::Config>::Hash, BalanceOf<T>>;
+    type LedgerBidOf<T> = LedgerBid<BalanceOf<T>, BlockNumberFor<T>, <T as Config>::AssetId>;
+    type EscrowInfoOf<T> =
+        EscrowInfo<AccountIdOf<T>, BalanceOf<T>, BlockNumberFor<T>, <T as Config>::AssetId>;
+    type AssetOf<T> = SettlementAsset<<T as Config>::AssetId>;
+    type MarketParametersOf<T> = MarketParameters<BlockNumberFor<T>>;
+    type FeeDistributionOf<T> = FeeDistribution<BlockNumberFor<T>>;
+    type BidderAccessOf<T> = BidderAccess<
+        AccountIdOf<T>,
+        <T as pallet_uniques::Config>::CollectionId,
+        <T as Config>::MaxAllowlistLen,
+    >;
+    type AuctionSettingsOf<T> = AuctionSettings<
+        AccountIdOf<T>,
+        <T as pallet_uniques::Config>::CollectionId,
+        BalanceOf<T>,
+        BlockNumberFor<T>,
+        <T as Config>::MaxAllowlistLen,
+    >;
+    type PendingRefundOf<T> = (
+        (<T as pallet_uniques::Config>::CollectionId, <T as pallet_uniques::Config>::ItemId),
+        AssetOf<T>,
+        BalanceOf<T>,
+    );
+    type BatchListingInfoOf<T> = BatchListingInfo<
+        <T as pallet_uniques::Config>::CollectionId,
+        <T as pallet_uniques::Config>::ItemId,
+        BalanceOf<T>,
+        BlockNumberFor<T>,
+        <T as Config>::AssetId,
+    >;
+    type AuctionInfoOf<T> =
+        AuctionInfo<AccountIdOf<T>, BalanceOf<T>, BlockNumberFor<T>, <T as Config>::AssetId>;
+    type SaleRecordOf<T> =
+        SaleRecord<AccountIdOf<T>, BalanceOf<T>, BlockNumberFor<T>, <T as Config>::AssetId>;
 
     #[pallet::config]
     pub trait Config: frame_system::Config + pallet_uniques::Config {
         type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
-        /// The currency mechanism for handling bids
-        type Currency: ReservableCurrency<Self::AccountId>;
+        /// The currency bids and offers are held in and sales are paid in
+        type Currency: fungible::Mutate<Self::AccountId>
+            + fungible::MutateHold<Self::AccountId, Reason = Self::RuntimeHoldReason>;
 
-        /// The maximum number of bids per auction
+        /// The overarching hold reason
+        type RuntimeHoldReason: From<HoldReason>;
+
+        /// Identifier of the fungible assets auctions and listings can settle in
+        type AssetId: Member + Parameter + Copy + MaxEncodedLen;
+
+        /// The fungible assets, besides the native currency, bids and sales can be paid in
+        type Assets: fungibles::Mutate<
+            Self::AccountId,
+            AssetId = Self::AssetId,
+            Balance = BalanceOf<Self>,
+        >;
+
+        /// Highest number of bids governance can let an auction rank, and the default
         #[pallet::constant]
         type MaxBidsPerAuction: Get<u32>;
 
-        /// Number of blocks after which the auction auto-resolves
+        /// Default number of blocks after which an auction auto-resolves when no duration
+        /// is given, until governance sets another
         #[pallet::constant]
         type AuctionTimeoutBlocks: Get<BlockNumberFor<Self>>;
 
-        /// Royalty percentage for original creators (0-100)
+        /// Shortest auction duration a seller can choose, in blocks
+        #[pallet::constant]
+        type MinAuctionDuration: Get<BlockNumberFor<Self>>;
+
+        /// Longest auction duration a seller can choose, in blocks
+        #[pallet::constant]
+        type MaxAuctionDuration: Get<BlockNumberFor<Self>>;
+
+        /// A bid placed this many blocks or fewer before the end extends the auction
+        #[pallet::constant]
+        type SoftCloseWindow: Get<BlockNumberFor<Self>>;
+
+        /// Number of blocks a bid inside the soft close window adds to the auction
+        #[pallet::constant]
+        type SoftCloseExtension: Get<BlockNumberFor<Self>>;
+
+        /// Upper bound on the total soft close extension of a single auction
+        #[pallet::constant]
+        type MaxSoftCloseExtension: Get<BlockNumberFor<Self>>;
+
+        /// Percentage of the buy-now price (0-100) a bid has to exceed to withdraw the
+        /// buy-now option
+        #[pallet::constant]
+        type BuyNowBidThreshold: Get<u8>;
+
+        /// Percentage of the highest bid (0-100) a seller pays to cancel an auction with bids
+        #[pallet::constant]
+        type CancellationPenalty: Get<u8>;
+
+        /// Who receives the penalty for cancelling an auction with bids
+        #[pallet::constant]
+        type CancellationPenaltyRecipient: Get<PenaltyRecipient>;
+
+        /// Number of blocks a bid has to stand before it can be retracted
+        #[pallet::constant]
+        type BidRetractionCooldown: Get<BlockNumberFor<Self>>;
+
+        /// Number of blocks at the end of a sealed-bid auction during which bids are revealed
+        /// instead of committed
+        #[pallet::constant]
+        type SealedBidRevealPeriod: Get<BlockNumberFor<Self>>;
+
+        /// Maximum number of accounts a collection's royalty can be split between
         #[pallet::constant]
-        type RoyaltyPercentage: Get<u8>;
+        type MaxRoyaltyRecipients: Get<u32>;
 
+        /// Highest number of NFTs governance can let a batch list, and the default
         #[pallet::constant]
         type MaxBatchListingSize: Get<u32>;
 
+        /// Maximum number of auctions that can end in the same block
+        #[pallet::constant]
+        type MaxAuctionsPerBlock: Get<u32>;
+
+        /// Maximum number of expiry schedule entries processed per block, counting both
+        /// scheduled blocks read and auctions resolved; the rest carries over to the next block.
+        /// At least 2
+        #[pallet::constant]
+        type MaxResolutionsPerBlock: Get<u32>;
+
+        /// Maximum number of bids `claim_all_refunds` releases in one call
+        #[pallet::constant]
+        type MaxRefundsPerClaim: Get<u32>;
+
+        /// Maximum number of sales kept in the history of each NFT, older sales are dropped
+        #[pallet::constant]
+        type MaxSalesHistory: Get<u32>;
+
+        /// Number of blocks the sales aggregates of a collection are bucketed by
+        #[pallet::constant]
+        type StatsBucketLength: Get<BlockNumberFor<Self>>;
+
+        /// Maximum number of buckets kept per collection, older buckets are dropped
+        #[pallet::constant]
+        type MaxStatsBuckets: Get<u32>;
+
+        /// Maximum number of sale prices sampled per bucket for the median
+        #[pallet::constant]
+        type MaxPricesPerBucket: Get<u32>;
+
+        /// Number of blocks the proceeds of an auction sale are held in escrow, during which
+        /// the buyer can dispute the sale. Zero pays sellers straight away
+        #[pallet::constant]
+        type EscrowPeriod: Get<BlockNumberFor<Self>>;
+
+        /// Origin that resolves disputed escrows
+        type DisputeOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Maximum number of NFTs sold together in a bundle auction
+        #[pallet::constant]
+        type MaxBundleSize: Get<u32>;
+
+        /// Accounts that have a profile, which auctions can be gated on
+        type ProfileHolders: Contains<Self::AccountId>;
+
+        /// Maximum number of accounts allowed to bid on an allowlisted auction
+        #[pallet::constant]
+        type MaxAllowlistLen: Get<u32>;
+
+        /// Origin that sets the marketplace parameters, fees and maximum royalty
+        type AdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Maximum number of volume tiers of seller fees per settlement asset
+        #[pallet::constant]
+        type MaxFeeTiers: Get<u32>;
+
+        /// Treasury account receiving withdrawn fees and the treasury share of distributed fees
+        type TreasuryAccount: Get<Self::AccountId>;
+
+        /// Pot receiving the rewards share of distributed fees
+        type RewardsAccount: Get<Self::AccountId>;
+
+        /// Maximum number of settlement assets whose fees are distributed per block, the
+        /// rest are distributed in the following blocks
+        #[pallet::constant]
+        type MaxFeeAssetsPerDistribution: Get<u32>;
+
         type PalletId: Get<PalletId>;
 
         type WeightInfo: WeightInfo;
@@ -68,14 +238,28 @@ pub mod pallet {
         _,
         Blake2_128Concat,
         (T::CollectionId, T::ItemId),
-        AuctionInfo<T::AccountId, BalanceOf<T>, BlockNumberFor<T>>,
+        AuctionInfoOf<T>,
+        OptionQuery,
+    >;
+
+    /// Bids of each bidder by NFT, each held under `HoldReason::AuctionBid` until it wins or
+    /// is claimed back. Bids no longer ranked in a running auction can be claimed back.
+    #[pallet::storage]
+    #[pallet::getter(fn bid_of)]
+    pub type BidLedger<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        (T::CollectionId, T::ItemId),
+        LedgerBidOf<T>,
         OptionQuery,
     >;
 
-    /// Mapping from NFT to bidders and their bids, ordered by bid amount
+    /// Bidders of the active bids of a running auction ranked by amount, highest first
     #[pallet::storage]
-    #[pallet::getter(fn bids)]
-    pub type Bids<T: Config> = StorageMap<
+    #[pallet::getter(fn ranked_bids)]
+    pub type RankedBids<T: Config> = StorageMap<
         _,
         Blake2_128Concat,
         (T::CollectionId, T::ItemId),
@@ -83,37 +267,463 @@ pub mod pallet {
         ValueQuery,
     >;
 
-    /// Tracks whether an NFT is currently in an auction
+    /// Hidden maximum of the leading bid of a running auction, when it was placed as a maximum
+    /// bid. The leading bid is raised automatically up to it whenever someone else bids.
+    #[pallet::storage]
+    pub type MaxBids<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        (T::CollectionId, T::ItemId),
+        BalanceOf<T>,
+        OptionQuery,
+    >;
+
+    /// Who may bid on gated auctions
+    #[pallet::storage]
+    #[pallet::getter(fn auction_access)]
+    pub type AuctionAccess<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        (T::CollectionId, T::ItemId),
+        BidderAccessOf<T>,
+        OptionQuery,
+    >;
+
+    /// Committed bids of sealed-bid auctions, in commit order
+    #[pallet::storage]
+    #[pallet::getter(fn sealed_bids)]
+    pub type SealedBids<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        (T::CollectionId, T::ItemId),
+        BoundedVec<(T::AccountId, SealedBidOf<T>), T::MaxBidsPerAuction>,
+        ValueQuery,
+    >;
+
+    /// NFTs listed for sale at a fixed price
+    #[pallet::storage]
+    #[pallet::getter(fn listings)]
+    pub type Listings<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        (T::CollectionId, T::ItemId),
+        ListingInfo<T::AccountId, BalanceOf<T>, T::AssetId>,
+        OptionQuery,
+    >;
+
+    /// Standing offers on NFTs, keyed by NFT and buyer
+    #[pallet::storage]
+    #[pallet::getter(fn offers)]
+    pub type Offers<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        (T::CollectionId, T::ItemId),
+        Blake2_128Concat,
+        T::AccountId,
+        OfferInfo<BalanceOf<T>, BlockNumberFor<T>>,
+        OptionQuery,
+    >;
+
+    /// Latest auction sales of each NFT, oldest first
+    #[pallet::storage]
+    #[pallet::getter(fn sales_history)]
+    pub type SalesHistory<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        (T::CollectionId, T::ItemId),
+        BoundedVec<SaleRecordOf<T>, T::MaxSalesHistory>,
+        ValueQuery,
+    >;
+
+    /// Summary of the auction sales of each collection, per settlement asset
+    #[pallet::storage]
+    #[pallet::getter(fn collection_summary)]
+    pub type CollectionSummaries<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::CollectionId,
+        Blake2_128Concat,
+        AssetOf<T>,
+        CollectionSummary<BalanceOf<T>>,
+        ValueQuery,
+    >;
+
+    /// Auction volume bought and sold and auctions won by each account, per settlement asset
+    #[pallet::storage]
+    #[pallet::getter(fn account_stats)]
+    pub type AccountStatistics<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        AssetOf<T>,
+        AccountStats<BalanceOf<T>>,
+        ValueQuery,
+    >;
+
+    /// Time-bucketed aggregates of the auction sales of each collection, per settlement asset,
+    /// keyed by the first block of the bucket
+    #[pallet::storage]
+    #[pallet::getter(fn sales_bucket)]
+    pub type SalesBuckets<T: Config> = StorageNMap<
+        _,
+        (
+            NMapKey<Blake2_128Concat, T::CollectionId>,
+            NMapKey<Blake2_128Concat, AssetOf<T>>,
+            NMapKey<Twox64Concat, BlockNumberFor<T>>,
+        ),
+        SalesBucket<T>,
+        OptionQuery,
+    >;
+
+    /// First blocks of the sales buckets kept for each collection, per settlement asset,
+    /// oldest first
+    #[pallet::storage]
+    #[pallet::getter(fn sales_bucket_starts)]
+    pub type SalesBucketStarts<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::CollectionId,
+        Blake2_128Concat,
+        AssetOf<T>,
+        BoundedVec<BlockNumberFor<T>, T::MaxStatsBuckets>,
+        ValueQuery,
+    >;
+
+    /// NFTs sold together by a bundle auction, keyed by the first of them, which the auction
+    /// is keyed by
+    #[pallet::storage]
+    #[pallet::getter(fn bundle_items)]
+    pub type BundleItems<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        (T::CollectionId, T::ItemId),
+        BoundedVec<(T::CollectionId, T::ItemId), T::MaxBundleSize>,
+        ValueQuery,
+    >;
+
+    /// The NFT a bundle auction is keyed by, for every NFT in the bundle
+    #[pallet::storage]
+    #[pallet::getter(fn bundle_of)]
+    pub type BundleOf<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        (T::CollectionId, T::ItemId),
+        (T::CollectionId, T::ItemId),
+        OptionQuery,
+    >;
+
+    /// Proceeds of auction sales held in the pallet account until their dispute window closes
+    #[pallet::storage]
+    #[pallet::getter(fn escrows)]
+    pub type Escrows<T: Config> =
+        StorageMap<_, Blake2_128Concat, (T::CollectionId, T::ItemId), EscrowInfoOf<T>, OptionQuery>;
+
+    /// Auctions due to auto-resolve, keyed by their end block
+    #[pallet::storage]
+    #[pallet::getter(fn auction_expiries)]
+    pub type AuctionExpiries<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        BlockNumberFor<T>,
+        BoundedVec<(T::CollectionId, T::ItemId), T::MaxAuctionsPerBlock>,
+        ValueQuery,
+    >;
+
+    /// Oldest block whose expiries could not all be processed yet
+    #[pallet::storage]
+    #[pallet::getter(fn expiry_backlog)]
+    pub type ExpiryBacklog<T: Config> = StorageValue<_, BlockNumberFor<T>, OptionQuery>;
+
+    /// Marketplace fee charged on sales, unless a fee tier or the collection sets another
+    #[pallet::storage]
+    #[pallet::getter(fn market_fee)]
+    pub(super) type MarketFee<T> = StorageValue<_, Permill, ValueQuery>;
+
+    /// Fees of sellers by the volume they sold in each settlement asset, ordered by ascending
+    /// volume. A seller pays the fee of the highest tier their volume reaches. Only auction
+    /// sales count toward the volume, fixed-price and offer sales do not
+    #[pallet::storage]
+    #[pallet::getter(fn fee_tiers)]
+    pub type FeeTiers<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        AssetOf<T>,
+        BoundedVec<(BalanceOf<T>, Permill), T::MaxFeeTiers>,
+        ValueQuery,
+    >;
+
+    /// Fees of collections, charged on their sales in place of the market fee and fee tiers
+    #[pallet::storage]
+    #[pallet::getter(fn collection_fee)]
+    pub type CollectionFees<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::CollectionId, Permill, OptionQuery>;
+
+    /// How accumulated fees are split and how often they are distributed. Without a policy
+    /// fees accumulate until withdrawn
     #[pallet::storage]
-    #[pallet::getter(fn is_in_auction)]
-    pub type InAuction<T: Config> =
-        StorageMap<_, Blake2_128Concat, (T::CollectionId, T::ItemId), bool, ValueQuery>;
+    #[pallet::getter(fn fee_distribution)]
+    pub type FeeDistributionPolicy<T: Config> =
+        StorageValue<_, FeeDistributionOf<T>, OptionQuery>;
 
+    /// Last settlement asset distributed by a fee distribution that did not get through every
+    /// asset, the next block carries on after it
     #[pallet::storage]
-    #[pallet::getter(fn fee_percentage)]
-    pub(super) type FeePercentage<T> = StorageValue<_, u8, ValueQuery>; // e.g., 5 for 5%
+    #[pallet::getter(fn fee_distribution_backlog)]
+    pub type FeeDistributionBacklog<T: Config> = StorageValue<_, AssetOf<T>, OptionQuery>;
 
+    /// Marketplace fees collected in the pallet account, per settlement asset
     #[pallet::storage]
     #[pallet::getter(fn accumulated_fees)]
-    pub(super) type AccumulatedFees<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
+    pub(super) type AccumulatedFees<T: Config> =
+        StorageMap<_, Blake2_128Concat, AssetOf<T>, BalanceOf<T>, ValueQuery>;
+
+    /// Asset funds of bids, offers and sealed-bid deposits held in the pallet account for
+    /// their owners, per asset
+    #[pallet::storage]
+    #[pallet::getter(fn held_assets)]
+    pub type HeldAssets<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AssetId, BalanceOf<T>, ValueQuery>;
+
+    /// Royalty terms collection owners set for sales of their NFTs
+    #[pallet::storage]
+    #[pallet::getter(fn collection_royalty)]
+    pub type CollectionRoyalties<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::CollectionId, RoyaltyInfo<T>, OptionQuery>;
+
+    #[pallet::type_value]
+    pub fn DefaultMaxRoyalty() -> Perbill {
+        Perbill::from_percent(10)
+    }
+
+    /// Highest royalty rate a collection can charge, set by governance
+    #[pallet::storage]
+    #[pallet::getter(fn max_royalty)]
+    pub type MaxRoyalty<T: Config> = StorageValue<_, Perbill, ValueQuery, DefaultMaxRoyalty>;
+
+    #[pallet::type_value]
+    pub fn DefaultMarketParams<T: Config>() -> MarketParametersOf<T> {
+        MarketParameters {
+            max_bids_per_auction: T::MaxBidsPerAuction::get(),
+            auction_timeout: T::AuctionTimeoutBlocks::get(),
+            max_batch_listing_size: T::MaxBatchListingSize::get(),
+        }
+    }
+
+    /// Marketplace parameters set by governance
+    #[pallet::storage]
+    #[pallet::getter(fn market_params)]
+    pub type MarketParams<T: Config> =
+        StorageValue<_, MarketParametersOf<T>, ValueQuery, DefaultMarketParams<T>>;
+
+    #[pallet::genesis_config]
+    pub struct GenesisConfig<T: Config> {
+        /// Number of bids an auction ranks
+        pub max_bids_per_auction: u32,
+        /// Number of blocks an auction runs when no duration is given
+        pub auction_timeout: BlockNumberFor<T>,
+        /// Number of NFTs a batch can list
+        pub max_batch_listing_size: u32,
+        /// Marketplace fee charged on sales
+        pub market_fee: Permill,
+        /// Highest royalty rate a collection can charge
+        pub max_royalty: Perbill,
+    }
+
+    impl<T: Config> Default for GenesisConfig<T> {
+        fn default() -> Self {
+            let params = DefaultMarketParams::<T>::get();
+            Self {
+                max_bids_per_auction: params.max_bids_per_auction,
+                auction_timeout: params.auction_timeout,
+                max_batch_listing_size: params.max_batch_listing_size,
+                market_fee: Permill::zero(),
+                max_royalty: DefaultMaxRoyalty::get(),
+            }
+        }
+    }
+
+    #[pallet::genesis_build]
+    impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+        fn build(&self) {
+            let params = MarketParameters {
+                max_bids_per_auction: self.max_bids_per_auction,
+                auction_timeout: self.auction_timeout,
+                max_batch_listing_size: self.max_batch_listing_size,
+            };
+            assert!(Pallet::<T>::is_valid_market_params(&params), "invalid market parameters");
+            MarketParams::<T>::put(params);
+            MarketFee::<T>::put(self.market_fee);
+            MaxRoyalty::<T>::put(self.max_royalty);
+        }
+    }
 
     /// Structure for auction information
     #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
-    pub struct AuctionInfo<AccountId, Balance, BlockNumber> {
+    pub struct AuctionInfo<AccountId, Balance, BlockNumber, AssetId> {
         /// The owner of the auction
         pub owner: AccountId,
         /// The block number when the auction started
         pub start_block: BlockNumber,
+        /// The block number at which the auction auto-resolves
+        pub end_block: BlockNumber,
         /// The highest bid amount
         pub highest_bid: Balance,
         /// The highest bidder
         pub highest_bidder: Option<AccountId>,
         /// Whether the auction has ended
         pub ended: bool,
+        /// Hidden minimum price the highest bid must reach for the sale to go through
+        pub reserve_price: Option<Balance>,
+        /// Minimum amount by which a new bid must exceed the highest bid
+        pub min_increment: Option<BidIncrement<Balance>>,
+        /// Total number of blocks late bids have added to the auction
+        pub extended_by: BlockNumber,
+        /// Price at which the NFT can be bought outright, until bidding gets close to it
+        pub buy_now_price: Option<Balance>,
+        /// Whether prices are discovered by ascending bids or a falling price
+        pub kind: AuctionKind<Balance>,
+        /// What bids are placed and the sale is paid in
+        pub asset: SettlementAsset<AssetId>,
+    }
+
+    /// Marketplace parameters governance can change without a runtime upgrade
+    #[derive(
+        Clone,
+        Encode,
+        Decode,
+        DecodeWithMemTracking,
+        Eq,
+        PartialEq,
+        RuntimeDebug,
+        MaxEncodedLen,
+        TypeInfo,
+    )]
+    pub struct MarketParameters<BlockNumber> {
+        /// Number of bids an auction ranks, lower bids can be claimed back. At most
+        /// `MaxBidsPerAuction`
+        pub max_bids_per_auction: u32,
+        /// Number of blocks an auction runs when the seller does not choose a duration.
+        /// Within the allowed auction durations
+        pub auction_timeout: BlockNumber,
+        /// Number of NFTs a batch can list. At most `MaxBatchListingSize`
+        pub max_batch_listing_size: u32,
+    }
+
+    /// Split of the accumulated fees between the treasury, a burn and the rewards pot, paid
+    /// out every `period` blocks. The shares add up to the whole
+    #[derive(
+        Clone,
+        Encode,
+        Decode,
+        DecodeWithMemTracking,
+        Eq,
+        PartialEq,
+        RuntimeDebug,
+        MaxEncodedLen,
+        TypeInfo,
+    )]
+    pub struct FeeDistribution<BlockNumber> {
+        /// Share sent to the treasury account
+        pub treasury: Permill,
+        /// Share burned
+        pub burn: Permill,
+        /// Share sent to the rewards pot
+        pub rewards: Permill,
+        /// Number of blocks between distributions
+        pub period: BlockNumber,
     }
 
+    /// Reasons the pallet holds funds of an account
+    #[pallet::composite_enum]
+    pub enum HoldReason {
+        /// Funds backing the leading bid on an auction
+        AuctionBid,
+        /// Funds backing a standing offer on an NFT
+        Offer,
+        /// Deposit backing a sealed-bid commitment
+        SealedBidDeposit,
+    }
+
+    /// Royalty terms of a collection
+    #[derive(
+        CloneNoBound, Encode, Decode, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound, TypeInfo,
+    )]
+    #[scale_info(skip_type_params(T))]
+    pub struct RoyaltyInfo<T: Config> {
+        /// Share of the sale price paid as royalty
+        pub rate: Perbill,
+        /// Accounts the royalty is split between, each with its weight
+        pub recipients: BoundedVec<(T::AccountId, u32), T::MaxRoyaltyRecipients>,
+    }
+
+    /// Aggregates of the auction sales of a collection settled within one bucket of blocks
+    #[derive(
+        CloneNoBound, Encode, Decode, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound, TypeInfo,
+    )]
+    #[scale_info(skip_type_params(T))]
+    pub struct SalesBucket<T: Config> {
+        /// The lowest sale price
+        pub floor: BalanceOf<T>,
+        /// The sum of all sale prices
+        pub volume: BalanceOf<T>,
+        /// The number of sales
+        pub sales: u32,
+        /// Sale prices sampled uniformly from the sales of the bucket for the median
+        pub prices: BoundedVec<BalanceOf<T>, T::MaxPricesPerBucket>,
+    }
+
+    /// Who receives the penalty for cancelling an auction that has bids
+    #[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+    pub enum PenaltyRecipient {
+        /// The highest bidder, as compensation for the locked funds
+        TopBidder,
+        /// The pallet account, where it adds to the accumulated fees
+        Treasury,
+    }
+
+    /// A bid on an auction, held until it wins, is retracted or is claimed back
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+    pub struct LedgerBid<Balance, BlockNumber, AssetId> {
+        /// The amount held for the bid
+        pub amount: Balance,
+        /// The block the bid was last placed or raised at
+        pub placed_at: BlockNumber,
+        /// What the bid is held in
+        pub asset: SettlementAsset<AssetId>,
+    }
+
+    /// Proceeds of an auction sale held until the buyer can no longer dispute the sale
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+    pub struct EscrowInfo<AccountId, Balance, BlockNumber, AssetId> {
+        /// The account the proceeds are released to
+        pub seller: AccountId,
+        /// The account that can dispute the sale and get the proceeds refunded
+        pub buyer: AccountId,
+        /// What the proceeds are held in
+        pub asset: SettlementAsset<AssetId>,
+        /// The proceeds held, the sale price less royalty and fee
+        pub amount: Balance,
+        /// The marketplace fee held with the proceeds, collected on release and refunded with
+        /// them
+        pub fee: Balance,
+        /// The block from which undisputed proceeds can be released
+        pub release_at: BlockNumber,
+        /// Whether the buyer disputed the sale
+        pub disputed: bool,
+    }
+
+    /// A bid committed to a sealed-bid auction
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+    pub struct SealedBid<Hash, Balance> {
+        /// Hash of the SCALE-encoded `(amount, salt)` pair
+        pub commitment: Hash,
+        /// Funds held alongside the commitment, the revealed amount may not exceed it
+        pub deposit: Balance,
+        /// The amount, once the bidder has revealed it
+        pub revealed: Option<Balance>,
+    }
 
-    
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config> {
@@ -125,8 +735,77 @@ pub mod pallet {
         AuctionResolved(T::CollectionId, T::ItemId, T::AccountId, BalanceOf<T>),
         /// An auction failed to find a valid buyer. [collection_id, item_id]
         AuctionFailed(T::CollectionId, T::ItemId),
-        FeePercentageSet(u8),
-        FeesWithdrawn(T::AccountId, BalanceOf<T>),
+        /// An auction ended below its reserve price and the NFT was returned to the owner.
+        /// [collection_id, item_id, highest_bid]
+        ReserveNotMet(T::CollectionId, T::ItemId, BalanceOf<T>),
+        /// A late bid pushed back the end of an auction. [collection_id, item_id, end_block]
+        AuctionExtended(T::CollectionId, T::ItemId, BlockNumberFor<T>),
+        /// An NFT was listed for sale at a fixed price. [collection_id, item_id, seller, price]
+        NftListedForSale(T::CollectionId, T::ItemId, T::AccountId, BalanceOf<T>),
+        /// The price of a fixed-price listing changed. [collection_id, item_id, price]
+        ListingPriceUpdated(T::CollectionId, T::ItemId, BalanceOf<T>),
+        /// A fixed-price listing was withdrawn. [collection_id, item_id]
+        NftDelisted(T::CollectionId, T::ItemId),
+        /// An NFT was bought at its listed price. [collection_id, item_id, buyer, price]
+        NftSold(T::CollectionId, T::ItemId, T::AccountId, BalanceOf<T>),
+        /// An offer was made on an NFT. [collection_id, item_id, buyer, amount, expires_at]
+        OfferMade(T::CollectionId, T::ItemId, T::AccountId, BalanceOf<T>, BlockNumberFor<T>),
+        /// An offer was withdrawn by the buyer. [collection_id, item_id, buyer]
+        OfferCancelled(T::CollectionId, T::ItemId, T::AccountId),
+        /// The owner accepted an offer and sold the NFT. [collection_id, item_id, buyer, amount]
+        OfferAccepted(T::CollectionId, T::ItemId, T::AccountId, BalanceOf<T>),
+        /// A sealed bid was committed. [collection_id, item_id, bidder, deposit]
+        SealedBidCommitted(T::CollectionId, T::ItemId, T::AccountId, BalanceOf<T>),
+        /// A sealed bid was revealed. [collection_id, item_id, bidder, amount]
+        SealedBidRevealed(T::CollectionId, T::ItemId, T::AccountId, BalanceOf<T>),
+        /// The deposit of a sealed bid that was never revealed went to the pallet account.
+        /// [collection_id, item_id, bidder, deposit]
+        SealedBidSlashed(T::CollectionId, T::ItemId, T::AccountId, BalanceOf<T>),
+        /// The seller cancelled an auction. [collection_id, item_id, penalty]
+        AuctionCancelled(T::CollectionId, T::ItemId, BalanceOf<T>),
+        /// A bidder retracted a bid that was not leading. [collection_id, item_id, bidder]
+        BidRetracted(T::CollectionId, T::ItemId, T::AccountId),
+        /// A bid fell out of the bids an auction ranks and can be claimed back.
+        /// [collection_id, item_id, bidder, amount]
+        BidOutranked(T::CollectionId, T::ItemId, T::AccountId, BalanceOf<T>),
+        /// A bidder claimed back a bid that no longer competes. [collection_id, item_id, bidder, amount]
+        RefundClaimed(T::CollectionId, T::ItemId, T::AccountId, BalanceOf<T>),
+        /// A collection owner set the royalty terms of the collection. [collection_id, rate]
+        CollectionRoyaltySet(T::CollectionId, Perbill),
+        /// Governance changed the highest royalty rate collections can charge. [max_royalty]
+        MaxRoyaltySet(Perbill),
+        /// A royalty was paid on a sale. [collection_id, recipient, asset, amount]
+        RoyaltyPaid(T::CollectionId, T::AccountId, AssetOf<T>, BalanceOf<T>),
+        /// The proceeds of an auction sale were put in escrow.
+        /// [collection_id, item_id, seller, amount, release_at]
+        ProceedsEscrowed(T::CollectionId, T::ItemId, T::AccountId, BalanceOf<T>, BlockNumberFor<T>),
+        /// A buyer disputed an escrowed sale. [collection_id, item_id, buyer]
+        DisputeOpened(T::CollectionId, T::ItemId, T::AccountId),
+        /// Escrowed proceeds were released to the seller. [collection_id, item_id, seller, amount]
+        EscrowReleased(T::CollectionId, T::ItemId, T::AccountId, BalanceOf<T>),
+        /// A dispute was upheld, the escrowed proceeds and fee refunded to the buyer and the NFT
+        /// returned to the seller. [collection_id, item_id, buyer, amount]
+        EscrowRefunded(T::CollectionId, T::ItemId, T::AccountId, BalanceOf<T>),
+        /// NFTs of a collection were listed together as one bundle auction, keyed by the first
+        /// of them. [collection_id, item_id, owner, items]
+        BundleListed(T::CollectionId, T::ItemId, T::AccountId, u32),
+        /// A maximum bid was placed or raised, its amount stays hidden behind the bids placed
+        /// for it. [collection_id, item_id, bidder]
+        MaxBidPlaced(T::CollectionId, T::ItemId, T::AccountId),
+        /// Governance changed the marketplace parameters. [params]
+        MarketParamsUpdated(MarketParametersOf<T>),
+        /// The marketplace fee was set. [fee]
+        MarketFeeSet(Permill),
+        /// The fee tiers of sellers in a settlement asset were set. [asset, tiers]
+        FeeTiersSet(AssetOf<T>, u32),
+        /// The fee of a collection was set, or removed. [collection_id, fee]
+        CollectionFeeSet(T::CollectionId, Option<Permill>),
+        /// The fee distribution policy was set, or removed. [policy]
+        FeeDistributionSet(Option<FeeDistributionOf<T>>),
+        /// Accumulated fees were distributed. [asset, treasury, burned, rewards]
+        FeesDistributed(AssetOf<T>, BalanceOf<T>, BalanceOf<T>, BalanceOf<T>),
+        /// Accumulated fees were moved into the treasury account. [asset, treasury, amount]
+        FeesWithdrawn(AssetOf<T>, T::AccountId, BalanceOf<T>),
         BatchNftsListed(T::AccountId, u32),
     }
 
@@ -150,8 +829,97 @@ pub mod pallet {
         NoValidBuyer,
         /// Collection or item does not exist
         NftNotFound,
+        /// Fee tiers must be ordered by strictly ascending volume
         InvalidFee,
         NoFeesAvailable,
+        /// Percentage bid increment is above 100
+        InvalidBidIncrement,
+        /// Auction duration is outside the allowed range
+        InvalidAuctionDuration,
+        /// Too many auctions already end in the requested block
+        TooManyAuctionsExpiring,
+        /// Buy-now price is zero or below the reserve price
+        InvalidBuyNowPrice,
+        /// Auction has no buy-now option, or bidding has withdrawn it
+        BuyNowUnavailable,
+        /// NFT is already listed for sale
+        NftAlreadyListed,
+        /// NFT is not listed for sale
+        ListingNotFound,
+        /// Price must be greater than zero
+        InvalidPrice,
+        /// Cannot buy or make an offer on an owned NFT
+        CannotBuyOwnNft,
+        /// Offer does not exist
+        OfferNotFound,
+        /// Offer is past its expiry block
+        OfferExpired,
+        /// Offer expiry must be in the future
+        InvalidOfferExpiry,
+        /// Dutch auction prices must fall from a non-zero start price to a lower floor
+        InvalidDutchAuction,
+        /// Sealed-bid auctions only take committed bids and settle once revealed
+        SealedBidAuction,
+        /// Auction is not a sealed-bid auction
+        NotSealedBidAuction,
+        /// Sealed-bid auction is not in its commit phase
+        NotInCommitPhase,
+        /// Sealed-bid auction is not in its reveal phase
+        NotInRevealPhase,
+        /// No committed bid from this account
+        SealedBidNotFound,
+        /// Sealed bid was already revealed
+        AlreadyRevealed,
+        /// Revealed amount and salt do not match the commitment, or exceed the deposit
+        InvalidReveal,
+        /// No bid from this account on the auction
+        BidNotFound,
+        /// The bid still competes in a running auction
+        BidStillActive,
+        /// There are no bids to claim back
+        NoRefundAvailable,
+        /// The leading bid cannot be retracted
+        CannotRetractLeadingBid,
+        /// Bid was placed too recently to be retracted
+        RetractionCooldown,
+        /// Settlement asset does not exist
+        UnknownAsset,
+        /// Not the owner of the collection
+        NotCollectionOwner,
+        /// Royalty rate is above the maximum set by governance
+        RoyaltyTooHigh,
+        /// A royalty needs at least one recipient and every recipient a non-zero weight
+        InvalidRoyaltyRecipients,
+        /// The proceeds of the NFT's last sale are still in escrow
+        NftInEscrow,
+        /// No escrow for the NFT
+        EscrowNotFound,
+        /// Only the buyer can dispute the sale
+        NotEscrowBuyer,
+        /// The dispute window of the escrow has closed
+        DisputeWindowClosed,
+        /// The escrow is disputed and waits for the dispute to be resolved
+        EscrowDisputed,
+        /// The escrow is not disputed
+        EscrowNotDisputed,
+        /// The dispute window of the escrow is still open
+        EscrowNotDue,
+        /// A bundle needs at least two distinct NFTs of one collection
+        InvalidBundle,
+        /// Maximum bids are only taken by English auctions
+        MaxBidNotSupported,
+        /// The auction is gated and the bidder does not meet its access rule
+        NotEligibleBidder,
+        /// A marketplace parameter is outside its allowed range
+        InvalidMarketParams,
+        /// The batch lists more NFTs than the marketplace allows
+        BatchTooLarge,
+        /// Fee shares must add up to the whole and the period must be non-zero
+        InvalidFeeDistribution,
+        /// A sealed-bid auction cannot be cancelled once bids are committed to it
+        SealedBidsCommitted,
+        /// The buyer no longer holds the NFT a refund returns to the seller
+        NftNotReturnable,
     }
 
     #[pallet::pallet]
@@ -162,41 +930,128 @@ pub mod pallet {
     #[pallet::hooks]
     impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
         fn on_initialize(now: BlockNumberFor<T>) -> Weight {
-            let mut weight = Weight::zero();
+            let mut weight = T::DbWeight::get().reads_writes(3, 2);
+            let mut budget = T::MaxResolutionsPerBlock::get() as usize;
+
+            // Carry on with an unfinished distribution before starting a new one
+            let resume_after = FeeDistributionBacklog::<T>::take();
+            if let Some(policy) = FeeDistributionPolicy::<T>::get() {
+                if resume_after.is_some() || (now % policy.period).is_zero() {
+                    weight = weight.saturating_add(Self::distribute_fees(&policy, resume_after));
+                }
+            }
 
-            // Check for auctions that need to be auto-resolved
-            let mut auctions_to_resolve = Vec::new();
+            // Pick up where a previous block ran out of budget, otherwise only this block is due
+            let mut block = ExpiryBacklog::<T>::take().unwrap_or(now);
 
-            for ((collection_id, item_id), auction_info) in Auctions::<T>::iter() {
-                if !auction_info.ended
-                    && now >= auction_info.start_block + T::AuctionTimeoutBlocks::get()
-                {
-                    auctions_to_resolve.push((collection_id, item_id));
+            while block <= now {
+                if budget == 0 {
+                    ExpiryBacklog::<T>::put(block);
+                    break;
+                }
+                budget -= 1;
+
+                let mut due = AuctionExpiries::<T>::take(block).into_inner();
+                weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
+
+                // Keep whatever does not fit in this block's budget for the next one
+                let carried_over = due.split_off(due.len().min(budget));
+                budget -= due.len();
+
+                for (collection_id, item_id) in due {
+                    // Entries go stale when an auction is settled before its end block
+                    let expired = Auctions::<T>::get((collection_id.clone(), item_id))
+                        .is_some_and(|auction_info| {
+                            !auction_info.ended && now >= auction_info.end_block
+                        });
+                    weight = weight.saturating_add(T::DbWeight::get().reads(1));
+
+                    if expired {
+                        // Settling a bundle thaws or transfers each of its NFTs
+                        let items = Self::auction_items(&collection_id, &item_id).len() as u64;
+                        let _ = Self::auto_resolve_auction(&collection_id, &item_id);
+                        weight = weight
+                            .saturating_add(T::DbWeight::get().reads(1))
+                            .saturating_add(
+                                T::DbWeight::get().reads_writes(8, 7).saturating_mul(items),
+                            );
+                    }
+                }
+
+                if !carried_over.is_empty() {
+                    AuctionExpiries::<T>::insert(block, BoundedVec::truncate_from(carried_over));
+                    ExpiryBacklog::<T>::put(block);
+                    break;
                 }
-                weight = weight.saturating_add(T::DbWeight::get().reads(1));
-            }
 
-            // Resolve expired auctions
-            for (collection_id, item_id) in auctions_to_resolve {
-                let _ = Self::auto_resolve_auction(&collection_id, &item_id);
-                weight = weight.saturating_add(T::DbWeight::get().reads_writes(3, 2));
+                block = block.saturating_add(One::one());
             }
 
             weight
         }
+
+        fn integrity_test() {
+            // Reading the schedule of a block takes one unit of the budget
+            assert!(
+                T::MaxResolutionsPerBlock::get() >= 2,
+                "`MaxResolutionsPerBlock` must leave room to resolve an auction after reading a block"
+            );
+            assert!(
+                T::BuyNowBidThreshold::get() <= 100,
+                "`BuyNowBidThreshold` is a percentage"
+            );
+            assert!(
+                T::CancellationPenalty::get() <= 100,
+                "`CancellationPenalty` is a percentage"
+            );
+            // Sales history and buckets make room by dropping their oldest entry
+            assert!(T::MaxSalesHistory::get() > 0, "`MaxSalesHistory` must be non-zero");
+            assert!(T::MaxStatsBuckets::get() > 0, "`MaxStatsBuckets` must be non-zero");
+            assert!(
+                T::MaxFeeAssetsPerDistribution::get() > 0,
+                "`MaxFeeAssetsPerDistribution` must be non-zero"
+            );
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn try_state(_n: BlockNumberFor<T>) -> Result<(), sp_runtime::TryRuntimeError> {
+            Self::do_try_state()
+        }
     }
 
     #[pallet::call]
     impl<T: Config> Pallet<T> {
         // List an NFT for auction
         #[pallet::call_index(0)]
-        #[pallet::weight(T::DbWeight::get().reads_writes(3, 2))]
+        #[pallet::weight(T::DbWeight::get().reads_writes(3, 3))]
         pub fn list_nft_for_auction(
             origin: OriginFor<T>,
             collection_id: T::CollectionId,
             item_id: T::ItemId,
+            settings: AuctionSettingsOf<T>,
+            asset: AssetOf<T>,
         ) -> DispatchResult {
             let owner = ensure_signed(origin)?;
+            let AuctionSettings { reserve_price, min_increment, duration, buy_now_price, access } =
+                settings;
+
+            Self::ensure_asset_exists(&asset)?;
+
+            let start_block = <frame_system::Pallet<T>>::block_number();
+            let end_block = Self::auction_end_block(start_block, duration)?;
+
+            // Ensure a percentage increment is a valid percentage
+            if let Some(BidIncrement::Percentage(percent)) = min_increment {
+                ensure!(percent <= 100, Error::<T>::InvalidBidIncrement);
+            }
+
+            // Ensure buying outright is never cheaper than the reserve
+            if let Some(price) = buy_now_price {
+                ensure!(
+                    !price.is_zero() && reserve_price.is_none_or(|reserve| price >= reserve),
+                    Error::<T>::InvalidBuyNowPrice
+                );
+            }
 
             // Ensure collection and item exist
             ensure!(
@@ -213,9 +1068,22 @@ pub mod pallet {
 
             // Ensure NFT is not already in an auction
             ensure!(
-                !InAuction::<T>::get((collection_id.clone(), item_id.clone())),
+                !Self::is_in_auction((collection_id.clone(), item_id.clone())),
                 Error::<T>::NftAlreadyInAuction
             );
+            ensure!(
+                !Self::is_in_escrow((collection_id.clone(), item_id)),
+                Error::<T>::NftInEscrow
+            );
+
+            // Ensure NFT is not listed for sale
+            ensure!(
+                !Listings::<T>::contains_key((collection_id.clone(), item_id)),
+                Error::<T>::NftAlreadyListed
+            );
+
+            // Schedule the auction to auto-resolve at its end block
+            Self::schedule_expiry(end_block, &collection_id, &item_id)?;
 
             // Freeze the Nft
             pallet_uniques::Pallet::<T>::freeze(
@@ -227,15 +1095,20 @@ pub mod pallet {
             // Create auction info
             let auction_info = AuctionInfo {
                 owner: owner.clone(),
-                start_block: <frame_system::Pallet<T>>::block_number(),
+                start_block,
+                end_block,
                 highest_bid: Zero::zero(),
                 highest_bidder: None,
                 ended: false,
+                reserve_price,
+                min_increment,
+                extended_by: Zero::zero(),
+                buy_now_price,
+                kind: AuctionKind::English,
+                asset,
             };
             Auctions::<T>::insert((collection_id.clone(), item_id.clone()), auction_info);
-
-            // Mark NFT as in auction
-            InAuction::<T>::insert((collection_id.clone(), item_id.clone()), true);
+            AuctionAccess::<T>::set((collection_id.clone(), item_id), access);
 
             // Emit event
             Self::deposit_event(Event::NftListed(collection_id, item_id, owner));
@@ -245,7 +1118,7 @@ pub mod pallet {
 
         // Place a bid on an NFT
         #[pallet::call_index(1)]
-        #[pallet::weight(T::DbWeight::get().reads_writes(4, 2))]
+        #[pallet::weight(T::DbWeight::get().reads_writes(8, 5))]
         pub fn place_bid(
             origin: OriginFor<T>,
             collection_id: T::CollectionId,
@@ -253,81 +1126,30 @@ pub mod pallet {
             bid_amount: BalanceOf<T>,
         ) -> DispatchResult {
             let bidder = ensure_signed(origin)?;
+            Self::do_place_bid(bidder, collection_id, item_id, bid_amount, false)
+        }
 
-            // Ensure auction exists and is active
-            let auction_info = Auctions::<T>::get((collection_id.clone(), item_id.clone()))
-                .ok_or(Error::<T>::AuctionNotFound)?;
-            ensure!(!auction_info.ended, Error::<T>::AuctionEnded);
-
-            // Ensure bidder is not the auction owner
-            ensure!(
-                bidder != auction_info.owner,
-                Error::<T>::CannotBidOnOwnAuction
-            );
-
-            // Ensure bid is higher than current highest bid
-            ensure!(bid_amount > auction_info.highest_bid, Error::<T>::BidTooLow);
-
-            // Check if bidder has enough funds and reserve them
-            <T as Config>::Currency::reserve(&bidder, bid_amount)?;
-
-            // If there's a previous highest bidder, unreserve their funds
-            if let Some(highest_bidder) = auction_info.highest_bidder {
-                if highest_bidder != bidder {
-                    let _ = <T as Config>::Currency::unreserve(
-                        &highest_bidder,
-                        auction_info.highest_bid,
-                    );
-                } else {
-                    // If same bidder is increasing their bid, unreserve previous amount
-                    let _ = <T as Config>::Currency::unreserve(&bidder, auction_info.highest_bid);
-                }
-            }
-
-            // Update auction with new highest bid
-            let new_auction_info = AuctionInfo {
-                highest_bid: bid_amount,
-                highest_bidder: Some(bidder.clone()),
-                ..auction_info
-            };
-            Auctions::<T>::insert((collection_id.clone(), item_id.clone()), new_auction_info);
-
-            // Update bids collection
-            let mut bids = Bids::<T>::get((collection_id.clone(), item_id.clone()));
-
-            // Remove previous bid by this bidder if exists
-            bids.retain(|(b, _)| b != &bidder);
-
-            // Add new bid, ensuring it's sorted (highest first)
-            let new_bid = (bidder.clone(), bid_amount);
-            match bids.binary_search_by(|(_, b)| b.cmp(&bid_amount).reverse()) {
-                Ok(pos) | Err(pos) => {
-                    if bids.len() == T::MaxBidsPerAuction::get() as usize && pos >= bids.len() {
-                        // New bid is too low to be included in max bids
-                        return Err(Error::<T>::BidTooLow.into());
-                    }
-
-                    if bids.len() == T::MaxBidsPerAuction::get() as usize {
-                        // Remove lowest bid if at capacity
-                        bids.pop();
-                    }
-
-                    if let Err(_e) = bids.try_insert(pos, new_bid) {
-                        return Err(Error::<T>::TooManyBids.into());
-                    }
-                }
-            }
-            Bids::<T>::insert((collection_id.clone(), item_id.clone()), bids);
-
-            // Emit event
-            Self::deposit_event(Event::BidPlaced(collection_id, item_id, bidder, bid_amount));
-
-            Ok(())
-        }
+        /// Bid up to a hidden maximum. Only the maximum is held, while the pallet bids the
+        /// least needed to lead and raises the bid automatically whenever someone else bids,
+        /// until the maximum is reached. Between two maximum bids the higher one leads, at one
+        /// increment over the other, and the earlier one on a tie.
+        #[pallet::call_index(28)]
+        #[pallet::weight(T::DbWeight::get().reads_writes(10, 7))]
+        pub fn place_max_bid(
+            origin: OriginFor<T>,
+            collection_id: T::CollectionId,
+            item_id: T::ItemId,
+            max_amount: BalanceOf<T>,
+        ) -> DispatchResult {
+            let bidder = ensure_signed(origin)?;
+            Self::do_place_bid(bidder, collection_id, item_id, max_amount, true)
+        }
 
         // Resolve auction by choosing a buyer
         #[pallet::call_index(2)]
-        #[pallet::weight(T::DbWeight::get().reads_writes(4, 3))]
+        #[pallet::weight(
+            T::DbWeight::get().reads_writes(4, 3).saturating_mul(T::MaxBundleSize::get().into())
+        )]
         pub fn resolve_auction(
             origin: OriginFor<T>,
             collection_id: T::CollectionId,
@@ -345,11 +1167,23 @@ pub mod pallet {
             // Ensure caller is the auction owner
             ensure!(who == auction_info.owner, Error::<T>::NotNftOwner);
 
+            // Sealed-bid auctions settle on their own once the reveal phase is over
+            ensure!(
+                !matches!(auction_info.kind, AuctionKind::Sealed(_)),
+                Error::<T>::SealedBidAuction
+            );
+
             // Require at least one bid
             let highest_bidder = auction_info
                 .highest_bidder
+                .clone()
                 .ok_or(Error::<T>::NoValidBuyer)?;
 
+            // Return the NFT to its owner if the reserve price was not reached
+            if !Self::meets_reserve(&auction_info, auction_info.highest_bid) {
+                return Self::end_with_reserve_not_met(&collection_id, &item_id, auction_info);
+            }
+
             // Finalize the auction
             Self::finalize_auction(
                 &collection_id,
@@ -361,38 +1195,31 @@ pub mod pallet {
             Ok(())
         }
 
+        /// Set the marketplace fee charged on sales
         #[pallet::call_index(3)]
         #[pallet::weight(10_000)]
-        pub fn set_fee_percentage(origin: OriginFor<T>, fee: u8) -> DispatchResult {
-            ensure_root(origin)?; // Only Sudo (Root) can call
-            ensure!(fee <= 100, Error::<T>::InvalidFee);
-            FeePercentage::<T>::put(fee);
-            Self::deposit_event(Event::FeePercentageSet(fee));
+        pub fn set_market_fee(origin: OriginFor<T>, fee: Permill) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+            MarketFee::<T>::put(fee);
+            Self::deposit_event(Event::MarketFeeSet(fee));
             Ok(())
         }
 
+        /// Move the accumulated fees of a settlement asset into the treasury account, where
+        /// spending them takes a treasury spend
         #[pallet::call_index(4)]
         #[pallet::weight(10_000)]
-        pub fn withdraw_fees(origin: OriginFor<T>, to: T::AccountId) -> DispatchResult {
-            ensure_root(origin)?;
+        pub fn withdraw_fees(origin: OriginFor<T>, asset: AssetOf<T>) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+            let to = T::TreasuryAccount::get();
 
-            let total_fees = AccumulatedFees::<T>::take();
+            let total_fees = AccumulatedFees::<T>::take(asset);
             if total_fees.is_zero() {
                 Err(Error::<T>::NoFeesAvailable)?
             }
 
-            log::info!(
-                "Pallet account balance: {:?}",
-                <T as Config>::Currency::free_balance(&Self::account_id())
-            );
-
-            <T as Config>::Currency::transfer(
-                &Self::account_id(),
-                &to,
-                total_fees,
-                ExistenceRequirement::AllowDeath,
-            )?;
-            Self::deposit_event(Event::FeesWithdrawn(to, total_fees));
+            Self::transfer_fees(&asset, &to, total_fees)?;
+            Self::deposit_event(Event::FeesWithdrawn(asset, to, total_fees));
             Ok(())
         }
 
@@ -404,15 +1231,20 @@ pub mod pallet {
 ))]
         pub fn batch_list_nfts_for_auction(
             origin: OriginFor<T>,
-            batch_info: BatchListingInfo<
-                T::CollectionId,
-                T::ItemId,
-                BalanceOf<T>,
-                BlockNumberFor<T>,
-            >,
+            batch_info: BatchListingInfoOf<T>,
         ) -> DispatchResult {
             let owner = ensure_signed(origin)?;
 
+            Self::ensure_asset_exists(&batch_info.asset)?;
+
+            let start_block = <frame_system::Pallet<T>>::block_number();
+            let end_block = Self::auction_end_block(start_block, batch_info.custom_timeout)?;
+
+            ensure!(
+                batch_info.nfts.len() as u32 <= MarketParams::<T>::get().max_batch_listing_size,
+                Error::<T>::BatchTooLarge
+            );
+
             // Validate all NFTs before processing any
             for (collection_id, item_id) in &batch_info.nfts {
                 // Ensure collection and item exist
@@ -430,13 +1262,26 @@ pub mod pallet {
 
                 // Ensure NFT is not already in an auction
                 ensure!(
-                    !InAuction::<T>::get((collection_id.clone(), item_id.clone())),
+                    !Self::is_in_auction((collection_id.clone(), item_id.clone())),
                     Error::<T>::NftAlreadyInAuction
                 );
+                ensure!(
+                    !Self::is_in_escrow((collection_id.clone(), *item_id)),
+                    Error::<T>::NftInEscrow
+                );
+
+                // Ensure NFT is not listed for sale
+                ensure!(
+                    !Listings::<T>::contains_key((collection_id.clone(), *item_id)),
+                    Error::<T>::NftAlreadyListed
+                );
             }
 
             // Process all NFTs after validation
             for (collection_id, item_id) in &batch_info.nfts {
+                // Schedule the auction to auto-resolve at its end block
+                Self::schedule_expiry(end_block, collection_id, item_id)?;
+
                 // Freeze the NFT
                 pallet_uniques::Pallet::<T>::freeze(
                     frame_system::RawOrigin::Signed(owner.clone()).into(),
@@ -447,17 +1292,21 @@ pub mod pallet {
                 // Create auction info with custom parameters if provided
                 let auction_info = AuctionInfo {
                     owner: owner.clone(),
-                    start_block: <frame_system::Pallet<T>>::block_number(),
+                    start_block,
+                    end_block,
                     highest_bid: batch_info.min_bid.unwrap_or_else(|| Zero::zero()),
                     highest_bidder: None,
                     ended: false,
+                    reserve_price: None,
+                    min_increment: None,
+                    extended_by: Zero::zero(),
+                    buy_now_price: None,
+                    kind: AuctionKind::English,
+                    asset: batch_info.asset,
                 };
 
                 Auctions::<T>::insert((collection_id.clone(), item_id.clone()), auction_info);
 
-                // Mark NFT as in auction
-                InAuction::<T>::insert((collection_id.clone(), item_id.clone()), true);
-
                 // Emit event for each NFT listed
                 Self::deposit_event(Event::NftListed(
                     collection_id.clone(),
@@ -470,164 +1319,2453 @@ pub mod pallet {
             Self::deposit_event(Event::BatchNftsListed(owner, batch_info.nfts.len() as u32));
             Ok(())
         }
-    }
 
-    impl<T: Config> Pallet<T> {
-        pub fn account_id() -> T::AccountId {
-            T::PalletId::get().into_account_truncating()
+        /// Buy an NFT outright at the auction's buy-now price
+        #[pallet::call_index(6)]
+        #[pallet::weight(
+            T::DbWeight::get().reads_writes(6, 6).saturating_mul(T::MaxBundleSize::get().into())
+        )]
+        pub fn buy_now(
+            origin: OriginFor<T>,
+            collection_id: T::CollectionId,
+            item_id: T::ItemId,
+        ) -> DispatchResult {
+            let buyer = ensure_signed(origin)?;
+
+            // Ensure auction exists and is active
+            let auction_info = Auctions::<T>::get((collection_id.clone(), item_id))
+                .ok_or(Error::<T>::AuctionNotFound)?;
+            ensure!(!auction_info.ended, Error::<T>::AuctionEnded);
+
+            // Ensure buyer is not the auction owner
+            ensure!(
+                buyer != auction_info.owner,
+                Error::<T>::CannotBidOnOwnAuction
+            );
+            Self::ensure_eligible_bidder(&(collection_id.clone(), item_id), &buyer)?;
+
+            let price = auction_info
+                .buy_now_price
+                .ok_or(Error::<T>::BuyNowUnavailable)?;
+
+            Self::finalize_auction(&collection_id, &item_id, &buyer, price)
         }
 
-        // Auto-resolve auction after timeout
-        fn auto_resolve_auction(
-            collection_id: &T::CollectionId,
-            item_id: &T::ItemId,
+        /// List an NFT for sale at a fixed price
+        #[pallet::call_index(7)]
+        #[pallet::weight(T::DbWeight::get().reads_writes(4, 2))]
+        pub fn list_nft_for_sale(
+            origin: OriginFor<T>,
+            collection_id: T::CollectionId,
+            item_id: T::ItemId,
+            price: BalanceOf<T>,
+            asset: AssetOf<T>,
         ) -> DispatchResult {
-            // Get auction info
-            let mut auction_info =
-                Auctions::<T>::get((collection_id, item_id)).ok_or(Error::<T>::AuctionNotFound)?;
+            let seller = ensure_signed(origin)?;
 
-            // Check if auction is still active
-            if auction_info.ended {
-                return Err(Error::<T>::AuctionEnded.into());
-            }
+            ensure!(!price.is_zero(), Error::<T>::InvalidPrice);
+            Self::ensure_asset_exists(&asset)?;
 
-            // Try to finalize auction with the highest bidder
-            if let Some(highest_bidder) = &auction_info.highest_bidder {
-                // Try to transfer NFT and funds
-                if Self::finalize_auction(
-                    collection_id,
-                    item_id,
-                    highest_bidder,
-                    auction_info.highest_bid,
-                )
-                .is_err()
-                {
-                    // If transfer fails, try next highest bidders
-                    let bids = Bids::<T>::get((collection_id, item_id));
-                    for (bidder, bid_amount) in bids.iter() {
-                        if bidder != highest_bidder
-                            && Self::finalize_auction(collection_id, item_id, bidder, *bid_amount)
-                                .is_ok()
-                        {
-                            return Ok(());
-                        }
-                    }
-                    // If all transfers fail, emit auction failed event
-                    auction_info.ended = true;
-                    Auctions::<T>::insert((collection_id, item_id), &auction_info);
-                    Self::deposit_event(Event::AuctionFailed(collection_id.clone(), *item_id));
-                }
-            } else {
-                // No bids, auction failed
-                auction_info.ended = true;
-                Auctions::<T>::insert((collection_id, item_id), &auction_info);
-                Self::deposit_event(Event::AuctionFailed(collection_id.clone(), *item_id));
-            }
+            // Ensure caller is the NFT owner
+            let nft_owner = pallet_uniques::Pallet::<T>::owner(collection_id.clone(), item_id)
+                .ok_or(Error::<T>::NftNotFound)?;
+            ensure!(seller == nft_owner, Error::<T>::NotNftOwner);
+
+            // Ensure NFT is neither in an auction nor already listed
+            ensure!(
+                !Self::is_in_auction((collection_id.clone(), item_id)),
+                Error::<T>::NftAlreadyInAuction
+            );
+            ensure!(
+                !Self::is_in_escrow((collection_id.clone(), item_id)),
+                Error::<T>::NftInEscrow
+            );
+            ensure!(
+                !Listings::<T>::contains_key((collection_id.clone(), item_id)),
+                Error::<T>::NftAlreadyListed
+            );
+
+            // Freeze the NFT while it is listed
+            pallet_uniques::Pallet::<T>::freeze(
+                frame_system::RawOrigin::Signed(seller.clone()).into(),
+                collection_id.clone(),
+                item_id,
+            )?;
+
+            Listings::<T>::insert(
+                (collection_id.clone(), item_id),
+                ListingInfo { seller: seller.clone(), price, asset },
+            );
 
+            Self::deposit_event(Event::NftListedForSale(collection_id, item_id, seller, price));
             Ok(())
         }
 
-        // Finalize auction by transferring NFT and handling funds
-        #[transactional]
-        fn finalize_auction(
-            collection_id: &T::CollectionId,
-            item_id: &T::ItemId,
-            buyer: &T::AccountId,
-            bid_amount: BalanceOf<T>,
+        /// Change the price of a fixed-price listing
+        #[pallet::call_index(8)]
+        #[pallet::weight(T::DbWeight::get().reads_writes(1, 1))]
+        pub fn update_listing_price(
+            origin: OriginFor<T>,
+            collection_id: T::CollectionId,
+            item_id: T::ItemId,
+            price: BalanceOf<T>,
         ) -> DispatchResult {
-            // Retrieve auction information
-            let auction_info =
-                Auctions::<T>::get((collection_id, item_id)).ok_or(Error::<T>::AuctionNotFound)?;
+            let who = ensure_signed(origin)?;
 
-            // Ensure auction hasn't already ended
-            ensure!(!auction_info.ended, Error::<T>::AuctionEnded);
+            ensure!(!price.is_zero(), Error::<T>::InvalidPrice);
 
-            // Verify current NFT ownership
-            let current_owner =
-                pallet_uniques::Pallet::<T>::owner(collection_id.clone(), item_id.clone())
-                    .ok_or(Error::<T>::NftNotFound)?;
-            ensure!(current_owner == auction_info.owner, Error::<T>::NotNftOwner);
+            Listings::<T>::try_mutate((collection_id.clone(), item_id), |maybe_listing| {
+                let listing = maybe_listing.as_mut().ok_or(Error::<T>::ListingNotFound)?;
+                ensure!(who == listing.seller, Error::<T>::NotNftOwner);
+                listing.price = price;
+                Ok::<_, DispatchError>(())
+            })?;
 
-            // Validate buyer's funds
+            Self::deposit_event(Event::ListingPriceUpdated(collection_id, item_id, price));
+            Ok(())
+        }
+
+        /// Withdraw a fixed-price listing and unfreeze the NFT
+        #[pallet::call_index(9)]
+        #[pallet::weight(T::DbWeight::get().reads_writes(2, 2))]
+        pub fn delist_nft(
+            origin: OriginFor<T>,
+            collection_id: T::CollectionId,
+            item_id: T::ItemId,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let listing = Listings::<T>::get((collection_id.clone(), item_id))
+                .ok_or(Error::<T>::ListingNotFound)?;
+            ensure!(who == listing.seller, Error::<T>::NotNftOwner);
+
+            pallet_uniques::Pallet::<T>::thaw(
+                frame_system::RawOrigin::Signed(listing.seller).into(),
+                collection_id.clone(),
+                item_id,
+            )?;
+            Listings::<T>::remove((collection_id.clone(), item_id));
+
+            Self::deposit_event(Event::NftDelisted(collection_id, item_id));
+            Ok(())
+        }
+
+        /// Buy an NFT at its listed price
+        #[pallet::call_index(10)]
+        #[pallet::weight(T::DbWeight::get().reads_writes(5, 6))]
+        pub fn buy_nft(
+            origin: OriginFor<T>,
+            collection_id: T::CollectionId,
+            item_id: T::ItemId,
+        ) -> DispatchResult {
+            let buyer = ensure_signed(origin)?;
+
+            let listing = Listings::<T>::take((collection_id.clone(), item_id))
+                .ok_or(Error::<T>::ListingNotFound)?;
+            ensure!(buyer != listing.seller, Error::<T>::CannotBuyOwnNft);
+
+            Self::settle_sale(
+                &collection_id,
+                &listing.seller,
+                &buyer,
+                listing.price,
+                &listing.asset,
+                None,
+                false,
+            )?;
+            Self::transfer_nft(&collection_id, &item_id, &listing.seller, &buyer)?;
+
+            Self::deposit_event(Event::NftSold(collection_id, item_id, buyer, listing.price));
+            Ok(())
+        }
+
+        /// Hold funds as an offer on any NFT, listed or not
+        #[pallet::call_index(11)]
+        #[pallet::weight(T::DbWeight::get().reads_writes(4, 3))]
+        pub fn make_offer(
+            origin: OriginFor<T>,
+            collection_id: T::CollectionId,
+            item_id: T::ItemId,
+            amount: BalanceOf<T>,
+            expires_at: BlockNumberFor<T>,
+        ) -> DispatchResult {
+            let buyer = ensure_signed(origin)?;
+
+            ensure!(!amount.is_zero(), Error::<T>::InvalidPrice);
             ensure!(
-                <T as Config>::Currency::can_slash(buyer, bid_amount),
-                Error::<T>::NoValidBuyer
+                expires_at > <frame_system::Pallet<T>>::block_number(),
+                Error::<T>::InvalidOfferExpiry
             );
 
-            // Calculate royalty (if applicable)
-            let royalty_percentage = T::RoyaltyPercentage::get();
-            let royalty_amount = bid_amount
-                .checked_mul(&BalanceOf::<T>::from(royalty_percentage as u32))
-                .and_then(|royalty| royalty.checked_div(&BalanceOf::<T>::from(100u32)))
-                .unwrap_or_else(|| Zero::zero());
+            let nft_owner = pallet_uniques::Pallet::<T>::owner(collection_id.clone(), item_id)
+                .ok_or(Error::<T>::NftNotFound)?;
+            ensure!(buyer != nft_owner, Error::<T>::CannotBuyOwnNft);
 
-            // Calculate seller's amount (total bid minus royalty)
-            let _seller_amount = bid_amount.saturating_sub(royalty_amount);
+            // A new offer replaces the buyer's previous one
+            if let Some(previous) = Offers::<T>::get((collection_id.clone(), item_id), &buyer) {
+                Self::release(&SettlementAsset::Native, HoldReason::Offer, &buyer, previous.amount);
+            }
+            Self::hold(&SettlementAsset::Native, HoldReason::Offer, &buyer, amount)?;
 
-            // Perform atomic transactions
-            let _ = <T as Config>::Currency::unreserve(buyer, bid_amount.into());
+            Offers::<T>::insert(
+                (collection_id.clone(), item_id),
+                &buyer,
+                OfferInfo { amount, expires_at },
+            );
 
-            // 1. Transfer funds from buyer
-            let _ = <T as Config>::Currency::withdraw(
-                buyer,
-                bid_amount,
-                WithdrawReasons::TRANSFER,
-                ExistenceRequirement::KeepAlive,
+            Self::deposit_event(Event::OfferMade(collection_id, item_id, buyer, amount, expires_at));
+            Ok(())
+        }
+
+        /// Withdraw an offer and release its funds, also after it expired
+        #[pallet::call_index(12)]
+        #[pallet::weight(T::DbWeight::get().reads_writes(2, 2))]
+        pub fn cancel_offer(
+            origin: OriginFor<T>,
+            collection_id: T::CollectionId,
+            item_id: T::ItemId,
+        ) -> DispatchResult {
+            let buyer = ensure_signed(origin)?;
+
+            let offer = Offers::<T>::take((collection_id.clone(), item_id), &buyer)
+                .ok_or(Error::<T>::OfferNotFound)?;
+            Self::release(&SettlementAsset::Native, HoldReason::Offer, &buyer, offer.amount);
+
+            Self::deposit_event(Event::OfferCancelled(collection_id, item_id, buyer));
+            Ok(())
+        }
+
+        /// Sell an NFT to the buyer of an offer on it
+        #[pallet::call_index(13)]
+        #[pallet::weight(T::DbWeight::get().reads_writes(6, 7))]
+        pub fn accept_offer(
+            origin: OriginFor<T>,
+            collection_id: T::CollectionId,
+            item_id: T::ItemId,
+            buyer: T::AccountId,
+        ) -> DispatchResult {
+            let owner = ensure_signed(origin)?;
+
+            let nft_owner = pallet_uniques::Pallet::<T>::owner(collection_id.clone(), item_id)
+                .ok_or(Error::<T>::NftNotFound)?;
+            ensure!(owner == nft_owner, Error::<T>::NotNftOwner);
+
+            // Auctioned NFTs can only be sold through the auction
+            ensure!(
+                !Self::is_in_auction((collection_id.clone(), item_id)),
+                Error::<T>::NftAlreadyInAuction
+            );
+            ensure!(
+                !Self::is_in_escrow((collection_id.clone(), item_id)),
+                Error::<T>::NftInEscrow
+            );
+
+            let offer = Offers::<T>::take((collection_id.clone(), item_id), &buyer)
+                .ok_or(Error::<T>::OfferNotFound)?;
+            ensure!(
+                <frame_system::Pallet<T>>::block_number() < offer.expires_at,
+                Error::<T>::OfferExpired
+            );
+
+            // The buyer pays out of the funds held for the offer
+            Self::settle_sale(
+                &collection_id,
+                &owner,
+                &buyer,
+                offer.amount,
+                &SettlementAsset::Native,
+                Some(HoldReason::Offer),
+                false,
             )?;
 
-            // 2. Pay royalty to collection creator (if applicable)
-            if royalty_amount > Zero::zero() {
-                if let Some(collection_admin) =
-                    pallet_uniques::Pallet::<T>::collection_owner(collection_id.clone())
-                {
-                    let _ = <T as Config>::Currency::deposit_creating(
-                        &collection_admin,
-                        royalty_amount,
-                    );
-                }
+            // Accepting an offer supersedes a fixed-price listing of the NFT
+            if Listings::<T>::take((collection_id.clone(), item_id)).is_some() {
+                Self::transfer_nft(&collection_id, &item_id, &owner, &buyer)?;
+            } else {
+                pallet_uniques::Pallet::<T>::do_transfer(
+                    collection_id.clone(),
+                    item_id,
+                    buyer.clone(),
+                    |_, _| Ok(()),
+                )?;
             }
 
-            let fee_percent = FeePercentage::<T>::get(); // e.g., 5
-            let fee_amount = bid_amount * fee_percent.into() / 100u32.into();
-            let payout = bid_amount.saturating_sub(fee_amount);
+            Self::deposit_event(Event::OfferAccepted(collection_id, item_id, buyer, offer.amount));
+            Ok(())
+        }
+
+        /// List an NFT for a Dutch auction whose price falls every block until someone bids
+        #[pallet::call_index(14)]
+        #[pallet::weight(T::DbWeight::get().reads_writes(4, 3))]
+        #[allow(clippy::too_many_arguments)]
+        pub fn list_nft_for_dutch_auction(
+            origin: OriginFor<T>,
+            collection_id: T::CollectionId,
+            item_id: T::ItemId,
+            start_price: BalanceOf<T>,
+            floor_price: BalanceOf<T>,
+            decay_per_block: BalanceOf<T>,
+            duration: Option<BlockNumberFor<T>>,
+            asset: AssetOf<T>,
+        ) -> DispatchResult {
+            let owner = ensure_signed(origin)?;
+
+            Self::ensure_asset_exists(&asset)?;
 
-            // 3. Pay remaining funds to auction owner
-            let _ = <T as Config>::Currency::deposit_creating(&auction_info.owner, payout);
+            let start_block = <frame_system::Pallet<T>>::block_number();
+            let end_block = Self::auction_end_block(start_block, duration)?;
 
-            // Transfer fees to pallet account
-            let _ = <T as Config>::Currency::deposit_creating(&Self::account_id(), fee_amount);
+            // Ensure the price actually falls towards the floor
+            ensure!(
+                !decay_per_block.is_zero() && floor_price < start_price,
+                Error::<T>::InvalidDutchAuction
+            );
 
-            // Add fee to pallet storage
-            AccumulatedFees::<T>::mutate(|f| *f += fee_amount);
+            // Ensure caller is the NFT owner
+            let nft_owner = pallet_uniques::Pallet::<T>::owner(collection_id.clone(), item_id)
+                .ok_or(Error::<T>::NftNotFound)?;
+            ensure!(owner == nft_owner, Error::<T>::NotNftOwner);
 
-            // 4. Unfreeze the NFT before transferring
-            pallet_uniques::Pallet::<T>::thaw(
-                frame_system::RawOrigin::Signed(auction_info.owner.clone()).into(),
-                collection_id.clone(),
-                *item_id,
-            )?;
+            // Ensure NFT is neither in an auction nor listed for sale
+            ensure!(
+                !Self::is_in_auction((collection_id.clone(), item_id)),
+                Error::<T>::NftAlreadyInAuction
+            );
+            ensure!(
+                !Self::is_in_escrow((collection_id.clone(), item_id)),
+                Error::<T>::NftInEscrow
+            );
+            ensure!(
+                !Listings::<T>::contains_key((collection_id.clone(), item_id)),
+                Error::<T>::NftAlreadyListed
+            );
 
-            // 5. Transfer NFT to the buyer
-            pallet_uniques::Pallet::<T>::do_transfer(
+            // Schedule the auction to auto-resolve at its end block
+            Self::schedule_expiry(end_block, &collection_id, &item_id)?;
+
+            // Freeze the Nft
+            pallet_uniques::Pallet::<T>::freeze(
+                frame_system::RawOrigin::Signed(owner.clone()).into(),
                 collection_id.clone(),
-                *item_id,
-                buyer.clone(),
-                |_, _| Ok(()),
+                item_id,
             )?;
 
-            // Update auction status
-            Auctions::<T>::mutate((collection_id, item_id), |auction| {
-                if let Some(auction_info) = auction {
-                    auction_info.ended = true;
-                    auction_info.highest_bidder = Some(buyer.clone());
-                }
-            });
-
-            // Remove from in-auction tracking
-            InAuction::<T>::remove((collection_id, item_id));
+            let auction_info = AuctionInfo {
+                owner: owner.clone(),
+                start_block,
+                end_block,
+                highest_bid: Zero::zero(),
+                highest_bidder: None,
+                ended: false,
+                reserve_price: None,
+                min_increment: None,
+                extended_by: Zero::zero(),
+                buy_now_price: None,
+                kind: AuctionKind::Dutch { start_price, floor_price, decay_per_block },
+                asset,
+            };
+            Auctions::<T>::insert((collection_id.clone(), item_id), auction_info);
+
+            Self::deposit_event(Event::NftListed(collection_id, item_id, owner));
+            Ok(())
+        }
+
+        /// List an NFT for a sealed-bid auction, bids are committed as hashes and revealed
+        /// during the last `SealedBidRevealPeriod` blocks
+        #[pallet::call_index(15)]
+        #[pallet::weight(T::DbWeight::get().reads_writes(4, 3))]
+        pub fn list_nft_for_sealed_auction(
+            origin: OriginFor<T>,
+            collection_id: T::CollectionId,
+            item_id: T::ItemId,
+            reserve_price: Option<BalanceOf<T>>,
+            duration: Option<BlockNumberFor<T>>,
+            pricing: SealedBidPricing,
+            asset: AssetOf<T>,
+        ) -> DispatchResult {
+            let owner = ensure_signed(origin)?;
+
+            Self::ensure_asset_exists(&asset)?;
+
+            let start_block = <frame_system::Pallet<T>>::block_number();
+            let end_block = Self::auction_end_block(start_block, duration)?;
+
+            // Ensure there is a commit phase before the reveal phase
+            ensure!(
+                end_block.saturating_sub(start_block) > T::SealedBidRevealPeriod::get(),
+                Error::<T>::InvalidAuctionDuration
+            );
+
+            // Ensure caller is the NFT owner
+            let nft_owner = pallet_uniques::Pallet::<T>::owner(collection_id.clone(), item_id)
+                .ok_or(Error::<T>::NftNotFound)?;
+            ensure!(owner == nft_owner, Error::<T>::NotNftOwner);
+
+            // Ensure NFT is neither in an auction nor listed for sale
+            ensure!(
+                !Self::is_in_auction((collection_id.clone(), item_id)),
+                Error::<T>::NftAlreadyInAuction
+            );
+            ensure!(
+                !Self::is_in_escrow((collection_id.clone(), item_id)),
+                Error::<T>::NftInEscrow
+            );
+            ensure!(
+                !Listings::<T>::contains_key((collection_id.clone(), item_id)),
+                Error::<T>::NftAlreadyListed
+            );
+
+            // Schedule the auction to settle at its end block
+            Self::schedule_expiry(end_block, &collection_id, &item_id)?;
+
+            // Freeze the Nft
+            pallet_uniques::Pallet::<T>::freeze(
+                frame_system::RawOrigin::Signed(owner.clone()).into(),
+                collection_id.clone(),
+                item_id,
+            )?;
+
+            let auction_info = AuctionInfo {
+                owner: owner.clone(),
+                start_block,
+                end_block,
+                highest_bid: Zero::zero(),
+                highest_bidder: None,
+                ended: false,
+                reserve_price,
+                min_increment: None,
+                extended_by: Zero::zero(),
+                buy_now_price: None,
+                kind: AuctionKind::Sealed(pricing),
+                asset,
+            };
+            Auctions::<T>::insert((collection_id.clone(), item_id), auction_info);
+
+            Self::deposit_event(Event::NftListed(collection_id, item_id, owner));
+            Ok(())
+        }
+
+        /// Commit to a hidden bid on a sealed-bid auction. `commitment` is the hash of the
+        /// SCALE-encoded `(amount, salt)` pair and `deposit` is held until settlement.
+        #[pallet::call_index(16)]
+        #[pallet::weight(T::DbWeight::get().reads_writes(4, 3))]
+        pub fn commit_bid(
+            origin: OriginFor<T>,
+            collection_id: T::CollectionId,
+            item_id: T::ItemId,
+            commitment: T::Hash,
+            deposit: BalanceOf<T>,
+        ) -> DispatchResult {
+            let bidder = ensure_signed(origin)?;
+
+            let auction_info = Auctions::<T>::get((collection_id.clone(), item_id))
+                .ok_or(Error::<T>::AuctionNotFound)?;
+            ensure!(!auction_info.ended, Error::<T>::AuctionEnded);
+            ensure!(
+                matches!(auction_info.kind, AuctionKind::Sealed(_)),
+                Error::<T>::NotSealedBidAuction
+            );
+            ensure!(
+                bidder != auction_info.owner,
+                Error::<T>::CannotBidOnOwnAuction
+            );
+            ensure!(
+                <frame_system::Pallet<T>>::block_number() < Self::reveal_phase_start(&auction_info),
+                Error::<T>::NotInCommitPhase
+            );
+            ensure!(!deposit.is_zero(), Error::<T>::BidTooLow);
+
+            SealedBids::<T>::try_mutate((collection_id.clone(), item_id), |bids| {
+                // A new commitment replaces the bidder's previous one
+                if let Some(pos) = bids.iter().position(|(b, _)| b == &bidder) {
+                    let (_, previous) = bids.remove(pos);
+                    Self::release(
+                        &auction_info.asset,
+                        HoldReason::SealedBidDeposit,
+                        &bidder,
+                        previous.deposit,
+                    );
+                }
+
+                Self::hold(&auction_info.asset, HoldReason::SealedBidDeposit, &bidder, deposit)?;
+                ensure!(bids.len() < Self::max_bids_per_auction(), Error::<T>::TooManyBids);
+                bids.try_push((
+                    bidder.clone(),
+                    SealedBid { commitment, deposit, revealed: None },
+                ))
+                .map_err(|_| Error::<T>::TooManyBids)?;
+                Ok::<_, DispatchError>(())
+            })?;
+
+            Self::deposit_event(Event::SealedBidCommitted(collection_id, item_id, bidder, deposit));
+            Ok(())
+        }
+
+        /// Reveal a committed bid during the reveal phase of a sealed-bid auction
+        #[pallet::call_index(17)]
+        #[pallet::weight(T::DbWeight::get().reads_writes(2, 1))]
+        pub fn reveal_bid(
+            origin: OriginFor<T>,
+            collection_id: T::CollectionId,
+            item_id: T::ItemId,
+            amount: BalanceOf<T>,
+            salt: [u8; 32],
+        ) -> DispatchResult {
+            let bidder = ensure_signed(origin)?;
+
+            let auction_info = Auctions::<T>::get((collection_id.clone(), item_id))
+                .ok_or(Error::<T>::AuctionNotFound)?;
+            ensure!(!auction_info.ended, Error::<T>::AuctionEnded);
+            ensure!(
+                matches!(auction_info.kind, AuctionKind::Sealed(_)),
+                Error::<T>::NotSealedBidAuction
+            );
+            ensure!(
+                <frame_system::Pallet<T>>::block_number() >= Self::reveal_phase_start(&auction_info),
+                Error::<T>::NotInRevealPhase
+            );
+
+            SealedBids::<T>::try_mutate((collection_id.clone(), item_id), |bids| {
+                let (_, bid) = bids
+                    .iter_mut()
+                    .find(|(b, _)| b == &bidder)
+                    .ok_or(Error::<T>::SealedBidNotFound)?;
+                ensure!(bid.revealed.is_none(), Error::<T>::AlreadyRevealed);
+                ensure!(
+                    !amount.is_zero()
+                        && amount <= bid.deposit
+                        && T::Hashing::hash_of(&(amount, salt)) == bid.commitment,
+                    Error::<T>::InvalidReveal
+                );
+                bid.revealed = Some(amount);
+                Ok::<_, DispatchError>(())
+            })?;
+
+            Self::deposit_event(Event::SealedBidRevealed(collection_id, item_id, bidder, amount));
+            Ok(())
+        }
+
+        /// Cancel an auction and unfreeze the NFT. Free without bids, otherwise the seller pays
+        /// `CancellationPenalty` percent of the highest bid to the `CancellationPenaltyRecipient`.
+        /// Sealed-bid auctions can only be cancelled before any bid is committed
+        #[pallet::call_index(18)]
+        #[pallet::weight(T::DbWeight::get().reads_writes(5, 6))]
+        pub fn cancel_auction(
+            origin: OriginFor<T>,
+            collection_id: T::CollectionId,
+            item_id: T::ItemId,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let auction_info = Auctions::<T>::get((collection_id.clone(), item_id))
+                .ok_or(Error::<T>::AuctionNotFound)?;
+            ensure!(!auction_info.ended, Error::<T>::AuctionEnded);
+            ensure!(who == auction_info.owner, Error::<T>::NotNftOwner);
+
+            // Committed bids are hidden, leaving no highest bid to base a penalty on
+            if let AuctionKind::Sealed(_) = auction_info.kind {
+                ensure!(
+                    SealedBids::<T>::decode_len((collection_id.clone(), item_id)).unwrap_or(0) == 0,
+                    Error::<T>::SealedBidsCommitted
+                );
+            }
+
+            // Bidders claim their bids back once the auction is gone
+            Self::clear_bids(&collection_id, &item_id);
+
+            let mut penalty = Zero::zero();
+            if let Some(highest_bidder) = &auction_info.highest_bidder {
+                penalty = auction_info
+                    .highest_bid
+                    .saturating_mul(T::CancellationPenalty::get().into())
+                    / BalanceOf::<T>::from(100u32);
+                let recipient = match T::CancellationPenaltyRecipient::get() {
+                    PenaltyRecipient::TopBidder => highest_bidder.clone(),
+                    PenaltyRecipient::Treasury => Self::account_id(),
+                };
+
+                // A penalty too small for the recipient to receive is waived
+                if Self::can_receive(&auction_info.asset, &recipient, penalty) {
+                    Self::pay(&auction_info.asset, &who, &recipient, penalty)?;
+                    if recipient == Self::account_id() {
+                        AccumulatedFees::<T>::mutate(auction_info.asset, |f| *f += penalty);
+                    }
+                } else {
+                    penalty = Zero::zero();
+                }
+            }
+
+            // Unfreeze the NFTs so the owner can use them again
+            Self::thaw_items(&collection_id, &item_id, &who)?;
+            Self::clear_bundle(&collection_id, &item_id);
+
+            Self::unschedule_expiry(auction_info.end_block, &collection_id, &item_id);
+            Auctions::<T>::remove((collection_id.clone(), item_id));
+
+            Self::deposit_event(Event::AuctionCancelled(collection_id, item_id, penalty));
+            Ok(())
+        }
+
+        /// Retract a bid that is not leading, at least `BidRetractionCooldown` blocks after
+        /// it was placed
+        #[pallet::call_index(19)]
+        #[pallet::weight(T::DbWeight::get().reads_writes(3, 2))]
+        pub fn retract_bid(
+            origin: OriginFor<T>,
+            collection_id: T::CollectionId,
+            item_id: T::ItemId,
+        ) -> DispatchResult {
+            let bidder = ensure_signed(origin)?;
+
+            let auction_info = Auctions::<T>::get((collection_id.clone(), item_id))
+                .ok_or(Error::<T>::AuctionNotFound)?;
+            ensure!(!auction_info.ended, Error::<T>::AuctionEnded);
+            ensure!(
+                auction_info.highest_bidder.as_ref() != Some(&bidder),
+                Error::<T>::CannotRetractLeadingBid
+            );
+
+            let bid = BidLedger::<T>::get(&bidder, (collection_id.clone(), item_id))
+                .ok_or(Error::<T>::BidNotFound)?;
+            ensure!(
+                <frame_system::Pallet<T>>::block_number()
+                    >= bid.placed_at.saturating_add(T::BidRetractionCooldown::get()),
+                Error::<T>::RetractionCooldown
+            );
+
+            Self::release(&auction_info.asset, HoldReason::AuctionBid, &bidder, bid.amount);
+            RankedBids::<T>::mutate((collection_id.clone(), item_id), |bids| {
+                bids.retain(|(b, _)| b != &bidder)
+            });
+            BidLedger::<T>::remove(&bidder, (collection_id.clone(), item_id));
+
+            Self::deposit_event(Event::BidRetracted(collection_id, item_id, bidder));
+            Ok(())
+        }
+
+        /// Set the royalty terms of a collection. A zero rate removes them.
+        #[pallet::call_index(20)]
+        #[pallet::weight(T::DbWeight::get().reads_writes(2, 1))]
+        pub fn set_collection_royalty(
+            origin: OriginFor<T>,
+            collection_id: T::CollectionId,
+            rate: Perbill,
+            recipients: BoundedVec<(T::AccountId, u32), T::MaxRoyaltyRecipients>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let collection_owner =
+                pallet_uniques::Pallet::<T>::collection_owner(collection_id.clone())
+                    .ok_or(Error::<T>::NftNotFound)?;
+            ensure!(who == collection_owner, Error::<T>::NotCollectionOwner);
+            ensure!(rate <= MaxRoyalty::<T>::get(), Error::<T>::RoyaltyTooHigh);
+
+            if rate.is_zero() {
+                CollectionRoyalties::<T>::remove(&collection_id);
+            } else {
+                ensure!(
+                    !recipients.is_empty() && recipients.iter().all(|(_, weight)| *weight > 0),
+                    Error::<T>::InvalidRoyaltyRecipients
+                );
+                CollectionRoyalties::<T>::insert(&collection_id, RoyaltyInfo { rate, recipients });
+            }
+
+            Self::deposit_event(Event::CollectionRoyaltySet(collection_id, rate));
+            Ok(())
+        }
+
+        /// Set the highest royalty rate collections can charge
+        #[pallet::call_index(21)]
+        #[pallet::weight(T::DbWeight::get().writes(1))]
+        pub fn set_max_royalty(origin: OriginFor<T>, max_royalty: Perbill) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+            MaxRoyalty::<T>::put(max_royalty);
+            Self::deposit_event(Event::MaxRoyaltySet(max_royalty));
+            Ok(())
+        }
+
+        /// Set the marketplace parameters, each within the bounds of the runtime
+        #[pallet::call_index(29)]
+        #[pallet::weight(T::DbWeight::get().writes(1))]
+        pub fn set_market_params(
+            origin: OriginFor<T>,
+            params: MarketParametersOf<T>,
+        ) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+            ensure!(Self::is_valid_market_params(&params), Error::<T>::InvalidMarketParams);
+            MarketParams::<T>::put(params.clone());
+            Self::deposit_event(Event::MarketParamsUpdated(params));
+            Ok(())
+        }
+
+        /// Set the fees of sellers by the volume they sold at auction in a settlement asset, as
+        /// `(volume, fee)` tiers ordered by ascending volume. No tiers charges everyone the
+        /// market fee
+        #[pallet::call_index(30)]
+        #[pallet::weight(T::DbWeight::get().writes(1))]
+        pub fn set_fee_tiers(
+            origin: OriginFor<T>,
+            asset: AssetOf<T>,
+            tiers: BoundedVec<(BalanceOf<T>, Permill), T::MaxFeeTiers>,
+        ) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+            ensure!(
+                tiers.windows(2).all(|pair| pair[0].0 < pair[1].0),
+                Error::<T>::InvalidFee
+            );
+
+            let count = tiers.len() as u32;
+            if tiers.is_empty() {
+                FeeTiers::<T>::remove(asset);
+            } else {
+                FeeTiers::<T>::insert(asset, tiers);
+            }
+            Self::deposit_event(Event::FeeTiersSet(asset, count));
+            Ok(())
+        }
+
+        /// Set the fee charged on sales of a collection in place of the market fee and fee
+        /// tiers, `None` removes it
+        #[pallet::call_index(31)]
+        #[pallet::weight(T::DbWeight::get().writes(1))]
+        pub fn set_collection_fee(
+            origin: OriginFor<T>,
+            collection_id: T::CollectionId,
+            fee: Option<Permill>,
+        ) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+            CollectionFees::<T>::set(&collection_id, fee);
+            Self::deposit_event(Event::CollectionFeeSet(collection_id, fee));
+            Ok(())
+        }
+
+        /// Set how accumulated fees are split and how often they are distributed, `None` lets
+        /// them accumulate until withdrawn
+        #[pallet::call_index(32)]
+        #[pallet::weight(T::DbWeight::get().writes(1))]
+        pub fn set_fee_distribution(
+            origin: OriginFor<T>,
+            policy: Option<FeeDistributionOf<T>>,
+        ) -> DispatchResult {
+            T::AdminOrigin::ensure_origin(origin)?;
+            if let Some(policy) = &policy {
+                ensure!(
+                    Self::is_valid_fee_distribution(policy),
+                    Error::<T>::InvalidFeeDistribution
+                );
+            }
+
+            FeeDistributionPolicy::<T>::set(policy.clone());
+            Self::deposit_event(Event::FeeDistributionSet(policy));
+            Ok(())
+        }
+
+        /// Claim back a bid on an NFT once it no longer competes, because the bidder was
+        /// outranked or the auction is over
+        #[pallet::call_index(22)]
+        #[pallet::weight(T::DbWeight::get().reads_writes(2, 2))]
+        pub fn claim_refund(
+            origin: OriginFor<T>,
+            collection_id: T::CollectionId,
+            item_id: T::ItemId,
+        ) -> DispatchResult {
+            let bidder = ensure_signed(origin)?;
+            Self::do_claim_refund(&bidder, (collection_id, item_id))
+        }
+
+        /// Claim back the bids that no longer compete among the first `MaxRefundsPerClaim`
+        /// bids of the caller. Bids past them are claimed back with `claim_refund`
+        #[pallet::call_index(23)]
+        #[pallet::weight(
+            T::DbWeight::get().reads_writes(4, 2).saturating_mul(T::MaxRefundsPerClaim::get().into())
+        )]
+        pub fn claim_all_refunds(origin: OriginFor<T>) -> DispatchResult {
+            let bidder = ensure_signed(origin)?;
+
+            // Each bid read also reads the ranked bids of its auction
+            let refunds: Vec<_> = BidLedger::<T>::iter_prefix(&bidder)
+                .take(T::MaxRefundsPerClaim::get() as usize)
+                .filter(|(nft, _)| Self::is_refundable(&bidder, nft))
+                .map(|(nft, _)| nft)
+                .collect();
+            ensure!(!refunds.is_empty(), Error::<T>::NoRefundAvailable);
+            for nft in refunds {
+                Self::do_claim_refund(&bidder, nft)?;
+            }
+            Ok(())
+        }
+
+        /// List NFTs of one collection for auction as a single lot. The auction is keyed by the
+        /// first NFT, all of them are frozen together and go to the winner in one settlement.
+        #[pallet::call_index(27)]
+        #[pallet::weight(
+            T::DbWeight::get().reads_writes(4, 3).saturating_mul(T::MaxBundleSize::get().into())
+        )]
+        #[allow(clippy::too_many_arguments)]
+        pub fn list_bundle_for_auction(
+            origin: OriginFor<T>,
+            items: BoundedVec<(T::CollectionId, T::ItemId), T::MaxBundleSize>,
+            reserve_price: Option<BalanceOf<T>>,
+            min_increment: Option<BidIncrement<BalanceOf<T>>>,
+            duration: Option<BlockNumberFor<T>>,
+            buy_now_price: Option<BalanceOf<T>>,
+            asset: AssetOf<T>,
+        ) -> DispatchResult {
+            let owner = ensure_signed(origin)?;
+
+            Self::ensure_asset_exists(&asset)?;
+
+            // Royalty and sales stats are charged to the collection of the bundle
+            let (collection_id, item_id) = items.first().cloned().ok_or(Error::<T>::InvalidBundle)?;
+            ensure!(
+                items.len() >= 2
+                    && items.iter().all(|(c, _)| *c == collection_id)
+                    && items.iter().enumerate().all(|(n, nft)| !items[..n].contains(nft)),
+                Error::<T>::InvalidBundle
+            );
+
+            let start_block = <frame_system::Pallet<T>>::block_number();
+            let end_block = Self::auction_end_block(start_block, duration)?;
+
+            // Ensure a percentage increment is a valid percentage
+            if let Some(BidIncrement::Percentage(percent)) = min_increment {
+                ensure!(percent <= 100, Error::<T>::InvalidBidIncrement);
+            }
+
+            // Ensure buying outright is never cheaper than the reserve
+            if let Some(price) = buy_now_price {
+                ensure!(
+                    !price.is_zero() && reserve_price.is_none_or(|reserve| price >= reserve),
+                    Error::<T>::InvalidBuyNowPrice
+                );
+            }
+
+            for nft in items.iter() {
+                // Ensure caller is the NFT owner
+                let nft_owner = pallet_uniques::Pallet::<T>::owner(nft.0.clone(), nft.1)
+                    .ok_or(Error::<T>::NftNotFound)?;
+                ensure!(owner == nft_owner, Error::<T>::NotNftOwner);
+
+                // Ensure NFT is neither in an auction nor listed for sale
+                ensure!(!Self::is_in_auction(nft.clone()), Error::<T>::NftAlreadyInAuction);
+                ensure!(!Self::is_in_escrow(nft.clone()), Error::<T>::NftInEscrow);
+                ensure!(!Listings::<T>::contains_key(nft), Error::<T>::NftAlreadyListed);
+
+                pallet_uniques::Pallet::<T>::freeze(
+                    frame_system::RawOrigin::Signed(owner.clone()).into(),
+                    nft.0.clone(),
+                    nft.1,
+                )?;
+                BundleOf::<T>::insert(nft, (collection_id.clone(), item_id));
+            }
+
+            // Schedule the auction to auto-resolve at its end block
+            Self::schedule_expiry(end_block, &collection_id, &item_id)?;
+
+            let auction_info = AuctionInfo {
+                owner: owner.clone(),
+                start_block,
+                end_block,
+                highest_bid: Zero::zero(),
+                highest_bidder: None,
+                ended: false,
+                reserve_price,
+                min_increment,
+                extended_by: Zero::zero(),
+                buy_now_price,
+                kind: AuctionKind::English,
+                asset,
+            };
+            Auctions::<T>::insert((collection_id.clone(), item_id), auction_info);
+
+            let count = items.len() as u32;
+            BundleItems::<T>::insert((collection_id.clone(), item_id), items);
+
+            Self::deposit_event(Event::BundleListed(collection_id, item_id, owner, count));
+            Ok(())
+        }
+
+        /// Dispute an auction sale while its proceeds are in escrow, which keeps them there
+        /// until `DisputeOrigin` resolves the dispute
+        #[pallet::call_index(24)]
+        #[pallet::weight(T::DbWeight::get().reads_writes(1, 1))]
+        pub fn open_dispute(
+            origin: OriginFor<T>,
+            collection_id: T::CollectionId,
+            item_id: T::ItemId,
+        ) -> DispatchResult {
+            let buyer = ensure_signed(origin)?;
+
+            Escrows::<T>::try_mutate((collection_id.clone(), item_id), |escrow| {
+                let escrow = escrow.as_mut().ok_or(Error::<T>::EscrowNotFound)?;
+                ensure!(escrow.buyer == buyer, Error::<T>::NotEscrowBuyer);
+                ensure!(!escrow.disputed, Error::<T>::EscrowDisputed);
+                ensure!(
+                    <frame_system::Pallet<T>>::block_number() < escrow.release_at,
+                    Error::<T>::DisputeWindowClosed
+                );
+                escrow.disputed = true;
+                Ok::<_, DispatchError>(())
+            })?;
+
+            Self::deposit_event(Event::DisputeOpened(collection_id, item_id, buyer));
+            Ok(())
+        }
+
+        /// Resolve a disputed escrow by refunding the proceeds and fee to the buyer, who returns
+        /// the NFT to the seller, or by releasing them to the seller. Royalties were paid out at
+        /// the sale and are not refunded.
+        #[pallet::call_index(25)]
+        #[pallet::weight(
+            T::DbWeight::get().reads_writes(4, 5).saturating_mul(T::MaxBundleSize::get().into())
+        )]
+        pub fn resolve_dispute(
+            origin: OriginFor<T>,
+            collection_id: T::CollectionId,
+            item_id: T::ItemId,
+            refund: bool,
+        ) -> DispatchResult {
+            T::DisputeOrigin::ensure_origin(origin)?;
+
+            let nft = (collection_id, item_id);
+            let escrow = Escrows::<T>::get(&nft).ok_or(Error::<T>::EscrowNotFound)?;
+            ensure!(escrow.disputed, Error::<T>::EscrowNotDisputed);
+
+            Self::close_escrow(nft, escrow, refund)
+        }
+
+        /// Release undisputed proceeds to the seller once the dispute window has closed
+        #[pallet::call_index(26)]
+        #[pallet::weight(T::DbWeight::get().reads_writes(2, 3))]
+        pub fn release_escrow(
+            origin: OriginFor<T>,
+            collection_id: T::CollectionId,
+            item_id: T::ItemId,
+        ) -> DispatchResult {
+            ensure_signed(origin)?;
+
+            let nft = (collection_id, item_id);
+            let escrow = Escrows::<T>::get(&nft).ok_or(Error::<T>::EscrowNotFound)?;
+            ensure!(!escrow.disputed, Error::<T>::EscrowDisputed);
+            ensure!(
+                <frame_system::Pallet<T>>::block_number() >= escrow.release_at,
+                Error::<T>::EscrowNotDue
+            );
+
+            Self::close_escrow(nft, escrow, false)
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// The pallet account holds exactly the accumulated fees and escrowed proceeds of each
+        /// asset, and the held asset funds of bids, offers and sealed-bid deposits
+        #[cfg(any(feature = "try-runtime", test))]
+        pub fn do_try_state() -> Result<(), sp_runtime::TryRuntimeError> {
+            let escrowed = Escrows::<T>::iter_values()
+                .filter(|escrow| escrow.asset == SettlementAsset::Native)
+                .fold(BalanceOf::<T>::zero(), |total, escrow| {
+                    total.saturating_add(escrow.amount).saturating_add(escrow.fee)
+                });
+            ensure!(
+                <<T as Config>::Currency as fungible::Inspect<_>>::balance(&Self::account_id())
+                    == AccumulatedFees::<T>::get(SettlementAsset::Native).saturating_add(escrowed),
+                "Pallet account balance does not match the accumulated native fees and escrows"
+            );
+
+            let mut expected: Vec<(T::AssetId, BalanceOf<T>)> = HeldAssets::<T>::iter().collect();
+            let mut add = |id: T::AssetId, amount: BalanceOf<T>| {
+                match expected.iter_mut().find(|(asset, _)| *asset == id) {
+                    Some((_, total)) => *total = total.saturating_add(amount),
+                    None => expected.push((id, amount)),
+                }
+            };
+            for (asset, fees) in AccumulatedFees::<T>::iter() {
+                if let SettlementAsset::Asset(id) = asset {
+                    add(id, fees);
+                }
+            }
+            for escrow in Escrows::<T>::iter_values() {
+                if let SettlementAsset::Asset(id) = escrow.asset {
+                    add(id, escrow.amount.saturating_add(escrow.fee));
+                }
+            }
+            for (id, total) in expected {
+                ensure!(
+                    <T::Assets as fungibles::Inspect<_>>::balance(id, &Self::account_id()) == total,
+                    "Pallet account balance does not match the accumulated asset fees, held asset funds and escrows"
+                );
+            }
+            Ok(())
+        }
+
+        /// Whether every marketplace parameter is within the bounds of the runtime
+        pub fn is_valid_market_params(params: &MarketParametersOf<T>) -> bool {
+            (1..=T::MaxBidsPerAuction::get()).contains(&params.max_bids_per_auction)
+                && (T::MinAuctionDuration::get()..=T::MaxAuctionDuration::get())
+                    .contains(&params.auction_timeout)
+                && (1..=T::MaxBatchListingSize::get()).contains(&params.max_batch_listing_size)
+        }
+
+        // Number of bids an auction currently ranks
+        fn max_bids_per_auction() -> usize {
+            MarketParams::<T>::get()
+                .max_bids_per_auction
+                .min(T::MaxBidsPerAuction::get()) as usize
+        }
+
+        pub fn account_id() -> T::AccountId {
+            T::PalletId::get().into_account_truncating()
+        }
+
+        /// Whether the shares of a fee distribution policy add up to the whole and its period
+        /// is non-zero
+        pub fn is_valid_fee_distribution(policy: &FeeDistributionOf<T>) -> bool {
+            let total = policy.treasury.deconstruct() as u64
+                + policy.burn.deconstruct() as u64
+                + policy.rewards.deconstruct() as u64;
+            total == Permill::one().deconstruct() as u64 && !policy.period.is_zero()
+        }
+
+        /// Add fees paid into the pallet account from outside the marketplace, such as
+        /// transaction fees, to the accumulated fees
+        pub fn note_fees(asset: AssetOf<T>, amount: BalanceOf<T>) {
+            AccumulatedFees::<T>::mutate(asset, |f| *f = f.saturating_add(amount));
+        }
+
+        // Distribute the accumulated fees of up to `MaxFeeAssetsPerDistribution` assets by
+        // the policy, starting after `resume_after`, and keep the last one in the backlog when
+        // more are left. An asset whose distribution fails, like a share too small for a new
+        // account to receive, keeps its fees until the next distribution
+        fn distribute_fees(
+            policy: &FeeDistributionOf<T>,
+            resume_after: Option<AssetOf<T>>,
+        ) -> Weight {
+            let max = T::MaxFeeAssetsPerDistribution::get() as usize;
+            let pending = match resume_after {
+                Some(asset) => AccumulatedFees::<T>::iter_from(
+                    AccumulatedFees::<T>::hashed_key_for(asset),
+                ),
+                None => AccumulatedFees::<T>::iter(),
+            };
+            // Reading one more tells whether any are left for the next block
+            let mut fees: Vec<_> = pending.take(max.saturating_add(1)).collect();
+            let mut weight = T::DbWeight::get().reads(fees.len() as u64);
+            if fees.len() > max {
+                fees.truncate(max);
+                if let Some((asset, _)) = fees.last() {
+                    FeeDistributionBacklog::<T>::put(asset);
+                }
+                weight = weight.saturating_add(T::DbWeight::get().writes(1));
+            }
+
+            for (asset, total) in fees {
+                if total.is_zero() {
+                    continue;
+                }
+                weight = weight.saturating_add(T::DbWeight::get().reads_writes(4, 4));
+                if let Err(e) = Self::split_fees(&asset, total, policy) {
+                    log::warn!("Fee distribution of {:?} failed: {:?}", asset, e);
+                }
+            }
+            weight
+        }
+
+        // Pay the treasury and rewards shares of the fees of an asset and burn the rest
+        #[transactional]
+        fn split_fees(
+            asset: &AssetOf<T>,
+            total: BalanceOf<T>,
+            policy: &FeeDistributionOf<T>,
+        ) -> DispatchResult {
+            AccumulatedFees::<T>::remove(asset);
+
+            // Rounding leftovers go to the rewards pot
+            let treasury = policy.treasury.mul_floor(total);
+            let burned = policy.burn.mul_floor(total);
+            let rewards = total.saturating_sub(treasury).saturating_sub(burned);
+
+            Self::transfer_fees(asset, &T::TreasuryAccount::get(), treasury)?;
+            Self::transfer_fees(asset, &T::RewardsAccount::get(), rewards)?;
+            if !burned.is_zero() {
+                match asset {
+                    SettlementAsset::Native => {
+                        <<T as Config>::Currency as fungible::Mutate<_>>::burn_from(
+                            &Self::account_id(),
+                            burned,
+                            Preservation::Expendable,
+                            Precision::Exact,
+                            Fortitude::Polite,
+                        )?;
+                    }
+                    SettlementAsset::Asset(id) => {
+                        <T::Assets as fungibles::Mutate<_>>::burn_from(
+                            *id,
+                            &Self::account_id(),
+                            burned,
+                            Preservation::Expendable,
+                            Precision::Exact,
+                            Fortitude::Polite,
+                        )?;
+                    }
+                }
+            }
+            Self::deposit_event(Event::FeesDistributed(*asset, treasury, burned, rewards));
+            Ok(())
+        }
+
+        // Transfer accumulated fees out of the pallet account
+        fn transfer_fees(
+            asset: &AssetOf<T>,
+            to: &T::AccountId,
+            amount: BalanceOf<T>,
+        ) -> DispatchResult {
+            if amount.is_zero() {
+                return Ok(());
+            }
+            match asset {
+                SettlementAsset::Native => {
+                    <<T as Config>::Currency as fungible::Mutate<_>>::transfer(
+                        &Self::account_id(),
+                        to,
+                        amount,
+                        Preservation::Expendable,
+                    )
+                }
+                SettlementAsset::Asset(id) => <T::Assets as fungibles::Mutate<_>>::transfer(
+                    *id,
+                    &Self::account_id(),
+                    to,
+                    amount,
+                    Preservation::Expendable,
+                ),
+            }
+            .map(|_| ())
+        }
+
+        // Block at which an auction started at `start_block` ends, using the default
+        // timeout when the seller did not choose a duration
+        fn auction_end_block(
+            start_block: BlockNumberFor<T>,
+            duration: Option<BlockNumberFor<T>>,
+        ) -> Result<BlockNumberFor<T>, DispatchError> {
+            let duration = duration.unwrap_or_else(|| MarketParams::<T>::get().auction_timeout);
+            ensure!(
+                duration >= T::MinAuctionDuration::get() && duration <= T::MaxAuctionDuration::get(),
+                Error::<T>::InvalidAuctionDuration
+            );
+            Ok(start_block.saturating_add(duration))
+        }
+
+        // Add an auction to the expiry schedule of `end_block`
+        pub(crate) fn schedule_expiry(
+            end_block: BlockNumberFor<T>,
+            collection_id: &T::CollectionId,
+            item_id: &T::ItemId,
+        ) -> DispatchResult {
+            AuctionExpiries::<T>::try_mutate(end_block, |due| {
+                due.try_push((collection_id.clone(), *item_id))
+                    .map_err(|_| Error::<T>::TooManyAuctionsExpiring.into())
+            })
+        }
+
+        // Remove an auction from the expiry schedule of `end_block`
+        fn unschedule_expiry(
+            end_block: BlockNumberFor<T>,
+            collection_id: &T::CollectionId,
+            item_id: &T::ItemId,
+        ) {
+            AuctionExpiries::<T>::mutate_exists(end_block, |maybe_due| {
+                if let Some(due) = maybe_due {
+                    due.retain(|(c, i)| c != collection_id || i != item_id);
+                    if due.is_empty() {
+                        *maybe_due = None;
+                    }
+                }
+            });
+        }
+
+        // Move an auction to the expiry schedule of its new end block
+        fn reschedule_expiry(
+            old_end_block: BlockNumberFor<T>,
+            new_end_block: BlockNumberFor<T>,
+            collection_id: &T::CollectionId,
+            item_id: &T::ItemId,
+        ) -> DispatchResult {
+            Self::unschedule_expiry(old_end_block, collection_id, item_id);
+            Self::schedule_expiry(new_end_block, collection_id, item_id)
+        }
+
+        // Place a bid, or with `by_proxy` a maximum bid, and let a leading maximum bid defend
+        // itself against it
+        fn do_place_bid(
+            bidder: T::AccountId,
+            collection_id: T::CollectionId,
+            item_id: T::ItemId,
+            bid_amount: BalanceOf<T>,
+            by_proxy: bool,
+        ) -> DispatchResult {
+            // Ensure auction exists and is active
+            let nft = (collection_id.clone(), item_id);
+            let auction_info = Auctions::<T>::get(&nft).ok_or(Error::<T>::AuctionNotFound)?;
+            ensure!(!auction_info.ended, Error::<T>::AuctionEnded);
+
+            // Sealed-bid auctions only take committed bids
+            ensure!(
+                !matches!(auction_info.kind, AuctionKind::Sealed(_)),
+                Error::<T>::SealedBidAuction
+            );
+            ensure!(
+                !by_proxy || matches!(auction_info.kind, AuctionKind::English),
+                Error::<T>::MaxBidNotSupported
+            );
+
+            // Ensure bidder is not the auction owner
+            ensure!(
+                bidder != auction_info.owner,
+                Error::<T>::CannotBidOnOwnAuction
+            );
+            Self::ensure_eligible_bidder(&nft, &bidder)?;
+
+            // The leader raising their maximum keeps their bid and only holds the difference
+            let leading = auction_info.highest_bidder.as_ref() == Some(&bidder);
+            let own_max = MaxBids::<T>::get(&nft).filter(|_| leading);
+            if leading && by_proxy {
+                ensure!(
+                    bid_amount > own_max.unwrap_or(auction_info.highest_bid),
+                    Error::<T>::BidTooLow
+                );
+                Self::record_bid(
+                    &collection_id,
+                    &item_id,
+                    &auction_info.asset,
+                    &bidder,
+                    auction_info.highest_bid,
+                    bid_amount,
+                )?;
+                MaxBids::<T>::insert(&nft, bid_amount);
+                Self::deposit_event(Event::MaxBidPlaced(collection_id, item_id, bidder));
+                return Ok(());
+            }
+
+            // Ensure bid beats the current highest bid by at least the minimum increment
+            ensure!(
+                bid_amount >= Self::min_next_bid(&auction_info),
+                Error::<T>::BidTooLow
+            );
+
+            // The first bid reaching the current price of a Dutch auction buys the NFT at that price
+            if let Some(price) = Self::current_price(&auction_info) {
+                return Self::finalize_auction(&collection_id, &item_id, &bidder, price);
+            }
+
+            // Hold the bid, outbid bids stay held as fallbacks while they rank high enough
+            let asset = auction_info.asset;
+            let mut new_auction_info = auction_info;
+            let mut placed = Vec::new();
+            let defender = new_auction_info
+                .highest_bidder
+                .clone()
+                .filter(|_| !leading)
+                .zip(MaxBids::<T>::get(&nft));
+            match defender {
+                // A leading maximum bid at least as high keeps the lead, one increment over
+                Some((leader, max)) if bid_amount <= max => {
+                    let raised = max.min(Self::outbid(&new_auction_info, bid_amount));
+                    Self::record_bid(&collection_id, &item_id, &asset, &leader, raised, max)?;
+                    Self::record_bid(
+                        &collection_id,
+                        &item_id,
+                        &asset,
+                        &bidder,
+                        bid_amount,
+                        bid_amount,
+                    )?;
+                    if raised == max {
+                        MaxBids::<T>::remove(&nft);
+                    }
+                    placed.push((bidder.clone(), bid_amount));
+                    placed.push((leader, raised));
+                    new_auction_info.highest_bid = raised;
+                }
+                defender => {
+                    // An outbid maximum bid bids all of it before losing the lead
+                    if let Some((leader, max)) = defender {
+                        Self::record_bid(&collection_id, &item_id, &asset, &leader, max, max)?;
+                        placed.push((leader, max));
+                        new_auction_info.highest_bid = max;
+                    }
+
+                    // A maximum bid only bids what it takes to lead, and to meet the reserve
+                    let amount = if by_proxy {
+                        let reserve = new_auction_info
+                            .reserve_price
+                            .unwrap_or_default()
+                            .min(bid_amount);
+                        Self::min_next_bid(&new_auction_info).max(reserve).min(bid_amount)
+                    } else {
+                        bid_amount
+                    };
+                    let held = own_max.unwrap_or_default().max(bid_amount);
+                    Self::record_bid(&collection_id, &item_id, &asset, &bidder, amount, held)?;
+                    if held > amount {
+                        MaxBids::<T>::insert(&nft, held);
+                    } else {
+                        MaxBids::<T>::remove(&nft);
+                    }
+                    placed.push((bidder.clone(), amount));
+                    new_auction_info.highest_bid = amount;
+                    new_auction_info.highest_bidder = Some(bidder.clone());
+                }
+            }
+            let highest_bid = new_auction_info.highest_bid;
+
+            // Buying outright is no longer offered once bidding gets close to the buy-now price
+            if let Some(price) = new_auction_info.buy_now_price {
+                let threshold = price.saturating_mul(T::BuyNowBidThreshold::get().into())
+                    / BalanceOf::<T>::from(100u32);
+                if highest_bid > threshold {
+                    new_auction_info.buy_now_price = None;
+                }
+            }
+
+            // A bid close to the end pushes it back so others get a chance to respond
+            let extension = Self::soft_close_extension(&new_auction_info);
+            if !extension.is_zero() {
+                let end_block = new_auction_info.end_block.saturating_add(extension);
+                Self::reschedule_expiry(
+                    new_auction_info.end_block,
+                    end_block,
+                    &collection_id,
+                    &item_id,
+                )?;
+                new_auction_info.end_block = end_block;
+                new_auction_info.extended_by =
+                    new_auction_info.extended_by.saturating_add(extension);
+            }
+            let end_block = new_auction_info.end_block;
+            Auctions::<T>::insert(&nft, new_auction_info);
+
+            // Emit event
+            for (who, amount) in placed {
+                Self::deposit_event(Event::BidPlaced(collection_id.clone(), item_id, who, amount));
+            }
+            if by_proxy {
+                Self::deposit_event(Event::MaxBidPlaced(collection_id.clone(), item_id, bidder));
+            }
+            if !extension.is_zero() {
+                Self::deposit_event(Event::AuctionExtended(collection_id, item_id, end_block));
+            }
+
+            Ok(())
+        }
+
+        // Rank `amount` as the bid of `bidder` and hold `held` for it, on top of what their
+        // ranked bid already holds, and unrank the lowest bids once the auction ranks more than
+        // the market allows. A maximum bid holds more than it bids.
+        fn record_bid(
+            collection_id: &T::CollectionId,
+            item_id: &T::ItemId,
+            asset: &AssetOf<T>,
+            bidder: &T::AccountId,
+            amount: BalanceOf<T>,
+            held: BalanceOf<T>,
+        ) -> DispatchResult {
+            let key = (collection_id.clone(), *item_id);
+            let mut bids = RankedBids::<T>::get(&key);
+
+            let previous = match BidLedger::<T>::get(bidder, &key) {
+                Some(bid) if bids.iter().any(|(b, _)| b == bidder) => bid.amount,
+                // A bid left over from being outranked or from an earlier auction is given back
+                Some(bid) => {
+                    Self::release(&bid.asset, HoldReason::AuctionBid, bidder, bid.amount);
+                    Zero::zero()
+                }
+                None => Zero::zero(),
+            };
+            let held = held.max(amount).max(previous);
+            Self::hold(asset, HoldReason::AuctionBid, bidder, held.saturating_sub(previous))?;
+            bids.retain(|(b, _)| b != bidder);
+
+            // Equal bids keep the earlier one ahead
+            let pos = bids.partition_point(|(_, b)| *b >= amount);
+            let capacity = Self::max_bids_per_auction();
+            ensure!(pos < capacity, Error::<T>::BidTooLow);
+            // Outranked bids stay held until their bidders claim them back
+            while bids.len() >= capacity {
+                let Some((outranked, outranked_amount)) = bids.pop() else { break };
+                Self::deposit_event(Event::BidOutranked(
+                    collection_id.clone(),
+                    *item_id,
+                    outranked,
+                    outranked_amount,
+                ));
+            }
+            bids.try_insert(pos, (bidder.clone(), amount))
+                .map_err(|_| Error::<T>::TooManyBids)?;
+
+            RankedBids::<T>::insert(&key, bids);
+            BidLedger::<T>::insert(
+                bidder,
+                &key,
+                LedgerBid {
+                    amount: held,
+                    placed_at: <frame_system::Pallet<T>>::block_number(),
+                    asset: *asset,
+                },
+            );
+            Ok(())
+        }
+
+        // Stop ranking the bids of an ended auction, which leaves them to be claimed back, and
+        // drop the maximum bid and access rule that only applied while it ran
+        fn clear_bids(collection_id: &T::CollectionId, item_id: &T::ItemId) {
+            RankedBids::<T>::remove((collection_id, item_id));
+            MaxBids::<T>::remove((collection_id, item_id));
+            AuctionAccess::<T>::remove((collection_id, item_id));
+        }
+
+        // Ensure `bidder` meets the access rule of a gated auction
+        fn ensure_eligible_bidder(
+            nft: &(T::CollectionId, T::ItemId),
+            bidder: &T::AccountId,
+        ) -> DispatchResult {
+            let eligible = match AuctionAccess::<T>::get(nft) {
+                None => true,
+                Some(BidderAccess::Allowlist(accounts)) => accounts.contains(bidder),
+                Some(BidderAccess::CollectionHolders(collection_id)) => {
+                    pallet_uniques::Pallet::<T>::owned_in_collection(&collection_id, bidder)
+                        .next()
+                        .is_some()
+                }
+                Some(BidderAccess::ProfileHolders) => T::ProfileHolders::contains(bidder),
+            };
+            ensure!(eligible, Error::<T>::NotEligibleBidder);
+            Ok(())
+        }
+
+        // Whether the bid of `bidder` on an NFT no longer competes in a running auction
+        fn is_refundable(bidder: &T::AccountId, nft: &(T::CollectionId, T::ItemId)) -> bool {
+            !RankedBids::<T>::get(nft).iter().any(|(b, _)| b == bidder)
+        }
+
+        // Release a bid that no longer competes back to its bidder
+        fn do_claim_refund(
+            bidder: &T::AccountId,
+            nft: (T::CollectionId, T::ItemId),
+        ) -> DispatchResult {
+            ensure!(Self::is_refundable(bidder, &nft), Error::<T>::BidStillActive);
+            let bid = BidLedger::<T>::take(bidder, &nft).ok_or(Error::<T>::NoRefundAvailable)?;
+            Self::release(&bid.asset, HoldReason::AuctionBid, bidder, bid.amount);
+
+            let (collection_id, item_id) = nft;
+            Self::deposit_event(Event::RefundClaimed(
+                collection_id,
+                item_id,
+                bidder.clone(),
+                bid.amount,
+            ));
+            Ok(())
+        }
+
+        /// Floor, median and volume of the auction sales of a collection settled within the
+        /// last `window` blocks, per settlement asset. Sales are counted by whole buckets, so
+        /// the window is rounded up to the start of the bucket it reaches into. The median is
+        /// taken over the sampled prices of the buckets, so it is approximate once a bucket
+        /// has more than `MaxPricesPerBucket` sales.
+        pub fn collection_stats(
+            collection_id: T::CollectionId,
+            window: BlockNumberFor<T>,
+        ) -> Vec<(AssetOf<T>, CollectionStats<BalanceOf<T>>)> {
+            let now = <frame_system::Pallet<T>>::block_number();
+            let bucket_length = T::StatsBucketLength::get().max(One::one());
+            let since = now.saturating_sub(window);
+            let since = since.saturating_sub(since % bucket_length);
+
+            SalesBucketStarts::<T>::iter_prefix(collection_id.clone())
+                .filter_map(|(asset, starts)| {
+                    let mut stats = CollectionStats::<BalanceOf<T>>::default();
+                    let mut prices = Vec::new();
+                    let buckets = starts
+                        .into_iter()
+                        .filter(|start| *start >= since)
+                        .filter_map(|start| {
+                            SalesBuckets::<T>::get((collection_id.clone(), asset, start))
+                        });
+                    for bucket in buckets {
+                        stats.floor = if stats.sales == 0 {
+                            bucket.floor
+                        } else {
+                            stats.floor.min(bucket.floor)
+                        };
+                        stats.volume = stats.volume.saturating_add(bucket.volume);
+                        stats.sales = stats.sales.saturating_add(bucket.sales);
+                        prices.extend(bucket.prices.iter().copied());
+                    }
+                    if stats.sales == 0 {
+                        return None;
+                    }
+
+                    prices.sort();
+                    let mid = prices.len() / 2;
+                    stats.median = match prices.len() {
+                        0 => Zero::zero(),
+                        len if len % 2 == 1 => prices[mid],
+                        _ => prices[mid - 1].saturating_add(prices[mid]) / 2u32.into(),
+                    };
+                    Some((asset, stats))
+                })
+                .collect()
+        }
+
+        /// Bids of `who` that can be claimed back, by NFT
+        pub fn pending_refunds(who: &T::AccountId) -> Vec<PendingRefundOf<T>> {
+            BidLedger::<T>::iter_prefix(who)
+                .filter(|(nft, _)| Self::is_refundable(who, nft))
+                .map(|(nft, bid)| (nft, bid.asset, bid.amount))
+                .collect()
+        }
+
+        /// The `k` highest active bids of an auction, highest first
+        pub fn top_bids(
+            collection_id: T::CollectionId,
+            item_id: T::ItemId,
+            k: u32,
+        ) -> Vec<(T::AccountId, BalanceOf<T>)> {
+            let mut bids = RankedBids::<T>::get((collection_id, item_id)).into_inner();
+            bids.truncate(k as usize);
+            bids
+        }
+
+        /// The NFT the auction selling `nft` is keyed by: the first NFT of its bundle, or
+        /// `nft` itself
+        pub fn auction_key(
+            nft: (T::CollectionId, T::ItemId),
+        ) -> (T::CollectionId, T::ItemId) {
+            BundleOf::<T>::get(&nft).unwrap_or(nft)
+        }
+
+        // The NFTs an auction sells: the NFTs of its bundle, or the one it is keyed by
+        fn auction_items(
+            collection_id: &T::CollectionId,
+            item_id: &T::ItemId,
+        ) -> Vec<(T::CollectionId, T::ItemId)> {
+            let items = BundleItems::<T>::get((collection_id, item_id));
+            if items.is_empty() {
+                vec![(collection_id.clone(), *item_id)]
+            } else {
+                items.into_inner()
+            }
+        }
+
+        // Unfreeze the NFTs of an auction so the owner can use them again
+        fn thaw_items(
+            collection_id: &T::CollectionId,
+            item_id: &T::ItemId,
+            owner: &T::AccountId,
+        ) -> DispatchResult {
+            for (collection_id, item_id) in Self::auction_items(collection_id, item_id) {
+                pallet_uniques::Pallet::<T>::thaw(
+                    frame_system::RawOrigin::Signed(owner.clone()).into(),
+                    collection_id,
+                    item_id,
+                )?;
+            }
+            Ok(())
+        }
+
+        // Break up the bundle of an auction that is over
+        fn clear_bundle(collection_id: &T::CollectionId, item_id: &T::ItemId) {
+            for nft in BundleItems::<T>::take((collection_id, item_id)) {
+                BundleOf::<T>::remove(nft);
+            }
+        }
+
+        /// Whether the proceeds of the sale of an NFT, alone or in a bundle, are in escrow
+        pub fn is_in_escrow(nft: (T::CollectionId, T::ItemId)) -> bool {
+            Escrows::<T>::contains_key(Self::auction_key(nft))
+        }
+
+        /// Whether an NFT is in an auction that has not ended
+        pub fn is_in_auction(nft: (T::CollectionId, T::ItemId)) -> bool {
+            Auctions::<T>::get(Self::auction_key(nft))
+                .is_some_and(|auction_info| !auction_info.ended)
+        }
+
+        // Ensure a listing only settles in an asset that exists
+        fn ensure_asset_exists(asset: &AssetOf<T>) -> DispatchResult {
+            if let SettlementAsset::Asset(id) = asset {
+                ensure!(
+                    <T::Assets as fungibles::Inspect<_>>::asset_exists(*id),
+                    Error::<T>::UnknownAsset
+                );
+            }
+            Ok(())
+        }
+
+        // Set funds of `who` aside for `reason`. Native funds are put on hold, asset funds are
+        // moved into the pallet account as `pallet_assets` has no holds of its own, and kept
+        // apart from fees and escrows in `HeldAssets`.
+        fn hold(
+            asset: &AssetOf<T>,
+            reason: HoldReason,
+            who: &T::AccountId,
+            amount: BalanceOf<T>,
+        ) -> DispatchResult {
+            match asset {
+                SettlementAsset::Native => {
+                    <<T as Config>::Currency as fungible::MutateHold<_>>::hold(
+                        &reason.into(),
+                        who,
+                        amount,
+                    )
+                }
+                SettlementAsset::Asset(id) => {
+                    <T::Assets as fungibles::Mutate<_>>::transfer(
+                        *id,
+                        who,
+                        &Self::account_id(),
+                        amount,
+                        Preservation::Preserve,
+                    )?;
+                    HeldAssets::<T>::mutate(id, |held| *held = held.saturating_add(amount));
+                    Ok(())
+                }
+            }
+        }
+
+        // Give back funds set aside by `hold`
+        fn release(
+            asset: &AssetOf<T>,
+            reason: HoldReason,
+            who: &T::AccountId,
+            amount: BalanceOf<T>,
+        ) {
+            match asset {
+                SettlementAsset::Native => {
+                    let _ = <<T as Config>::Currency as fungible::MutateHold<_>>::release(
+                        &reason.into(),
+                        who,
+                        amount,
+                        Precision::BestEffort,
+                    );
+                }
+                SettlementAsset::Asset(id) => {
+                    if <T::Assets as fungibles::Mutate<_>>::transfer(
+                        *id,
+                        &Self::account_id(),
+                        who,
+                        amount,
+                        Preservation::Expendable,
+                    )
+                    .is_ok()
+                    {
+                        HeldAssets::<T>::mutate(id, |held| *held = held.saturating_sub(amount));
+                    }
+                }
+            }
+        }
+
+        // Transfer free funds in the given asset, keeping the sender alive
+        fn pay(
+            asset: &AssetOf<T>,
+            from: &T::AccountId,
+            to: &T::AccountId,
+            amount: BalanceOf<T>,
+        ) -> DispatchResult {
+            if amount.is_zero() {
+                return Ok(());
+            }
+            match asset {
+                SettlementAsset::Native => {
+                    <<T as Config>::Currency as fungible::Mutate<_>>::transfer(
+                        from,
+                        to,
+                        amount,
+                        Preservation::Preserve,
+                    )
+                }
+                SettlementAsset::Asset(id) => <T::Assets as fungibles::Mutate<_>>::transfer(
+                    *id,
+                    from,
+                    to,
+                    amount,
+                    Preservation::Preserve,
+                ),
+            }
+            .map(|_| ())
+        }
+
+        // Pay out of funds of `from` set aside by `hold`
+        fn pay_held(
+            asset: &AssetOf<T>,
+            reason: HoldReason,
+            from: &T::AccountId,
+            to: &T::AccountId,
+            amount: BalanceOf<T>,
+        ) -> DispatchResult {
+            if amount.is_zero() {
+                return Ok(());
+            }
+            match asset {
+                SettlementAsset::Native => {
+                    <<T as Config>::Currency as fungible::MutateHold<_>>::transfer_on_hold(
+                        &reason.into(),
+                        from,
+                        to,
+                        amount,
+                        Precision::Exact,
+                        Restriction::Free,
+                        Fortitude::Polite,
+                    )
+                    .map(|_| ())
+                }
+                SettlementAsset::Asset(id) => {
+                    // Held asset funds already sit in the pallet account
+                    if to != &Self::account_id() {
+                        <T::Assets as fungibles::Mutate<_>>::transfer(
+                            *id,
+                            &Self::account_id(),
+                            to,
+                            amount,
+                            Preservation::Expendable,
+                        )?;
+                    }
+                    HeldAssets::<T>::mutate(id, |held| *held = held.saturating_sub(amount));
+                    Ok(())
+                }
+            }
+        }
+
+        // Whether `who` can be credited `amount` without ending up below the existential deposit
+        fn can_receive(asset: &AssetOf<T>, who: &T::AccountId, amount: BalanceOf<T>) -> bool {
+            let consequence = match asset {
+                SettlementAsset::Native => {
+                    <<T as Config>::Currency as fungible::Inspect<_>>::can_deposit(
+                        who,
+                        amount,
+                        Provenance::Extant,
+                    )
+                }
+                SettlementAsset::Asset(id) => <T::Assets as fungibles::Inspect<_>>::can_deposit(
+                    *id,
+                    who,
+                    amount,
+                    Provenance::Extant,
+                ),
+            };
+            consequence == DepositConsequence::Success
+        }
+
+        /// Number of blocks a bid placed now would add to the auction, zero outside the
+        /// soft close window or once the maximum extension is used up
+        pub fn soft_close_extension(
+            auction_info: &AuctionInfoOf<T>,
+        ) -> BlockNumberFor<T> {
+            if Self::remaining_blocks(auction_info) > T::SoftCloseWindow::get() {
+                return Zero::zero();
+            }
+            T::SoftCloseExtension::get()
+                .min(T::MaxSoftCloseExtension::get().saturating_sub(auction_info.extended_by))
+        }
+
+        /// Number of blocks left before the auction auto-resolves
+        pub fn remaining_blocks(
+            auction_info: &AuctionInfoOf<T>,
+        ) -> BlockNumberFor<T> {
+            auction_info
+                .end_block
+                .saturating_sub(<frame_system::Pallet<T>>::block_number())
+        }
+
+        /// Smallest bid `place_bid` currently accepts for the given auction
+        pub fn min_next_bid(
+            auction_info: &AuctionInfoOf<T>,
+        ) -> BalanceOf<T> {
+            // A Dutch auction accepts any bid at its current price
+            if let Some(price) = Self::current_price(auction_info) {
+                return price;
+            }
+
+            // The increment only applies once there is a bid to raise
+            match auction_info.highest_bidder {
+                Some(_) => Self::outbid(auction_info, auction_info.highest_bid),
+                None => auction_info.highest_bid.saturating_add(One::one()),
+            }
+        }
+
+        // Least bid beating `amount` by the minimum increment of an auction
+        fn outbid(auction_info: &AuctionInfoOf<T>, amount: BalanceOf<T>) -> BalanceOf<T> {
+            let increment = match auction_info.min_increment {
+                Some(BidIncrement::Absolute(increment)) => increment,
+                Some(BidIncrement::Percentage(percent)) => {
+                    amount.saturating_mul(BalanceOf::<T>::from(percent))
+                        / BalanceOf::<T>::from(100u32)
+                }
+                None => Zero::zero(),
+            };
+
+            // A new bid always has to be strictly higher than the current one
+            amount.saturating_add(increment.max(One::one()))
+        }
+
+        /// Price a Dutch auction currently sells at, `None` for other auction kinds
+        pub fn current_price(
+            auction_info: &AuctionInfoOf<T>,
+        ) -> Option<BalanceOf<T>> {
+            match auction_info.kind {
+                AuctionKind::English | AuctionKind::Sealed(_) => None,
+                AuctionKind::Dutch { start_price, floor_price, decay_per_block } => {
+                    let elapsed = <frame_system::Pallet<T>>::block_number()
+                        .saturating_sub(auction_info.start_block)
+                        .saturated_into::<u128>()
+                        .saturated_into::<BalanceOf<T>>();
+                    let decay = decay_per_block.saturating_mul(elapsed);
+                    Some(start_price.saturating_sub(decay).max(floor_price))
+                }
+            }
+        }
+
+        /// First block of the reveal phase of a sealed-bid auction
+        pub fn reveal_phase_start(
+            auction_info: &AuctionInfoOf<T>,
+        ) -> BlockNumberFor<T> {
+            auction_info
+                .end_block
+                .saturating_sub(T::SealedBidRevealPeriod::get())
+        }
+
+        /// Whether `amount` reaches the auction's reserve price, if it has one
+        pub fn meets_reserve(
+            auction_info: &AuctionInfoOf<T>,
+            amount: BalanceOf<T>,
+        ) -> bool {
+            auction_info
+                .reserve_price
+                .is_none_or(|reserve_price| amount >= reserve_price)
+        }
+
+        // Close an auction that did not reach its reserve price and hand the NFT back
+        fn end_with_reserve_not_met(
+            collection_id: &T::CollectionId,
+            item_id: &T::ItemId,
+            mut auction_info: AuctionInfoOf<T>,
+        ) -> DispatchResult {
+            // Leave the bids to be claimed back
+            Self::clear_bids(collection_id, item_id);
+
+            // Unfreeze the NFTs so the owner can use them again
+            Self::thaw_items(collection_id, item_id, &auction_info.owner)?;
+            Self::clear_bundle(collection_id, item_id);
+
+            auction_info.ended = true;
+            Auctions::<T>::insert((collection_id, item_id), &auction_info);
+
+            Self::deposit_event(Event::ReserveNotMet(
+                collection_id.clone(),
+                *item_id,
+                auction_info.highest_bid,
+            ));
+
+            Ok(())
+        }
+
+        // Auto-resolve auction after timeout
+        fn auto_resolve_auction(
+            collection_id: &T::CollectionId,
+            item_id: &T::ItemId,
+        ) -> DispatchResult {
+            // Get auction info
+            let mut auction_info =
+                Auctions::<T>::get((collection_id, item_id)).ok_or(Error::<T>::AuctionNotFound)?;
+
+            // Check if auction is still active
+            if auction_info.ended {
+                return Err(Error::<T>::AuctionEnded.into());
+            }
+
+            if let AuctionKind::Sealed(pricing) = auction_info.kind {
+                return Self::settle_sealed_auction(collection_id, item_id, auction_info, pricing);
+            }
+
+            // Try to finalize auction with the highest bidder
+            if let Some(highest_bidder) = &auction_info.highest_bidder {
+                // Lower bids cannot reach the reserve either, so return the NFT
+                if !Self::meets_reserve(&auction_info, auction_info.highest_bid) {
+                    return Self::end_with_reserve_not_met(collection_id, item_id, auction_info);
+                }
+
+                // Try to transfer NFT and funds
+                if Self::finalize_auction(
+                    collection_id,
+                    item_id,
+                    highest_bidder,
+                    auction_info.highest_bid,
+                )
+                .is_err()
+                {
+                    // If transfer fails, try next highest bidders, whose bids are held as well
+                    let bids = RankedBids::<T>::get((collection_id, item_id));
+                    for (bidder, bid_amount) in bids.iter() {
+                        if bidder != highest_bidder
+                            && Self::meets_reserve(&auction_info, *bid_amount)
+                            && Self::finalize_auction(collection_id, item_id, bidder, *bid_amount)
+                                .is_ok()
+                        {
+                            return Ok(());
+                        }
+                    }
+                    // If all transfers fail, leave the bids to be claimed and emit auction failed event
+                    Self::clear_bids(collection_id, item_id);
+                    Self::thaw_items(collection_id, item_id, &auction_info.owner)?;
+                    Self::clear_bundle(collection_id, item_id);
+                    auction_info.ended = true;
+                    Auctions::<T>::insert((collection_id, item_id), &auction_info);
+                    Self::deposit_event(Event::AuctionFailed(collection_id.clone(), *item_id));
+                }
+            } else {
+                // No bids, auction failed
+                Self::clear_bids(collection_id, item_id);
+                Self::thaw_items(collection_id, item_id, &auction_info.owner)?;
+                Self::clear_bundle(collection_id, item_id);
+                auction_info.ended = true;
+                Auctions::<T>::insert((collection_id, item_id), &auction_info);
+                Self::deposit_event(Event::AuctionFailed(collection_id.clone(), *item_id));
+            }
+
+            Ok(())
+        }
+
+        // Settle a sealed-bid auction after its reveal phase: slash unrevealed deposits,
+        // release the revealed ones and sell to the best revealed bid that can pay
+        fn settle_sealed_auction(
+            collection_id: &T::CollectionId,
+            item_id: &T::ItemId,
+            mut auction_info: AuctionInfoOf<T>,
+            pricing: SealedBidPricing,
+        ) -> DispatchResult {
+            let mut revealed = Vec::new();
+            for (bidder, bid) in SealedBids::<T>::take((collection_id, item_id)) {
+                match bid.revealed {
+                    Some(amount) => {
+                        Self::release(
+                            &auction_info.asset,
+                            HoldReason::SealedBidDeposit,
+                            &bidder,
+                            bid.deposit,
+                        );
+                        revealed.push((bidder, amount));
+                    }
+                    None => {
+                        let amount = match Self::pay_held(
+                            &auction_info.asset,
+                            HoldReason::SealedBidDeposit,
+                            &bidder,
+                            &Self::account_id(),
+                            bid.deposit,
+                        ) {
+                            Ok(()) => bid.deposit,
+                            // A deposit too small to open the pallet account is given back
+                            Err(_) => {
+                                Self::release(
+                                    &auction_info.asset,
+                                    HoldReason::SealedBidDeposit,
+                                    &bidder,
+                                    bid.deposit,
+                                );
+                                Zero::zero()
+                            }
+                        };
+                        AccumulatedFees::<T>::mutate(auction_info.asset, |f| *f += amount);
+                        Self::deposit_event(Event::SealedBidSlashed(
+                            collection_id.clone(),
+                            *item_id,
+                            bidder,
+                            amount,
+                        ));
+                    }
+                }
+            }
+
+            // Highest bid first, earlier commitments win ties
+            revealed.sort_by(|(_, a), (_, b)| b.cmp(a));
+
+            // Lower bids cannot reach the reserve either, so return the NFT
+            if let Some((_, top)) = revealed.first() {
+                if !Self::meets_reserve(&auction_info, *top) {
+                    auction_info.highest_bid = *top;
+                    return Self::end_with_reserve_not_met(collection_id, item_id, auction_info);
+                }
+            }
+
+            for (rank, (bidder, amount)) in revealed.iter().enumerate() {
+                if !Self::meets_reserve(&auction_info, *amount) {
+                    break;
+                }
+
+                // Vickrey pricing charges the next bid down, but never less than the reserve
+                let price = match pricing {
+                    SealedBidPricing::FirstPrice => *amount,
+                    SealedBidPricing::SecondPrice => revealed
+                        .get(rank + 1)
+                        .map(|(_, next)| *next)
+                        .max(auction_info.reserve_price)
+                        .unwrap_or(*amount),
+                };
+
+                auction_info.highest_bid = price;
+                Auctions::<T>::insert((collection_id, item_id), &auction_info);
+
+                if Self::finalize_auction(collection_id, item_id, bidder, price).is_ok() {
+                    return Ok(());
+                }
+            }
+
+            // Nobody revealed a bid that could be settled
+            Self::clear_bids(collection_id, item_id);
+            Self::thaw_items(collection_id, item_id, &auction_info.owner)?;
+            auction_info.ended = true;
+            Auctions::<T>::insert((collection_id, item_id), &auction_info);
+            Self::deposit_event(Event::AuctionFailed(collection_id.clone(), *item_id));
+
+            Ok(())
+        }
+
+        // Record a settled auction sale in the NFT's history and the collection and account stats
+        fn archive_sale(
+            collection_id: &T::CollectionId,
+            item_id: &T::ItemId,
+            auction_info: &AuctionInfoOf<T>,
+            buyer: &T::AccountId,
+            price: BalanceOf<T>,
+        ) {
+            let asset = auction_info.asset;
+            SalesHistory::<T>::mutate((collection_id, item_id), |history| {
+                if history.is_full() {
+                    history.remove(0);
+                }
+                let _ = history.try_push(SaleRecord {
+                    seller: auction_info.owner.clone(),
+                    buyer: buyer.clone(),
+                    price,
+                    asset,
+                    sold_at: <frame_system::Pallet<T>>::block_number(),
+                });
+            });
+
+            CollectionSummaries::<T>::mutate(collection_id, asset, |summary| {
+                summary.floor = if summary.sales == 0 { price } else { summary.floor.min(price) };
+                summary.last_price = price;
+                summary.volume = summary.volume.saturating_add(price);
+                summary.sales = summary.sales.saturating_add(1);
+            });
+
+            let now = <frame_system::Pallet<T>>::block_number();
+            let bucket_length = T::StatsBucketLength::get().max(One::one());
+            let start = now.saturating_sub(now % bucket_length);
+            let key = (collection_id.clone(), asset, start);
+            if !SalesBuckets::<T>::contains_key(&key) {
+                SalesBucketStarts::<T>::mutate(collection_id, asset, |starts| {
+                    if starts.is_full() {
+                        let oldest = starts.remove(0);
+                        SalesBuckets::<T>::remove((collection_id.clone(), asset, oldest));
+                    }
+                    let _ = starts.try_push(start);
+                });
+            }
+            SalesBuckets::<T>::mutate(&key, |maybe_bucket| {
+                let bucket = maybe_bucket.get_or_insert_with(|| SalesBucket {
+                    floor: price,
+                    volume: Zero::zero(),
+                    sales: 0,
+                    prices: BoundedVec::new(),
+                });
+                bucket.floor = bucket.floor.min(price);
+                bucket.volume = bucket.volume.saturating_add(price);
+                bucket.sales = bucket.sales.saturating_add(1);
+
+                // Reservoir sampling: once the sample is full, the n-th sale replaces a
+                // sampled price with probability `MaxPricesPerBucket / n`
+                if bucket.prices.try_push(price).is_err() {
+                    let seed = T::Hashing::hash_of(&(&key, bucket.sales, price));
+                    let slot = u32::decode(&mut seed.as_ref()).unwrap_or_default() % bucket.sales;
+                    if let Some(sampled) = bucket.prices.get_mut(slot as usize) {
+                        *sampled = price;
+                    }
+                }
+            });
+
+            AccountStatistics::<T>::mutate(buyer, asset, |stats| {
+                stats.volume_bought = stats.volume_bought.saturating_add(price);
+                stats.auctions_won = stats.auctions_won.saturating_add(1);
+            });
+            AccountStatistics::<T>::mutate(&auction_info.owner, asset, |stats| {
+                stats.volume_sold = stats.volume_sold.saturating_add(price);
+            });
+        }
+
+        // Take the sale price from the buyer and pay out royalty, marketplace fee and seller.
+        // With `escrow` the seller's proceeds and the fee go to the pallet account and are
+        // returned as escrowed, the fee is only collected once the escrow is released.
+        // With `held` the price is paid out of funds of the buyer held for that reason, which
+        // must cover it, otherwise out of the buyer's free balance.
+        //
+        // Funds only move between accounts, so settlement neither mints nor burns. A share that
+        // would leave its recipient below the existential deposit goes to the seller instead,
+        // and what the seller cannot receive either stays with the buyer.
+        fn settle_sale(
+            collection_id: &T::CollectionId,
+            seller: &T::AccountId,
+            buyer: &T::AccountId,
+            price: BalanceOf<T>,
+            asset: &AssetOf<T>,
+            held: Option<HoldReason>,
+            escrow: bool,
+        ) -> Result<(BalanceOf<T>, BalanceOf<T>), DispatchError> {
+            // Validate buyer's funds
+            if held.is_none() {
+                let available = match asset {
+                    SettlementAsset::Native => {
+                        <<T as Config>::Currency as fungible::Inspect<_>>::reducible_balance(
+                            buyer,
+                            Preservation::Preserve,
+                            Fortitude::Polite,
+                        )
+                    }
+                    SettlementAsset::Asset(id) => {
+                        <T::Assets as fungibles::Inspect<_>>::reducible_balance(
+                            *id,
+                            buyer,
+                            Preservation::Preserve,
+                            Fortitude::Polite,
+                        )
+                    }
+                };
+                ensure!(available >= price, Error::<T>::NoValidBuyer);
+            }
+
+            // Royalty owed to the collection's recipients
+            let royalties = Self::royalty_shares(collection_id, price);
+            let royalty_amount = royalties
+                .iter()
+                .fold(BalanceOf::<T>::zero(), |total, (_, share)| total.saturating_add(*share));
+
+            // Calculate seller's amount (sale price minus royalty)
+            let seller_amount = price.saturating_sub(royalty_amount);
+
+            let mut fee_amount = Self::fee_rate(collection_id, seller, asset)
+                .mul_floor(price)
+                .min(seller_amount);
+            let mut payout = seller_amount.saturating_sub(fee_amount);
+
+            // Redirect to the seller what royalty recipients and the pallet account cannot receive
+            let mut royalties_paid = Vec::new();
+            for (recipient, share) in royalties {
+                if Self::can_receive(asset, &recipient, share) {
+                    royalties_paid.push((recipient, share));
+                } else {
+                    payout = payout.saturating_add(share);
+                }
+            }
+            if !Self::can_receive(asset, &Self::account_id(), fee_amount) {
+                payout = payout.saturating_add(fee_amount);
+                fee_amount = Zero::zero();
+            }
+            if !Self::can_receive(asset, seller, payout) {
+                payout = Zero::zero();
+            }
+            // Proceeds the pallet account cannot receive are paid out straight away
+            let escrowed = escrow
+                && !payout.is_zero()
+                && Self::can_receive(asset, &Self::account_id(), payout);
+            let payee = if escrowed { Self::account_id() } else { seller.clone() };
+
+            let pay = |to: &T::AccountId, amount: BalanceOf<T>| match held {
+                Some(reason) => Self::pay_held(asset, reason, buyer, to, amount),
+                None => Self::pay(asset, buyer, to, amount),
+            };
+            let mut charged = fee_amount.saturating_add(payout);
+            for (recipient, share) in &royalties_paid {
+                pay(recipient, *share)?;
+                charged = charged.saturating_add(*share);
+            }
+            pay(&Self::account_id(), fee_amount)?;
+            pay(&payee, payout)?;
+
+            // Give back what could not be paid out of the held funds
+            if let Some(reason) = held {
+                Self::release(asset, reason, buyer, price.saturating_sub(charged));
+            }
+
+            // Add fee to pallet storage, unless it is escrowed with the proceeds
+            if !escrowed {
+                AccumulatedFees::<T>::mutate(asset, |f| *f += fee_amount);
+            }
+
+            for (recipient, share) in royalties_paid {
+                Self::deposit_event(Event::RoyaltyPaid(
+                    collection_id.clone(),
+                    recipient,
+                    *asset,
+                    share,
+                ));
+            }
+
+            Ok(if escrowed { (payout, fee_amount) } else { Default::default() })
+        }
+
+        /// Fee rate charged on a sale by `seller` of an NFT of the collection
+        pub fn fee_rate(
+            collection_id: &T::CollectionId,
+            seller: &T::AccountId,
+            asset: &AssetOf<T>,
+        ) -> Permill {
+            if let Some(fee) = CollectionFees::<T>::get(collection_id) {
+                return fee;
+            }
+
+            let volume = AccountStatistics::<T>::get(seller, asset).volume_sold;
+            FeeTiers::<T>::get(asset)
+                .iter()
+                .rev()
+                .find(|(threshold, _)| volume >= *threshold)
+                .map(|(_, fee)| *fee)
+                .unwrap_or_else(MarketFee::<T>::get)
+        }
+
+        /// Royalty, fee and seller payout of a sale of an NFT by `seller` at `price`, in the
+        /// asset the NFT is auctioned or listed in
+        pub fn quote_fees(
+            collection_id: &T::CollectionId,
+            item_id: &T::ItemId,
+            price: BalanceOf<T>,
+            seller: &T::AccountId,
+        ) -> FeeQuote<BalanceOf<T>> {
+            let nft = (collection_id.clone(), *item_id);
+            let asset = Auctions::<T>::get(Self::auction_key(nft.clone()))
+                .map(|auction| auction.asset)
+                .or_else(|| Listings::<T>::get(&nft).map(|listing| listing.asset))
+                .unwrap_or(SettlementAsset::Native);
+
+            let royalty = Self::royalty_shares(collection_id, price)
+                .iter()
+                .fold(BalanceOf::<T>::zero(), |total, (_, share)| total.saturating_add(*share));
+            let seller_amount = price.saturating_sub(royalty);
+            let fee_rate = Self::fee_rate(collection_id, seller, &asset);
+            let fee = fee_rate.mul_floor(price).min(seller_amount);
+
+            FeeQuote { fee_rate, royalty, fee, payout: seller_amount.saturating_sub(fee) }
+        }
+
+        /// Royalty owed on a sale of an NFT of the collection at `price`, split between the
+        /// collection's recipients by weight
+        pub fn royalty_shares(
+            collection_id: &T::CollectionId,
+            price: BalanceOf<T>,
+        ) -> Vec<(T::AccountId, BalanceOf<T>)> {
+            let Some(royalty) = CollectionRoyalties::<T>::get(collection_id) else {
+                return Vec::new();
+            };
+
+            // Terms set before governance lowered the maximum only get the new maximum
+            let total = royalty.rate.min(MaxRoyalty::<T>::get()).mul_floor(price);
+            let total_weight = royalty
+                .recipients
+                .iter()
+                .fold(0u32, |sum, (_, weight)| sum.saturating_add(*weight));
+            if total.is_zero() || total_weight == 0 {
+                return Vec::new();
+            }
+
+            let last = royalty.recipients.len().saturating_sub(1);
+            let mut remaining = total;
+            royalty
+                .recipients
+                .into_iter()
+                .enumerate()
+                .filter_map(|(index, (recipient, weight))| {
+                    // The last recipient also receives what rounding leaves over
+                    let share = if index == last {
+                        remaining
+                    } else {
+                        Perbill::from_rational(weight, total_weight).mul_floor(total)
+                    };
+                    remaining = remaining.saturating_sub(share);
+                    (!share.is_zero()).then_some((recipient, share))
+                })
+                .collect()
+        }
+
+        // Pay escrowed proceeds and fee out of the pallet account to the buyer on `refund`, who
+        // returns the NFTs to the seller. Otherwise release the proceeds to the seller and
+        // collect the fee
+        fn close_escrow(
+            nft: (T::CollectionId, T::ItemId),
+            escrow: EscrowInfoOf<T>,
+            refund: bool,
+        ) -> DispatchResult {
+            let (collection_id, item_id) = nft.clone();
+            let (to, amount) = if refund {
+                for (collection_id, item_id) in Self::auction_items(&collection_id, &item_id) {
+                    ensure!(
+                        pallet_uniques::Pallet::<T>::owner(collection_id.clone(), item_id)
+                            .as_ref()
+                            == Some(&escrow.buyer),
+                        Error::<T>::NftNotReturnable
+                    );
+                    pallet_uniques::Pallet::<T>::do_transfer(
+                        collection_id,
+                        item_id,
+                        escrow.seller.clone(),
+                        |_, _| Ok(()),
+                    )?;
+                }
+                (&escrow.buyer, escrow.amount.saturating_add(escrow.fee))
+            } else {
+                AccumulatedFees::<T>::mutate(escrow.asset, |f| *f += escrow.fee);
+                (&escrow.seller, escrow.amount)
+            };
+            match escrow.asset {
+                SettlementAsset::Native => {
+                    <<T as Config>::Currency as fungible::Mutate<_>>::transfer(
+                        &Self::account_id(),
+                        to,
+                        amount,
+                        Preservation::Expendable,
+                    )?;
+                }
+                SettlementAsset::Asset(id) => {
+                    <T::Assets as fungibles::Mutate<_>>::transfer(
+                        id,
+                        &Self::account_id(),
+                        to,
+                        amount,
+                        Preservation::Expendable,
+                    )?;
+                }
+            }
+            Escrows::<T>::remove(&nft);
+            Self::clear_bundle(&collection_id, &item_id);
+
+            let event = if refund {
+                Event::EscrowRefunded(collection_id, item_id, to.clone(), amount)
+            } else {
+                Event::EscrowReleased(collection_id, item_id, to.clone(), amount)
+            };
+            Self::deposit_event(event);
+            Ok(())
+        }
+
+        // Unfreeze a listed NFT and transfer it from `owner` to `buyer`
+        fn transfer_nft(
+            collection_id: &T::CollectionId,
+            item_id: &T::ItemId,
+            owner: &T::AccountId,
+            buyer: &T::AccountId,
+        ) -> DispatchResult {
+            pallet_uniques::Pallet::<T>::thaw(
+                frame_system::RawOrigin::Signed(owner.clone()).into(),
+                collection_id.clone(),
+                *item_id,
+            )?;
+
+            pallet_uniques::Pallet::<T>::do_transfer(
+                collection_id.clone(),
+                *item_id,
+                buyer.clone(),
+                |_, _| Ok(()),
+            )
+        }
+
+        // Finalize auction by transferring NFT and handling funds
+        #[transactional]
+        fn finalize_auction(
+            collection_id: &T::CollectionId,
+            item_id: &T::ItemId,
+            buyer: &T::AccountId,
+            bid_amount: BalanceOf<T>,
+        ) -> DispatchResult {
+            // Retrieve auction information
+            let auction_info =
+                Auctions::<T>::get((collection_id, item_id)).ok_or(Error::<T>::AuctionNotFound)?;
+
+            // Ensure auction hasn't already ended
+            ensure!(!auction_info.ended, Error::<T>::AuctionEnded);
+
+            // Verify current ownership of every NFT sold
+            let items = Self::auction_items(collection_id, item_id);
+            for (collection_id, item_id) in items.iter() {
+                let current_owner =
+                    pallet_uniques::Pallet::<T>::owner(collection_id.clone(), *item_id)
+                        .ok_or(Error::<T>::NftNotFound)?;
+                ensure!(current_owner == auction_info.owner, Error::<T>::NotNftOwner);
+            }
+
+            // A buyer whose ranked bid covers the price pays out of it, anyone else out of their
+            // free balance. Every other bid is left to be claimed back.
+            let nft = (collection_id.clone(), *item_id);
+            let ranked_bid = RankedBids::<T>::get(&nft)
+                .iter()
+                .any(|(bidder, _)| bidder == buyer)
+                .then(|| BidLedger::<T>::take(buyer, &nft))
+                .flatten();
+            let held = match ranked_bid.map(|bid| bid.amount) {
+                Some(amount) => {
+                    let held = (amount >= bid_amount).then_some(HoldReason::AuctionBid);
+                    let surplus = if held.is_some() { amount - bid_amount } else { amount };
+                    Self::release(&auction_info.asset, HoldReason::AuctionBid, buyer, surplus);
+                    held
+                }
+                None => None,
+            };
+            Self::clear_bids(collection_id, item_id);
+
+            // Pay the seller, or escrow the proceeds, and charge royalty and fees
+            let escrow_period = T::EscrowPeriod::get();
+            let (escrowed, escrowed_fee) = Self::settle_sale(
+                collection_id,
+                &auction_info.owner,
+                buyer,
+                bid_amount,
+                &auction_info.asset,
+                held,
+                !escrow_period.is_zero(),
+            )?;
+            if !escrowed.is_zero() {
+                let release_at =
+                    <frame_system::Pallet<T>>::block_number().saturating_add(escrow_period);
+                Escrows::<T>::insert(
+                    (collection_id, item_id),
+                    EscrowInfo {
+                        seller: auction_info.owner.clone(),
+                        buyer: buyer.clone(),
+                        asset: auction_info.asset,
+                        amount: escrowed,
+                        fee: escrowed_fee,
+                        release_at,
+                        disputed: false,
+                    },
+                );
+                Self::deposit_event(Event::ProceedsEscrowed(
+                    collection_id.clone(),
+                    *item_id,
+                    auction_info.owner.clone(),
+                    escrowed,
+                    release_at,
+                ));
+            }
+
+            // Unfreeze the NFTs and transfer them to the buyer. The bundle is kept while in
+            // escrow, for its NFTs to be returned on a refund
+            for (collection_id, item_id) in items.iter() {
+                Self::transfer_nft(collection_id, item_id, &auction_info.owner, buyer)?;
+            }
+            if escrowed.is_zero() {
+                Self::clear_bundle(collection_id, item_id);
+            }
 
-            // Clear bids
-            Bids::<T>::remove((collection_id, item_id));
+            // Move the sale into the history and drop the live auction
+            Auctions::<T>::remove((collection_id, item_id));
+            Self::archive_sale(collection_id, item_id, &auction_info, buyer, bid_amount);
 
             // Emit auction resolved event
             Self::deposit_event(Event::AuctionResolved(
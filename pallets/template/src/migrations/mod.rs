@@ -1,24 +1,45 @@
-
 use super::*;
 use frame_support::{pallet_prelude::*, traits::OnRuntimeUpgrade, weights::Weight};
 use sp_std::marker::PhantomData;
 
 pub mod v1;
+pub mod v2;
 /// The current storage version.
-pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
 
-/// Migration from u32 to u64 in Prices storage
+/// Migration removing DummyStorage
 pub struct MigrateToV2<T>(PhantomData<T>);
 
 impl<T: Config> OnRuntimeUpgrade for MigrateToV2<T> {
     fn on_runtime_upgrade() -> Weight {
         let current_version = Pallet::<T>::in_code_storage_version();
         let onchain_version = Pallet::<T>::on_chain_storage_version();
-        
-        if current_version == STORAGE_VERSION && onchain_version < STORAGE_VERSION {
+
+        if current_version == STORAGE_VERSION && onchain_version < 2 {
             return v1::migrate::<T>();
         }
-        
+
+        T::DbWeight::get().reads(1)
+    }
+}
+
+/// Migration of the original auction storage to the current layout. `OldCurrency` is the
+/// currency bids used to be reserved in
+pub struct MigrateToV3<T, OldCurrency>(PhantomData<(T, OldCurrency)>);
+
+impl<T, OldCurrency> OnRuntimeUpgrade for MigrateToV3<T, OldCurrency>
+where
+    T: Config,
+    OldCurrency: frame_support::traits::ReservableCurrency<T::AccountId, Balance = v2::BalanceOf<T>>,
+{
+    fn on_runtime_upgrade() -> Weight {
+        let current_version = Pallet::<T>::in_code_storage_version();
+        let onchain_version = Pallet::<T>::on_chain_storage_version();
+
+        if current_version == STORAGE_VERSION && onchain_version == 2 {
+            return v2::migrate::<T, OldCurrency>();
+        }
+
         T::DbWeight::get().reads(1)
     }
-}
\ No newline at end of file
+}
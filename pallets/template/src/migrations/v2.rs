@@ -0,0 +1,357 @@
+//! # V2 Migration
+//!
+//! Migration from V2 to V3
+//! This migration converts auctions stored in the original layout, which only had an owner,
+//! start block, highest bid and an ended flag, to the current `AuctionInfo`, moves the
+//! reserved highest bids onto fungible holds and into the bid ledger, drops the `Bids` and
+//! `InAuction` maps, keeps the accumulated fees, which were only collected in the native
+//! currency, per settlement asset, and turns the whole percent `FeePercentage` into the
+//! `Permill` market fee.
+
+use super::*;
+use frame_support::{
+    storage_alias,
+    traits::{fungible, ReservableCurrency},
+    weights::Weight,
+    Blake2_128Concat,
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+use primitives::{AuctionKind, SettlementAsset};
+use sp_runtime::{
+    traits::{Saturating, Zero},
+    Permill,
+};
+use sp_std::{vec, vec::Vec};
+
+pub type BalanceOf<T> =
+    <<T as Config>::Currency as fungible::Inspect<<T as frame_system::Config>::AccountId>>::Balance;
+
+/// Auction information as stored before V3
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct OldAuctionInfo<AccountId, Balance, BlockNumber> {
+    pub owner: AccountId,
+    pub start_block: BlockNumber,
+    pub highest_bid: Balance,
+    pub highest_bidder: Option<AccountId>,
+    pub ended: bool,
+}
+
+type OldAuctionInfoOf<T> =
+    OldAuctionInfo<<T as frame_system::Config>::AccountId, BalanceOf<T>, BlockNumberFor<T>>;
+
+pub mod old {
+    use super::*;
+
+    #[storage_alias]
+    pub type Auctions<T: Config> = StorageMap<
+        Pallet<T>,
+        Blake2_128Concat,
+        (
+            <T as pallet_uniques::Config>::CollectionId,
+            <T as pallet_uniques::Config>::ItemId,
+        ),
+        OldAuctionInfoOf<T>,
+        OptionQuery,
+    >;
+
+    #[storage_alias]
+    pub type Bids<T: Config> = StorageMap<
+        Pallet<T>,
+        Blake2_128Concat,
+        (
+            <T as pallet_uniques::Config>::CollectionId,
+            <T as pallet_uniques::Config>::ItemId,
+        ),
+        BoundedVec<
+            (<T as frame_system::Config>::AccountId, BalanceOf<T>),
+            <T as Config>::MaxBidsPerAuction,
+        >,
+        ValueQuery,
+    >;
+
+    #[storage_alias]
+    pub type InAuction<T: Config> = StorageMap<
+        Pallet<T>,
+        Blake2_128Concat,
+        (
+            <T as pallet_uniques::Config>::CollectionId,
+            <T as pallet_uniques::Config>::ItemId,
+        ),
+        bool,
+        ValueQuery,
+    >;
+
+    #[storage_alias]
+    pub type AccumulatedFees<T: Config> = StorageValue<Pallet<T>, BalanceOf<T>, ValueQuery>;
+
+    #[storage_alias]
+    pub type FeePercentage<T: Config> = StorageValue<Pallet<T>, u8, OptionQuery>;
+}
+
+/// Perform the V2 -> V3 migration.
+///
+/// Running auctions end `AuctionTimeoutBlocks` after they started, as they did before, and
+/// are scheduled to auto-resolve then, or straight away when that block has passed. Their
+/// highest bid was reserved with `OldCurrency` and is held under `HoldReason::AuctionBid`
+/// instead, a bid that cannot be held is given back. It becomes the only ranked bid, as the
+/// outbid bids listed in `Bids` were already unreserved. Ended auctions that failed to sell
+/// left their NFT frozen and their highest bid reserved, both are released.
+pub fn migrate<T, OldCurrency>() -> Weight
+where
+    T: Config,
+    OldCurrency: ReservableCurrency<T::AccountId, Balance = BalanceOf<T>>,
+{
+    let now = frame_system::Pallet::<T>::block_number();
+    let mut reads = 0u64;
+    let mut writes = 0u64;
+
+    let auctions: Vec<_> = old::Auctions::<T>::drain().collect();
+    reads += auctions.len() as u64;
+    writes += auctions.len() as u64;
+
+    for ((collection_id, item_id), old) in auctions {
+        let end_block = old.start_block.saturating_add(T::AuctionTimeoutBlocks::get());
+        let (mut highest_bidder, mut highest_bid) = (old.highest_bidder, old.highest_bid);
+        old::Bids::<T>::remove((collection_id.clone(), item_id));
+        let in_auction = old::InAuction::<T>::take((collection_id.clone(), item_id));
+        reads += 1;
+        writes += 2;
+
+        if old.ended {
+            // A failed auction never left the in-auction set and kept its NFT frozen
+            if in_auction {
+                if let Err(e) = pallet_uniques::Pallet::<T>::thaw(
+                    frame_system::RawOrigin::Signed(old.owner.clone()).into(),
+                    collection_id.clone(),
+                    item_id,
+                ) {
+                    log::warn!("Could not thaw the NFT of a failed auction: {:?}", e);
+                }
+                if let Some(bidder) = &highest_bidder {
+                    OldCurrency::unreserve(bidder, highest_bid);
+                }
+                reads += 3;
+                writes += 2;
+            }
+        } else {
+            if let Some(bidder) = &highest_bidder {
+                let unreserved =
+                    highest_bid.saturating_sub(OldCurrency::unreserve(bidder, highest_bid));
+                if let Err(e) = <<T as Config>::Currency as fungible::MutateHold<_>>::hold(
+                    &HoldReason::AuctionBid.into(),
+                    bidder,
+                    unreserved,
+                ) {
+                    log::warn!("Could not hold a reserved bid, giving it back: {:?}", e);
+                    highest_bidder = None;
+                    highest_bid = Zero::zero();
+                } else {
+                    highest_bid = unreserved;
+                    BidLedger::<T>::insert(
+                        bidder,
+                        (collection_id.clone(), item_id),
+                        LedgerBid {
+                            amount: highest_bid,
+                            placed_at: now,
+                            asset: SettlementAsset::Native,
+                        },
+                    );
+                    RankedBids::<T>::insert(
+                        (collection_id.clone(), item_id),
+                        BoundedVec::truncate_from(vec![(bidder.clone(), highest_bid)]),
+                    );
+                }
+                reads += 2;
+                writes += 4;
+            }
+
+            // Schedule the expiry no earlier than this block, in the first block with room
+            let mut block = end_block.max(now);
+            while Pallet::<T>::schedule_expiry(block, &collection_id, &item_id).is_err() {
+                block = block.saturating_add(One::one());
+                reads += 1;
+            }
+            reads += 1;
+            writes += 1;
+        }
+
+        Auctions::<T>::insert(
+            (collection_id, item_id),
+            AuctionInfo {
+                owner: old.owner,
+                start_block: old.start_block,
+                end_block,
+                highest_bid,
+                highest_bidder,
+                ended: old.ended,
+                reserve_price: None,
+                min_increment: None,
+                extended_by: Zero::zero(),
+                buy_now_price: None,
+                kind: AuctionKind::English,
+                asset: SettlementAsset::Native,
+            },
+        );
+    }
+
+    // Entries left behind without an auction
+    let _ = old::Bids::<T>::clear(u32::MAX, None);
+    let _ = old::InAuction::<T>::clear(u32::MAX, None);
+    writes += 2;
+
+    // Fees used to be collected in the native currency only
+    let fees = old::AccumulatedFees::<T>::take();
+    if !fees.is_zero() {
+        AccumulatedFees::<T>::insert(SettlementAsset::Native, fees);
+    }
+    reads += 1;
+    writes += 2;
+
+    // The market fee used to be a whole percentage
+    if let Some(percent) = old::FeePercentage::<T>::take() {
+        MarketFee::<T>::put(Permill::from_percent(percent.into()));
+    }
+    reads += 1;
+    writes += 2;
+
+    // Update storage version
+    StorageVersion::new(3).put::<Pallet<T>>();
+    writes += 1;
+
+    log::info!("✅ Migration to v3 completed successfully");
+    T::DbWeight::get().reads_writes(reads, writes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock::{new_test_ext, Balances, Test, Uniques};
+    use frame_support::{assert_ok, traits::fungible::InspectHold};
+
+    fn old_auction(owner: u64, start_block: u64, ended: bool) -> OldAuctionInfoOf<Test> {
+        OldAuctionInfo { owner, start_block, highest_bid: 0, highest_bidder: None, ended }
+    }
+
+    #[test]
+    fn migrates_old_auctions_and_schedules_their_expiry() {
+        new_test_ext().execute_with(|| {
+            frame_system::Pallet::<Test>::set_block_number(150);
+            old::Auctions::<Test>::insert((0, 0), old_auction(1, 100, false));
+            old::Auctions::<Test>::insert((0, 1), old_auction(1, 10, false));
+
+            migrate::<Test, Balances>();
+
+            let auction = Auctions::<Test>::get((0, 0)).unwrap();
+            assert_eq!(auction.end_block, 200);
+            assert_eq!(auction.kind, AuctionKind::English);
+            assert_eq!(auction.asset, SettlementAsset::Native);
+            assert_eq!(AuctionExpiries::<Test>::get(200).into_inner(), vec![(0, 0)]);
+            // Already overdue, so it resolves in the upgrade block
+            assert_eq!(AuctionExpiries::<Test>::get(150).into_inner(), vec![(0, 1)]);
+            assert_eq!(Pallet::<Test>::on_chain_storage_version(), StorageVersion::new(3));
+        });
+    }
+
+    #[test]
+    fn thaws_the_nft_of_a_failed_auction() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(Uniques::create(frame_system::RawOrigin::Signed(1).into(), 0, 1));
+            assert_ok!(Uniques::mint(frame_system::RawOrigin::Signed(1).into(), 0, 0, 1));
+            assert_ok!(Uniques::freeze(frame_system::RawOrigin::Signed(1).into(), 0, 0));
+            old::Auctions::<Test>::insert((0, 0), old_auction(1, 1, true));
+            old::InAuction::<Test>::insert((0, 0), true);
+
+            migrate::<Test, Balances>();
+
+            assert!(Auctions::<Test>::get((0, 0)).unwrap().ended);
+            assert_ok!(Uniques::transfer(frame_system::RawOrigin::Signed(1).into(), 0, 0, 2));
+        });
+    }
+
+    #[test]
+    fn moves_reserved_highest_bids_onto_holds() {
+        new_test_ext().execute_with(|| {
+            let reason = HoldReason::AuctionBid.into();
+            assert_ok!(Balances::reserve(&2, 500));
+            old::Auctions::<Test>::insert(
+                (0, 0),
+                OldAuctionInfo {
+                    highest_bid: 500,
+                    highest_bidder: Some(2),
+                    ..old_auction(1, 1, false)
+                },
+            );
+            // The reserve of a failed auction was never released
+            assert_ok!(Balances::reserve(&3, 300));
+            old::Auctions::<Test>::insert(
+                (0, 1),
+                OldAuctionInfo {
+                    highest_bid: 300,
+                    highest_bidder: Some(3),
+                    ..old_auction(1, 1, true)
+                },
+            );
+            old::InAuction::<Test>::insert((0, 1), true);
+
+            migrate::<Test, Balances>();
+
+            assert_eq!(Balances::balance_on_hold(&reason, &2), 500);
+            assert_eq!(Balances::reserved_balance(2), 500);
+            assert_eq!(Auctions::<Test>::get((0, 0)).unwrap().highest_bidder, Some(2));
+            assert_eq!(Balances::reserved_balance(3), 0);
+        });
+    }
+
+    #[test]
+    fn ranks_the_held_bid_and_drops_old_bid_lists() {
+        new_test_ext().execute_with(|| {
+            assert_ok!(Balances::reserve(&3, 600));
+            old::Auctions::<Test>::insert(
+                (0, 0),
+                OldAuctionInfo {
+                    highest_bid: 600,
+                    highest_bidder: Some(3),
+                    ..old_auction(1, 1, false)
+                },
+            );
+            // The outbid bid of account 2 was already unreserved
+            old::Bids::<Test>::insert((0, 0), BoundedVec::truncate_from(vec![(3, 600), (2, 500)]));
+            old::InAuction::<Test>::insert((0, 0), true);
+
+            migrate::<Test, Balances>();
+
+            assert_eq!(RankedBids::<Test>::get((0, 0)).into_inner(), vec![(3, 600)]);
+            assert_eq!(
+                BidLedger::<Test>::get(3, (0, 0)),
+                Some(LedgerBid { amount: 600, placed_at: 1, asset: SettlementAsset::Native })
+            );
+            assert_eq!(BidLedger::<Test>::get(2, (0, 0)), None);
+            assert!(!old::Bids::<Test>::contains_key((0, 0)));
+            assert!(!old::InAuction::<Test>::contains_key((0, 0)));
+        });
+    }
+
+    #[test]
+    fn keeps_accumulated_fees_as_native_fees() {
+        new_test_ext().execute_with(|| {
+            old::AccumulatedFees::<Test>::put(50);
+
+            migrate::<Test, Balances>();
+
+            assert_eq!(AccumulatedFees::<Test>::get(SettlementAsset::Native), 50);
+            assert_eq!(AccumulatedFees::<Test>::iter().count(), 1);
+        });
+    }
+
+    #[test]
+    fn turns_the_fee_percentage_into_the_market_fee() {
+        new_test_ext().execute_with(|| {
+            old::FeePercentage::<Test>::put(5);
+
+            migrate::<Test, Balances>();
+
+            assert_eq!(MarketFee::<Test>::get(), Permill::from_percent(5));
+            assert!(!old::FeePercentage::<Test>::exists());
+        });
+    }
+}
@@ -4,7 +4,7 @@ use frame_support::parameter_types;
 use frame_support::traits::Currency;
 use frame_support::PalletId;
 use frame_support::{
-    traits::{ConstU128, ConstU32, ConstU64, ConstU8},
+    traits::{AsEnsureOriginWithArg, ConstU128, ConstU32, ConstU64, ConstU8, IsInVec},
 };
 use frame_system::{self as system};
 use sp_core::H256;
@@ -42,6 +42,9 @@ mod runtime {
 
     #[runtime::pallet_index(3)]
     pub type Uniques = pallet_uniques::Pallet<Test>;
+
+    #[runtime::pallet_index(4)]
+    pub type Assets = pallet_assets::Pallet<Test>;
 }
 
 #[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
@@ -79,7 +82,7 @@ impl pallet_balances::Config for Test {
     /// The ubiquitous event type.
     type RuntimeEvent = RuntimeEvent;
     type DustRemoval = ();
-    type ExistentialDeposit = ConstU128<1>;
+    type ExistentialDeposit = ExistentialDeposit;
     type AccountStore = System;
     type WeightInfo = ();
     type FreezeIdentifier = RuntimeFreezeReason;
@@ -90,15 +93,53 @@ impl pallet_balances::Config for Test {
 }
 
 parameter_types! {
+    pub static ExistentialDeposit: u128 = 1;
     pub const TemplatePalletId: PalletId = PalletId(*b"ex/auctn");
+    pub const TreasuryAccount: u64 = 90;
+    pub const RewardsAccount: u64 = 91;
+    pub static CancellationPenaltyRecipient: pallet_template::PenaltyRecipient =
+        pallet_template::PenaltyRecipient::TopBidder;
+    pub static EscrowPeriod: u64 = 0;
+    pub static ProfileAccounts: Vec<u64> = vec![2];
 }
 
 impl pallet_template::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type Currency = Balances;
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type AssetId = u32;
+    type Assets = Assets;
     type MaxBidsPerAuction = ConstU32<10>;
     type AuctionTimeoutBlocks = ConstU64<100>;
-    type RoyaltyPercentage = ConstU8<10>;
+    type MinAuctionDuration = ConstU64<10>;
+    type MaxAuctionDuration = ConstU64<1000>;
+    type SoftCloseWindow = ConstU64<5>;
+    type SoftCloseExtension = ConstU64<10>;
+    type MaxSoftCloseExtension = ConstU64<15>;
+    type BuyNowBidThreshold = ConstU8<80>;
+    type SealedBidRevealPeriod = ConstU64<5>;
+    type CancellationPenalty = ConstU8<10>;
+    type CancellationPenaltyRecipient = CancellationPenaltyRecipient;
+    type BidRetractionCooldown = ConstU64<5>;
+    type MaxRoyaltyRecipients = ConstU32<5>;
+    type MaxBatchListingSize = ConstU32<10>;
+    type MaxAuctionsPerBlock = ConstU32<10>;
+    type MaxResolutionsPerBlock = ConstU32<3>;
+    type MaxRefundsPerClaim = ConstU32<10>;
+    type MaxSalesHistory = ConstU32<3>;
+    type StatsBucketLength = ConstU64<10>;
+    type MaxStatsBuckets = ConstU32<3>;
+    type MaxPricesPerBucket = ConstU32<4>;
+    type EscrowPeriod = EscrowPeriod;
+    type DisputeOrigin = frame_system::EnsureRoot<u64>;
+    type MaxBundleSize = ConstU32<5>;
+    type ProfileHolders = IsInVec<ProfileAccounts>;
+    type MaxAllowlistLen = ConstU32<3>;
+    type AdminOrigin = frame_system::EnsureRoot<u64>;
+    type MaxFeeTiers = ConstU32<3>;
+    type TreasuryAccount = TreasuryAccount;
+    type RewardsAccount = RewardsAccount;
+    type MaxFeeAssetsPerDistribution = ConstU32<2>;
     type PalletId = TemplatePalletId;
     type WeightInfo = ();
 }
@@ -123,11 +164,35 @@ impl pallet_uniques::Config for Test {
     type KeyLimit = ConstU32<32>;
     type ValueLimit = ConstU32<64>;
     type WeightInfo = ();
+    #[cfg(feature = "runtime-benchmarks")]
     type Helper = ();
 }
 
+impl pallet_assets::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type Balance = u128;
+    type AssetId = u32;
+    type AssetIdParameter = u32;
+    type Currency = Balances;
+    type CreateOrigin = AsEnsureOriginWithArg<frame_system::EnsureSigned<u64>>;
+    type ForceOrigin = frame_system::EnsureRoot<u64>;
+    type AssetDeposit = ConstU128<1>;
+    type AssetAccountDeposit = ConstU128<1>;
+    type MetadataDepositBase = ConstU128<1>;
+    type MetadataDepositPerByte = ConstU128<1>;
+    type ApprovalDeposit = ConstU128<1>;
+    type StringLimit = ConstU32<50>;
+    type Freezer = ();
+    type Holder = ();
+    type Extra = ();
+    type CallbackHandle = ();
+    type WeightInfo = ();
+    type RemoveItemsLimit = ConstU32<1000>;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = ();
+}
+
 pub fn new_test_ext() -> sp_io::TestExternalities {
-    let pallet_account = pallet_template::Pallet::<Test>::account_id();
     let mut t = system::GenesisConfig::<Test>::default()
         .build_storage()
         .unwrap();
@@ -138,12 +203,14 @@ pub fn new_test_ext() -> sp_io::TestExternalities {
             (3, 3000 * 1_000_000_000), // Bidder 2
             (4, 4000 * 1_000_000_000), // Bidder 3
             (5, 5000 * 1_000_000_000), // Bidder 4
-            (pallet_account, 1_000_000_000),
         ],
         dev_accounts: None,
     }
     .assimilate_storage(&mut t)
     .unwrap();
+    pallet_template::GenesisConfig::<Test>::default()
+        .assimilate_storage(&mut t)
+        .unwrap();
     
     // Initialize the accounts explicitly
     let mut ext = sp_io::TestExternalities::new(t);
@@ -152,7 +219,6 @@ pub fn new_test_ext() -> sp_io::TestExternalities {
         for account_id in 1..=5 {
             frame_system::Pallet::<Test>::inc_providers(&account_id);
         }
-        frame_system::Pallet::<Test>::inc_providers(&pallet_account);
         // Set block number to 1 for event emission
         frame_system::Pallet::<Test>::set_block_number(1);
     });
@@ -1,9 +1,16 @@
-use crate::{mock::*, AccumulatedFees, Error, Event};
+use crate::{mock::*, AccumulatedFees, Error, Event, FeeDistribution, MarketParameters, MaxBids};
+use primitives::{
+    AccountStats, AuctionKind, AuctionSettings, BatchListingInfo, BidIncrement, BidderAccess,
+    CollectionStats, CollectionSummary, FeeQuote, SealedBidPricing, SettlementAsset,
+};
+use sp_runtime::traits::{BlakeTwo256, Hash};
+use sp_runtime::{BuildStorage, Perbill, Permill};
 use frame_support::traits::nonfungibles::Create;
 use frame_support::traits::OnInitialize;
 use frame_support::{assert_noop, assert_ok};
 use frame_system::Origin;
 use frame_support::traits::Currency;
+use frame_support::traits::{fungible::MutateHold, tokens::Precision};
 
 #[test]
 fn list_nft_for_auction_works() {
@@ -38,7 +45,9 @@ fn list_nft_for_auction_works() {
         assert_ok!(Template::list_nft_for_auction(
             RuntimeOrigin::signed(owner),
             collection_id,
-            item_id
+            item_id,
+            AuctionSettings::default(),
+            SettlementAsset::Native
         ));
 
         // Assert: Check auction info is stored
@@ -89,12 +98,20 @@ fn cant_list_asset_twice() {
         assert_ok!(Template::list_nft_for_auction(
             RuntimeOrigin::signed(owner),
             collection_id,
-            item_id
+            item_id,
+            AuctionSettings::default(),
+            SettlementAsset::Native
         ));
 
         // Act & Assert: Try to list the same asset again
         assert_noop!(
-            Template::list_nft_for_auction(RuntimeOrigin::signed(owner), collection_id, item_id),
+            Template::list_nft_for_auction(
+                RuntimeOrigin::signed(owner),
+                collection_id,
+                item_id,
+                AuctionSettings::default(),
+                SettlementAsset::Native
+            ),
             Error::<Test>::NftAlreadyInAuction
         );
     });
@@ -133,7 +150,9 @@ fn place_bid_works() {
         assert_ok!(Template::list_nft_for_auction(
             RuntimeOrigin::signed(owner),
             collection_id,
-            item_id
+            item_id,
+            AuctionSettings::default(),
+            SettlementAsset::Native
         ));
 
         // Act: Place a bid
@@ -190,7 +209,9 @@ fn cant_bid_on_own_auction() {
         assert_ok!(Template::list_nft_for_auction(
             RuntimeOrigin::signed(owner),
             collection_id,
-            item_id
+            item_id,
+            AuctionSettings::default(),
+            SettlementAsset::Native
         ));
 
         // Act & Assert: Try to bid on own Template
@@ -234,7 +255,9 @@ fn must_bid_higher() {
         assert_ok!(Template::list_nft_for_auction(
             RuntimeOrigin::signed(owner),
             collection_id,
-            item_id
+            item_id,
+            AuctionSettings::default(),
+            SettlementAsset::Native
         ));
 
         assert_ok!(Template::place_bid(
@@ -291,7 +314,9 @@ fn increase_own_bid_works() {
         assert_ok!(Template::list_nft_for_auction(
             RuntimeOrigin::signed(owner),
             collection_id,
-            item_id
+            item_id,
+            AuctionSettings::default(),
+            SettlementAsset::Native
         ));
 
         assert_ok!(Template::place_bid(
@@ -320,7 +345,7 @@ fn increase_own_bid_works() {
 }
 
 #[test]
-fn outbid_works_and_unreserves_previous_bid() {
+fn outbid_works_and_keeps_previous_bid_held() {
     new_test_ext().execute_with(|| {
         // Arrange: List an asset and place a bid
         System::set_block_number(1);
@@ -352,7 +377,9 @@ fn outbid_works_and_unreserves_previous_bid() {
         assert_ok!(Template::list_nft_for_auction(
             RuntimeOrigin::signed(owner),
             collection_id,
-            item_id
+            item_id,
+            AuctionSettings::default(),
+            SettlementAsset::Native
         ));
 
         assert_ok!(Template::place_bid(
@@ -375,8 +402,9 @@ fn outbid_works_and_unreserves_previous_bid() {
         assert_eq!(auction.highest_bid, 60);
         assert_eq!(auction.highest_bidder, Some(3));
 
-        // Check previous bidder's funds were unreserved
-        assert_eq!(Balances::reserved_balance(2), 0);
+        // Check previous bidder's funds stay held as a fallback
+        assert_eq!(Balances::reserved_balance(2), 50);
+        assert_eq!(Template::top_bids(collection_id, item_id, 2), vec![(3, 60), (2, 50)]);
 
         // Check new bidder's funds were reserved
         assert_eq!(Balances::reserved_balance(3), 60);
@@ -416,7 +444,9 @@ fn choose_buyer_works() {
         assert_ok!(Template::list_nft_for_auction(
             RuntimeOrigin::signed(owner),
             collection_id,
-            item_id
+            item_id,
+            AuctionSettings::default(),
+            SettlementAsset::Native
         ));
 
         assert_ok!(Template::place_bid(
@@ -439,16 +469,17 @@ fn choose_buyer_works() {
             item_id
         ));
 
-        // Check Template is marked as ended
-        let auction = Template::auctions((collection_id, item_id)).unwrap();
-        assert_eq!(auction.ended, true);
+        // Check the sold auction was archived
+        assert!(Template::auctions((collection_id, item_id)).is_none());
 
         // Check funds were transferred
+        // The other bidder claims their funds back
+        assert_ok!(Template::claim_refund(RuntimeOrigin::signed(2), collection_id, item_id));
         assert_eq!(Balances::reserved_balance(2), 0); // Other bidder's funds released
         assert_eq!(Balances::reserved_balance(3), 0); // Non funds left
 
         // Check event was emitted
-        System::assert_last_event(Event::AuctionResolved(collection_id, item_id, 3, 60).into());
+        System::assert_has_event(Event::AuctionResolved(collection_id, item_id, 3, 60).into());
     });
 }
 
@@ -459,7 +490,10 @@ fn fee_accumulation_works() {
         System::set_block_number(1);
 
         let fee_percent = 10;
-        assert_ok!(Template::set_fee_percentage(Origin::<Test>::Root.into(), fee_percent));
+        assert_ok!(Template::set_market_fee(
+            Origin::<Test>::Root.into(),
+            Permill::from_percent(fee_percent)
+        ));
 
         let collection_id = 1;
         let item_id = 1;
@@ -488,7 +522,9 @@ fn fee_accumulation_works() {
         assert_ok!(Template::list_nft_for_auction(
             RuntimeOrigin::signed(owner),
             collection_id,
-            item_id
+            item_id,
+            AuctionSettings::default(),
+            SettlementAsset::Native
         ));
 
         assert_ok!(Template::place_bid(
@@ -511,20 +547,21 @@ fn fee_accumulation_works() {
             item_id
         ));
 
-        // Check Template is marked as ended
-        let auction = Template::auctions((collection_id, item_id)).unwrap();
-        assert_eq!(auction.ended, true);
+        // Check the sold auction was archived
+        assert!(Template::auctions((collection_id, item_id)).is_none());
 
         let bid_amount = 60;
         let fee_amount = bid_amount * (fee_percent as u128) / 100u128;
 
         // Check funds were transferred
+        // The other bidder claims their funds back
+        assert_ok!(Template::claim_refund(RuntimeOrigin::signed(2), collection_id, item_id));
         assert_eq!(Balances::reserved_balance(2), 0); // Other bidder's funds released
         assert_eq!(Balances::reserved_balance(3), 0); // Non funds left
-        assert_eq!(Template::accumulated_fees(), fee_amount);
+        assert_eq!(Template::accumulated_fees(SettlementAsset::Native), fee_amount);
 
         // Check event was emitted
-        System::assert_last_event(Event::AuctionResolved(collection_id, item_id, 3, 60).into());
+        System::assert_has_event(Event::AuctionResolved(collection_id, item_id, 3, 60).into());
     });
 }
 
@@ -561,7 +598,9 @@ fn only_owner_can_choose_buyer() {
         assert_ok!(Template::list_nft_for_auction(
             RuntimeOrigin::signed(owner),
             collection_id,
-            item_id
+            item_id,
+            AuctionSettings::default(),
+            SettlementAsset::Native
         ));
 
         assert_ok!(Template::place_bid(
@@ -613,7 +652,9 @@ fn auto_resolve_auction_after_timeout() {
         assert_ok!(Template::list_nft_for_auction(
             RuntimeOrigin::signed(owner),
             collection_id,
-            item_id
+            item_id,
+            AuctionSettings::default(),
+            SettlementAsset::Native
         ));
 
         assert_ok!(Template::place_bid(
@@ -634,11 +675,12 @@ fn auto_resolve_auction_after_timeout() {
         System::set_block_number(101); // Original block (1) + timeout (100)
         Template::on_initialize(101);
 
-        // Check Template is marked as ended
-        let auction = Template::auctions((collection_id, item_id)).unwrap();
-        assert_eq!(auction.ended, true);
+        // Check the sold auction was archived
+        assert!(Template::auctions((collection_id, item_id)).is_none());
 
         // Check funds were transferred
+        assert_eq!(Balances::reserved_balance(2), 50); // Outbid funds wait to be claimed
+        assert_ok!(Template::claim_refund(RuntimeOrigin::signed(2), collection_id, item_id));
         assert_eq!(Balances::reserved_balance(2), 0); // Bidder's funds released
         assert_eq!(Balances::reserved_balance(3), 0); // Winner's funds transferred
 
@@ -680,7 +722,9 @@ fn cant_bid_on_ended_auction() {
         assert_ok!(Template::list_nft_for_auction(
             RuntimeOrigin::signed(owner),
             collection_id,
-            item_id
+            item_id,
+            AuctionSettings::default(),
+            SettlementAsset::Native
         ));
 
         assert_ok!(Template::place_bid(
@@ -700,7 +744,7 @@ fn cant_bid_on_ended_auction() {
         // Act & Assert: Try to bid on ended Template
         assert_noop!(
             Template::place_bid(RuntimeOrigin::signed(3), collection_id, item_id, 60),
-            Error::<Test>::AuctionEnded
+            Error::<Test>::AuctionNotFound
         );
     });
 }
@@ -738,7 +782,9 @@ fn cant_choose_buyer_for_ended_auction() {
         assert_ok!(Template::list_nft_for_auction(
             RuntimeOrigin::signed(owner),
             collection_id,
-            item_id
+            item_id,
+            AuctionSettings::default(),
+            SettlementAsset::Native
         ));
 
         assert_ok!(Template::place_bid(
@@ -762,7 +808,7 @@ fn cant_choose_buyer_for_ended_auction() {
         // Act & Assert: Try to choose another buyer for ended Template
         assert_noop!(
             Template::resolve_auction(RuntimeOrigin::signed(1), collection_id, item_id),
-            Error::<Test>::AuctionEnded
+            Error::<Test>::AuctionNotFound
         );
     });
 }
@@ -800,7 +846,9 @@ fn auction_with_no_bids_fails_on_timeout() {
         assert_ok!(Template::list_nft_for_auction(
             RuntimeOrigin::signed(owner),
             collection_id,
-            item_id
+            item_id,
+            AuctionSettings::default(),
+            SettlementAsset::Native
         ));
 
         // Act: Advance blocks to trigger timeout
@@ -816,25 +864,2761 @@ fn auction_with_no_bids_fails_on_timeout() {
 }
 
 #[test]
-fn set_fee_percentage_works() {
+fn set_market_fee_works() {
 	new_test_ext().execute_with(|| {
-		assert_ok!(Template::set_fee_percentage(Origin::<Test>::Root.into(), 5));
-		assert_eq!(Template::fee_percentage(), 5);
+		assert_ok!(Template::set_market_fee(Origin::<Test>::Root.into(), Permill::from_percent(5)));
+		assert_eq!(Template::market_fee(), Permill::from_percent(5));
 	});
 }
 
 #[test]
 fn withdraw_fees_works() {
 	new_test_ext().execute_with(|| {
-		let receiver = 3;
+		let caller = 3;
 		let fee_amount = 50;
 
 		// Set some fees manually
-		AccumulatedFees::<Test>::put(fee_amount);
+		AccumulatedFees::<Test>::insert(SettlementAsset::Native, fee_amount);
 		let pallet_account = Template::account_id();
 		Balances::make_free_balance_be(&pallet_account, fee_amount);
 
-		assert_ok!(Template::withdraw_fees(Origin::<Test>::Root.into(), receiver));
-		assert_eq!(Template::accumulated_fees(), 0);
+		assert_noop!(
+			Template::withdraw_fees(RuntimeOrigin::signed(caller), SettlementAsset::Native),
+			sp_runtime::DispatchError::BadOrigin
+		);
+		assert_ok!(Template::withdraw_fees(Origin::<Test>::Root.into(), SettlementAsset::Native));
+		assert_eq!(Template::accumulated_fees(SettlementAsset::Native), 0);
+		// Withdrawn fees go to the treasury, never to the caller
+		assert_eq!(Balances::free_balance(TreasuryAccount::get()), fee_amount);
 	});
-}
\ No newline at end of file
+}
+
+#[test]
+fn fees_are_distributed_by_policy() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let policy = FeeDistribution {
+            treasury: Permill::from_percent(50),
+            burn: Permill::from_percent(20),
+            rewards: Permill::from_percent(30),
+            period: 10,
+        };
+        assert_noop!(
+            Template::set_fee_distribution(
+                RuntimeOrigin::root(),
+                Some(FeeDistribution { rewards: Permill::from_percent(20), ..policy.clone() })
+            ),
+            Error::<Test>::InvalidFeeDistribution
+        );
+        assert_noop!(
+            Template::set_fee_distribution(RuntimeOrigin::signed(1), Some(policy.clone())),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_ok!(Template::set_fee_distribution(RuntimeOrigin::root(), Some(policy.clone())));
+        System::assert_last_event(Event::FeeDistributionSet(Some(policy)).into());
+
+        // Transaction fees paid into the pallet account add to the marketplace fees
+        AccumulatedFees::<Test>::insert(SettlementAsset::Native, 900);
+        Balances::make_free_balance_be(&Template::account_id(), 1001);
+        Template::note_fees(SettlementAsset::Native, 101);
+        let issuance = Balances::total_issuance();
+
+        // Nothing is distributed between periods
+        Template::on_initialize(9);
+        assert_eq!(Template::accumulated_fees(SettlementAsset::Native), 1001);
+
+        Template::on_initialize(10);
+        System::assert_last_event(
+            Event::FeesDistributed(SettlementAsset::Native, 500, 200, 301).into(),
+        );
+        assert_eq!(Template::accumulated_fees(SettlementAsset::Native), 0);
+        assert_eq!(Balances::free_balance(TreasuryAccount::get()), 500);
+        assert_eq!(Balances::free_balance(RewardsAccount::get()), 301);
+        assert_eq!(Balances::total_issuance(), issuance - 200);
+        assert_ok!(Template::do_try_state());
+
+        // Without a policy fees accumulate again
+        assert_ok!(Template::set_fee_distribution(RuntimeOrigin::root(), None));
+        Template::note_fees(SettlementAsset::Native, 50);
+        Template::on_initialize(20);
+        assert_eq!(Template::accumulated_fees(SettlementAsset::Native), 50);
+    });
+}
+
+#[test]
+fn fee_distribution_carries_assets_over_the_cap_into_the_next_block() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        let policy = FeeDistribution {
+            treasury: Permill::one(),
+            burn: Permill::zero(),
+            rewards: Permill::zero(),
+            period: 10,
+        };
+        assert_ok!(Template::set_fee_distribution(RuntimeOrigin::root(), Some(policy)));
+
+        // Fees in three settlement assets, two are distributed per block
+        Balances::make_free_balance_be(&Template::account_id(), 101);
+        create_asset(7, &[Template::account_id()]);
+        create_asset(8, &[Template::account_id()]);
+        let assets =
+            [SettlementAsset::Native, SettlementAsset::Asset(7), SettlementAsset::Asset(8)];
+        for asset in assets {
+            Template::note_fees(asset, 100);
+        }
+        let distributed = || {
+            System::events()
+                .into_iter()
+                .filter(|record| {
+                    matches!(record.event, RuntimeEvent::Template(Event::FeesDistributed(..)))
+                })
+                .count()
+        };
+
+        Template::on_initialize(10);
+        assert_eq!(distributed(), 2);
+        assert!(Template::fee_distribution_backlog().is_some());
+
+        Template::on_initialize(11);
+        assert_eq!(distributed(), 3);
+        assert!(Template::fee_distribution_backlog().is_none());
+        for asset in assets {
+            assert_eq!(Template::accumulated_fees(asset), 0);
+        }
+
+        // Nothing is left to carry on with
+        Template::on_initialize(12);
+        assert_eq!(distributed(), 3);
+    });
+}
+
+#[test]
+fn fee_tiers_and_collection_fees_set_the_fee_rate() {
+    new_test_ext().execute_with(|| {
+        let (collection_id, owner) = (1, 1);
+        create_and_mint_nft(collection_id, 1, owner);
+        assert_ok!(Template::set_market_fee(RuntimeOrigin::root(), Permill::from_percent(5)));
+
+        assert_noop!(
+            Template::set_fee_tiers(
+                RuntimeOrigin::root(),
+                SettlementAsset::Native,
+                vec![(1000, Permill::from_percent(2)), (1000, Permill::from_percent(1))]
+                    .try_into()
+                    .unwrap()
+            ),
+            Error::<Test>::InvalidFee
+        );
+        assert_ok!(Template::set_fee_tiers(
+            RuntimeOrigin::root(),
+            SettlementAsset::Native,
+            vec![(1000, Permill::from_parts(25_000)), (5000, Permill::from_percent(1))]
+                .try_into()
+                .unwrap()
+        ));
+        System::assert_last_event(Event::FeeTiersSet(SettlementAsset::Native, 2).into());
+
+        // Below the first tier the seller pays the market fee
+        assert_eq!(
+            Template::quote_fees(&collection_id, &1, 1000, &owner),
+            FeeQuote { fee_rate: Permill::from_percent(5), royalty: 0, fee: 50, payout: 950 }
+        );
+        assert_ok!(Template::list_nft_for_auction(
+            RuntimeOrigin::signed(owner),
+            collection_id,
+            1,
+            AuctionSettings { buy_now_price: Some(1000), ..Default::default() },
+            SettlementAsset::Native
+        ));
+        assert_ok!(Template::buy_now(RuntimeOrigin::signed(3), collection_id, 1));
+        assert_eq!(Template::accumulated_fees(SettlementAsset::Native), 50);
+
+        // Having sold 1000, the seller reached the 2.5% tier
+        assert_eq!(
+            Template::quote_fees(&collection_id, &2, 1000, &owner),
+            FeeQuote { fee_rate: Permill::from_parts(25_000), royalty: 0, fee: 25, payout: 975 }
+        );
+
+        // A partner collection overrides the tiers
+        assert_ok!(Template::set_collection_fee(
+            RuntimeOrigin::root(),
+            collection_id,
+            Some(Permill::zero())
+        ));
+        System::assert_last_event(
+            Event::CollectionFeeSet(collection_id, Some(Permill::zero())).into(),
+        );
+        assert_eq!(
+            Template::fee_rate(&collection_id, &owner, &SettlementAsset::Native),
+            Permill::zero()
+        );
+
+        assert_ok!(Template::set_collection_fee(RuntimeOrigin::root(), collection_id, None));
+        assert_eq!(
+            Template::fee_rate(&collection_id, &owner, &SettlementAsset::Native),
+            Permill::from_parts(25_000)
+        );
+        assert_noop!(
+            Template::set_collection_fee(RuntimeOrigin::signed(owner), collection_id, None),
+            sp_runtime::DispatchError::BadOrigin
+        );
+    });
+}
+
+fn create_and_mint_nft(collection_id: u32, item_id: u32, owner: u64) {
+    assert_ok!(pallet_uniques::Pallet::<Test>::create_collection(
+        &collection_id,
+        &owner,
+        &owner
+    ));
+    assert_ok!(pallet_uniques::Pallet::<Test>::mint(
+        RuntimeOrigin::signed(owner),
+        collection_id,
+        item_id,
+        owner
+    ));
+}
+
+#[test]
+fn resolve_below_reserve_returns_nft() {
+    new_test_ext().execute_with(|| {
+        let (collection_id, item_id, owner) = (1, 1, 1);
+        create_and_mint_nft(collection_id, item_id, owner);
+
+        assert_ok!(Template::list_nft_for_auction(
+            RuntimeOrigin::signed(owner),
+            collection_id,
+            item_id,
+            AuctionSettings { reserve_price: Some(100), ..Default::default() },
+            SettlementAsset::Native
+        ));
+        assert_ok!(Template::place_bid(RuntimeOrigin::signed(2), collection_id, item_id, 60));
+
+        assert_ok!(Template::resolve_auction(RuntimeOrigin::signed(owner), collection_id, item_id));
+
+        // The auction is closed without a sale
+        let auction = Template::auctions((collection_id, item_id)).unwrap();
+        assert!(auction.ended);
+        assert!(!Template::is_in_auction((collection_id, item_id)));
+        // The bid is claimed back
+        assert_ok!(Template::claim_refund(RuntimeOrigin::signed(2), collection_id, item_id));
+        assert_eq!(Balances::reserved_balance(2), 0);
+        System::assert_has_event(Event::ReserveNotMet(collection_id, item_id, 60).into());
+
+        // The owner keeps the NFT and it is no longer frozen
+        assert_eq!(pallet_uniques::Pallet::<Test>::owner(collection_id, item_id), Some(owner));
+        assert_ok!(pallet_uniques::Pallet::<Test>::transfer(
+            RuntimeOrigin::signed(owner),
+            collection_id,
+            item_id,
+            3
+        ));
+    });
+}
+
+#[test]
+fn resolve_at_reserve_sells_nft() {
+    new_test_ext().execute_with(|| {
+        let (collection_id, item_id, owner) = (1, 1, 1);
+        create_and_mint_nft(collection_id, item_id, owner);
+
+        assert_ok!(Template::list_nft_for_auction(
+            RuntimeOrigin::signed(owner),
+            collection_id,
+            item_id,
+            AuctionSettings { reserve_price: Some(100), ..Default::default() },
+            SettlementAsset::Native
+        ));
+        assert_ok!(Template::place_bid(RuntimeOrigin::signed(2), collection_id, item_id, 100));
+
+        assert_ok!(Template::resolve_auction(RuntimeOrigin::signed(owner), collection_id, item_id));
+
+        assert_eq!(pallet_uniques::Pallet::<Test>::owner(collection_id, item_id), Some(2));
+        System::assert_last_event(Event::AuctionResolved(collection_id, item_id, 2, 100).into());
+    });
+}
+
+#[test]
+fn auto_resolve_below_reserve_returns_nft() {
+    new_test_ext().execute_with(|| {
+        let (collection_id, item_id, owner) = (1, 1, 1);
+        create_and_mint_nft(collection_id, item_id, owner);
+
+        assert_ok!(Template::list_nft_for_auction(
+            RuntimeOrigin::signed(owner),
+            collection_id,
+            item_id,
+            AuctionSettings { reserve_price: Some(100), ..Default::default() },
+            SettlementAsset::Native
+        ));
+        assert_ok!(Template::place_bid(RuntimeOrigin::signed(2), collection_id, item_id, 50));
+        assert_ok!(Template::place_bid(RuntimeOrigin::signed(3), collection_id, item_id, 60));
+
+        System::set_block_number(101);
+        Template::on_initialize(101);
+
+        assert_eq!(pallet_uniques::Pallet::<Test>::owner(collection_id, item_id), Some(owner));
+        // The bid is claimed back
+        assert_ok!(Template::claim_refund(RuntimeOrigin::signed(3), collection_id, item_id));
+        assert_eq!(Balances::reserved_balance(3), 0);
+        assert!(!Template::is_in_auction((collection_id, item_id)));
+        System::assert_has_event(Event::ReserveNotMet(collection_id, item_id, 60).into());
+    });
+}
+
+#[test]
+fn absolute_min_increment_is_enforced() {
+    new_test_ext().execute_with(|| {
+        let (collection_id, item_id, owner) = (1, 1, 1);
+        create_and_mint_nft(collection_id, item_id, owner);
+
+        assert_ok!(Template::list_nft_for_auction(
+            RuntimeOrigin::signed(owner),
+            collection_id,
+            item_id,
+            AuctionSettings {
+                min_increment: Some(BidIncrement::Absolute(10)),
+                ..Default::default()
+            },
+            SettlementAsset::Native
+        ));
+
+        // The increment does not apply to the opening bid
+        assert_ok!(Template::place_bid(RuntimeOrigin::signed(2), collection_id, item_id, 1));
+        let auction = Template::auctions((collection_id, item_id)).unwrap();
+        assert_eq!(Template::min_next_bid(&auction), 11);
+
+        assert_noop!(
+            Template::place_bid(RuntimeOrigin::signed(3), collection_id, item_id, 10),
+            Error::<Test>::BidTooLow
+        );
+        assert_ok!(Template::place_bid(RuntimeOrigin::signed(3), collection_id, item_id, 11));
+    });
+}
+
+#[test]
+fn percentage_min_increment_is_enforced() {
+    new_test_ext().execute_with(|| {
+        let (collection_id, item_id, owner) = (1, 1, 1);
+        create_and_mint_nft(collection_id, item_id, owner);
+
+        assert_ok!(Template::list_nft_for_auction(
+            RuntimeOrigin::signed(owner),
+            collection_id,
+            item_id,
+            AuctionSettings {
+                min_increment: Some(BidIncrement::Percentage(10)),
+                ..Default::default()
+            },
+            SettlementAsset::Native
+        ));
+        assert_ok!(Template::place_bid(RuntimeOrigin::signed(2), collection_id, item_id, 100));
+
+        assert_noop!(
+            Template::place_bid(RuntimeOrigin::signed(3), collection_id, item_id, 109),
+            Error::<Test>::BidTooLow
+        );
+        assert_ok!(Template::place_bid(RuntimeOrigin::signed(3), collection_id, item_id, 110));
+    });
+}
+
+#[test]
+fn cant_list_with_invalid_percentage_increment() {
+    new_test_ext().execute_with(|| {
+        let (collection_id, item_id, owner) = (1, 1, 1);
+        create_and_mint_nft(collection_id, item_id, owner);
+
+        assert_noop!(
+            Template::list_nft_for_auction(
+                RuntimeOrigin::signed(owner),
+                collection_id,
+                item_id,
+                AuctionSettings {
+                    min_increment: Some(BidIncrement::Percentage(101)),
+                    ..Default::default()
+                },
+                SettlementAsset::Native
+            ),
+            Error::<Test>::InvalidBidIncrement
+        );
+    });
+}
+
+#[test]
+fn list_with_custom_duration_sets_end_block() {
+    new_test_ext().execute_with(|| {
+        let (collection_id, item_id, owner) = (1, 1, 1);
+        create_and_mint_nft(collection_id, item_id, owner);
+
+        assert_ok!(Template::list_nft_for_auction(
+            RuntimeOrigin::signed(owner),
+            collection_id,
+            item_id,
+            AuctionSettings { duration: Some(20), ..Default::default() },
+            SettlementAsset::Native
+        ));
+
+        let auction = Template::auctions((collection_id, item_id)).unwrap();
+        assert_eq!(auction.start_block, 1);
+        assert_eq!(auction.end_block, 21);
+
+        System::set_block_number(16);
+        assert_eq!(Template::remaining_blocks(&auction), 5);
+
+        // Nothing happens before the end block
+        System::set_block_number(20);
+        Template::on_initialize(20);
+        assert!(!Template::auctions((collection_id, item_id)).unwrap().ended);
+
+        System::set_block_number(21);
+        Template::on_initialize(21);
+        assert!(Template::auctions((collection_id, item_id)).unwrap().ended);
+        System::assert_has_event(Event::AuctionFailed(collection_id, item_id).into());
+    });
+}
+
+#[test]
+fn cant_list_with_duration_out_of_bounds() {
+    new_test_ext().execute_with(|| {
+        let (collection_id, item_id, owner) = (1, 1, 1);
+        create_and_mint_nft(collection_id, item_id, owner);
+
+        assert_noop!(
+            Template::list_nft_for_auction(
+                RuntimeOrigin::signed(owner),
+                collection_id,
+                item_id,
+                AuctionSettings { duration: Some(9), ..Default::default() },
+                SettlementAsset::Native
+            ),
+            Error::<Test>::InvalidAuctionDuration
+        );
+        assert_noop!(
+            Template::list_nft_for_auction(
+                RuntimeOrigin::signed(owner),
+                collection_id,
+                item_id,
+                AuctionSettings { duration: Some(1001), ..Default::default() },
+                SettlementAsset::Native
+            ),
+            Error::<Test>::InvalidAuctionDuration
+        );
+    });
+}
+
+#[test]
+fn batch_listing_honors_custom_timeout() {
+    new_test_ext().execute_with(|| {
+        let (collection_id, owner) = (1, 1);
+        create_and_mint_nft(collection_id, 1, owner);
+        assert_ok!(pallet_uniques::Pallet::<Test>::mint(
+            RuntimeOrigin::signed(owner),
+            collection_id,
+            2,
+            owner
+        ));
+
+        assert_ok!(Template::batch_list_nfts_for_auction(
+            RuntimeOrigin::signed(owner),
+            BatchListingInfo {
+                nfts: vec![(collection_id, 1), (collection_id, 2)].try_into().unwrap(),
+                min_bid: None,
+                custom_timeout: Some(50),
+                asset: SettlementAsset::Native,
+            }
+        ));
+
+        assert_eq!(Template::auctions((collection_id, 1)).unwrap().end_block, 51);
+        assert_eq!(Template::auctions((collection_id, 2)).unwrap().end_block, 51);
+        System::assert_last_event(Event::BatchNftsListed(owner, 2).into());
+    });
+}
+
+#[test]
+fn batch_listing_rejects_invalid_custom_timeout() {
+    new_test_ext().execute_with(|| {
+        let (collection_id, owner) = (1, 1);
+        create_and_mint_nft(collection_id, 1, owner);
+
+        assert_noop!(
+            Template::batch_list_nfts_for_auction(
+                RuntimeOrigin::signed(owner),
+                BatchListingInfo {
+                    nfts: vec![(collection_id, 1)].try_into().unwrap(),
+                    min_bid: None,
+                    custom_timeout: Some(5_000),
+                    asset: SettlementAsset::Native,
+                }
+            ),
+            Error::<Test>::InvalidAuctionDuration
+        );
+    });
+}
+
+#[test]
+fn listing_schedules_auction_expiry() {
+    new_test_ext().execute_with(|| {
+        let (collection_id, item_id, owner) = (1, 1, 1);
+        create_and_mint_nft(collection_id, item_id, owner);
+
+        assert_ok!(Template::list_nft_for_auction(
+            RuntimeOrigin::signed(owner),
+            collection_id,
+            item_id,
+            AuctionSettings { duration: Some(20), ..Default::default() },
+            SettlementAsset::Native
+        ));
+
+        assert_eq!(Template::auction_expiries(21).into_inner(), vec![(collection_id, item_id)]);
+
+        Template::on_initialize(21);
+
+        assert!(Template::auctions((collection_id, item_id)).unwrap().ended);
+        assert!(Template::auction_expiries(21).is_empty());
+        assert_eq!(Template::expiry_backlog(), None);
+    });
+}
+
+#[test]
+fn expiries_over_the_block_cap_carry_over() {
+    new_test_ext().execute_with(|| {
+        let (collection_id, owner) = (1, 1);
+        create_and_mint_nft(collection_id, 1, owner);
+        for item_id in 2..=3 {
+            assert_ok!(pallet_uniques::Pallet::<Test>::mint(
+                RuntimeOrigin::signed(owner),
+                collection_id,
+                item_id,
+                owner
+            ));
+        }
+
+        assert_ok!(Template::batch_list_nfts_for_auction(
+            RuntimeOrigin::signed(owner),
+            BatchListingInfo {
+                nfts: vec![(collection_id, 1), (collection_id, 2), (collection_id, 3)]
+                    .try_into()
+                    .unwrap(),
+                min_bid: None,
+                custom_timeout: Some(50),
+                asset: SettlementAsset::Native,
+            }
+        ));
+
+        // One schedule read plus two resolutions exhaust the budget of 3
+        Template::on_initialize(51);
+
+        assert!(Template::auctions((collection_id, 1)).unwrap().ended);
+        assert!(Template::auctions((collection_id, 2)).unwrap().ended);
+        assert!(!Template::auctions((collection_id, 3)).unwrap().ended);
+        assert_eq!(Template::auction_expiries(51).into_inner(), vec![(collection_id, 3)]);
+        assert_eq!(Template::expiry_backlog(), Some(51));
+
+        // The leftover auction is resolved first in the next block
+        Template::on_initialize(52);
+
+        assert!(Template::auctions((collection_id, 3)).unwrap().ended);
+        assert!(Template::auction_expiries(51).is_empty());
+        assert_eq!(Template::expiry_backlog(), None);
+    });
+}
+
+#[test]
+fn stale_expiry_entries_are_skipped() {
+    new_test_ext().execute_with(|| {
+        let (collection_id, item_id, owner, bidder) = (1, 1, 1, 2);
+        create_and_mint_nft(collection_id, item_id, owner);
+
+        assert_ok!(Template::list_nft_for_auction(
+            RuntimeOrigin::signed(owner),
+            collection_id,
+            item_id,
+            AuctionSettings::default(),
+            SettlementAsset::Native
+        ));
+        assert_ok!(Template::place_bid(
+            RuntimeOrigin::signed(bidder),
+            collection_id,
+            item_id,
+            100
+        ));
+        assert_ok!(Template::resolve_auction(
+            RuntimeOrigin::signed(owner),
+            collection_id,
+            item_id
+        ));
+
+        System::reset_events();
+        Template::on_initialize(101);
+
+        assert!(System::events().is_empty());
+        assert!(Template::auction_expiries(101).is_empty());
+    });
+}
+
+#[test]
+fn cant_list_when_end_block_is_full() {
+    new_test_ext().execute_with(|| {
+        let (collection_id, owner) = (1, 1);
+        create_and_mint_nft(collection_id, 1, owner);
+        for item_id in 2..=11 {
+            assert_ok!(pallet_uniques::Pallet::<Test>::mint(
+                RuntimeOrigin::signed(owner),
+                collection_id,
+                item_id,
+                owner
+            ));
+        }
+
+        assert_ok!(Template::batch_list_nfts_for_auction(
+            RuntimeOrigin::signed(owner),
+            BatchListingInfo {
+                nfts: (1..=10)
+                    .map(|item_id| (collection_id, item_id))
+                    .collect::<Vec<_>>()
+                    .try_into()
+                    .unwrap(),
+                min_bid: None,
+                custom_timeout: Some(50),
+                asset: SettlementAsset::Native,
+            }
+        ));
+
+        assert_noop!(
+            Template::list_nft_for_auction(
+                RuntimeOrigin::signed(owner),
+                collection_id,
+                11,
+                AuctionSettings { duration: Some(50), ..Default::default() },
+                SettlementAsset::Native
+            ),
+            Error::<Test>::TooManyAuctionsExpiring
+        );
+    });
+}
+
+#[test]
+fn late_bid_extends_auction() {
+    new_test_ext().execute_with(|| {
+        let (collection_id, item_id, owner, bidder) = (1, 1, 1, 2);
+        create_and_mint_nft(collection_id, item_id, owner);
+
+        assert_ok!(Template::list_nft_for_auction(
+            RuntimeOrigin::signed(owner),
+            collection_id,
+            item_id,
+            AuctionSettings { duration: Some(20), ..Default::default() },
+            SettlementAsset::Native
+        ));
+
+        // Outside the soft close window the end stays put
+        System::set_block_number(15);
+        assert_ok!(Template::place_bid(
+            RuntimeOrigin::signed(bidder),
+            collection_id,
+            item_id,
+            100
+        ));
+        assert_eq!(Template::auctions((collection_id, item_id)).unwrap().end_block, 21);
+
+        System::set_block_number(16);
+        assert_ok!(Template::place_bid(
+            RuntimeOrigin::signed(3),
+            collection_id,
+            item_id,
+            200
+        ));
+        System::assert_last_event(Event::AuctionExtended(collection_id, item_id, 31).into());
+
+        let auction_info = Template::auctions((collection_id, item_id)).unwrap();
+        assert_eq!(auction_info.end_block, 31);
+        assert_eq!(auction_info.extended_by, 10);
+        assert!(Template::auction_expiries(21).is_empty());
+        assert_eq!(Template::auction_expiries(31).into_inner(), vec![(collection_id, item_id)]);
+
+        // The auction no longer resolves at its original end
+        Template::on_initialize(21);
+        assert!(!Template::auctions((collection_id, item_id)).unwrap().ended);
+
+        Template::on_initialize(31);
+        assert!(Template::auctions((collection_id, item_id)).is_none());
+    });
+}
+
+#[test]
+fn soft_close_extension_is_capped() {
+    new_test_ext().execute_with(|| {
+        let (collection_id, item_id, owner) = (1, 1, 1);
+        create_and_mint_nft(collection_id, item_id, owner);
+
+        assert_ok!(Template::list_nft_for_auction(
+            RuntimeOrigin::signed(owner),
+            collection_id,
+            item_id,
+            AuctionSettings { duration: Some(20), ..Default::default() },
+            SettlementAsset::Native
+        ));
+
+        System::set_block_number(20);
+        assert_ok!(Template::place_bid(
+            RuntimeOrigin::signed(2),
+            collection_id,
+            item_id,
+            100
+        ));
+        System::set_block_number(30);
+        assert_ok!(Template::place_bid(
+            RuntimeOrigin::signed(3),
+            collection_id,
+            item_id,
+            200
+        ));
+
+        // Only 5 of the 15 extension blocks were left for the second bid
+        let auction_info = Template::auctions((collection_id, item_id)).unwrap();
+        assert_eq!(auction_info.end_block, 36);
+        assert_eq!(auction_info.extended_by, 15);
+
+        System::set_block_number(35);
+        assert_ok!(Template::place_bid(
+            RuntimeOrigin::signed(2),
+            collection_id,
+            item_id,
+            300
+        ));
+        System::assert_last_event(Event::BidPlaced(collection_id, item_id, 2, 300).into());
+        assert_eq!(Template::auctions((collection_id, item_id)).unwrap().end_block, 36);
+    });
+}
+
+#[test]
+fn buy_now_settles_and_refunds_bidders() {
+    new_test_ext().execute_with(|| {
+        let (collection_id, item_id, owner) = (1, 1, 1);
+        create_and_mint_nft(collection_id, item_id, owner);
+        assert_ok!(Template::set_market_fee(RuntimeOrigin::root(), Permill::from_percent(5)));
+
+        assert_ok!(Template::list_nft_for_auction(
+            RuntimeOrigin::signed(owner),
+            collection_id,
+            item_id,
+            AuctionSettings { buy_now_price: Some(1000), ..Default::default() },
+            SettlementAsset::Native
+        ));
+        assert_ok!(Template::place_bid(
+            RuntimeOrigin::signed(2),
+            collection_id,
+            item_id,
+            100
+        ));
+
+        let owner_balance = Balances::free_balance(owner);
+        let buyer_balance = Balances::free_balance(3);
+
+        assert_ok!(Template::buy_now(RuntimeOrigin::signed(3), collection_id, item_id));
+        System::assert_last_event(Event::AuctionResolved(collection_id, item_id, 3, 1000).into());
+
+        assert_eq!(pallet_uniques::Pallet::<Test>::owner(collection_id, item_id), Some(3));
+        assert!(Template::auctions((collection_id, item_id)).is_none());
+        assert!(!Template::is_in_auction((collection_id, item_id)));
+        // The outbid bid is claimed back
+        assert_ok!(Template::claim_refund(RuntimeOrigin::signed(2), collection_id, item_id));
+        assert_eq!(Balances::reserved_balance(2), 0);
+        assert_eq!(Balances::free_balance(3), buyer_balance - 1000);
+        // No royalty is registered for the collection, so the owner gets 1000 - 50 fee
+        assert_eq!(Balances::free_balance(owner), owner_balance + 950);
+        assert_eq!(Template::accumulated_fees(SettlementAsset::Native), 50);
+    });
+}
+
+#[test]
+fn high_bid_withdraws_buy_now() {
+    new_test_ext().execute_with(|| {
+        let (collection_id, item_id, owner) = (1, 1, 1);
+        create_and_mint_nft(collection_id, item_id, owner);
+
+        assert_ok!(Template::list_nft_for_auction(
+            RuntimeOrigin::signed(owner),
+            collection_id,
+            item_id,
+            AuctionSettings { buy_now_price: Some(1000), ..Default::default() },
+            SettlementAsset::Native
+        ));
+
+        // A bid at exactly 80% of the buy-now price keeps the option
+        assert_ok!(Template::place_bid(
+            RuntimeOrigin::signed(2),
+            collection_id,
+            item_id,
+            800
+        ));
+        assert_eq!(Template::auctions((collection_id, item_id)).unwrap().buy_now_price, Some(1000));
+
+        assert_ok!(Template::place_bid(
+            RuntimeOrigin::signed(3),
+            collection_id,
+            item_id,
+            801
+        ));
+        assert_eq!(Template::auctions((collection_id, item_id)).unwrap().buy_now_price, None);
+
+        assert_noop!(
+            Template::buy_now(RuntimeOrigin::signed(2), collection_id, item_id),
+            Error::<Test>::BuyNowUnavailable
+        );
+    });
+}
+
+#[test]
+fn cant_list_with_buy_now_below_reserve() {
+    new_test_ext().execute_with(|| {
+        let (collection_id, item_id, owner) = (1, 1, 1);
+        create_and_mint_nft(collection_id, item_id, owner);
+
+        assert_noop!(
+            Template::list_nft_for_auction(
+                RuntimeOrigin::signed(owner),
+                collection_id,
+                item_id,
+                AuctionSettings {
+                    reserve_price: Some(500),
+                    buy_now_price: Some(400),
+                    ..Default::default()
+                },
+                SettlementAsset::Native
+            ),
+            Error::<Test>::InvalidBuyNowPrice
+        );
+        assert_noop!(
+            Template::buy_now(RuntimeOrigin::signed(2), collection_id, item_id),
+            Error::<Test>::AuctionNotFound
+        );
+    });
+}
+
+#[test]
+fn fixed_price_listing_can_be_repriced_and_bought() {
+    new_test_ext().execute_with(|| {
+        let (collection_id, item_id, owner, buyer) = (1, 1, 1, 2);
+        create_and_mint_nft(collection_id, item_id, owner);
+        assert_ok!(Template::set_market_fee(RuntimeOrigin::root(), Permill::from_percent(5)));
+
+        assert_ok!(Template::list_nft_for_sale(
+            RuntimeOrigin::signed(owner),
+            collection_id,
+            item_id,
+            500,
+            SettlementAsset::Native
+        ));
+        System::assert_last_event(Event::NftListedForSale(collection_id, item_id, owner, 500).into());
+
+        // Listed NFTs are frozen
+        assert!(pallet_uniques::Pallet::<Test>::transfer(
+            RuntimeOrigin::signed(owner),
+            collection_id,
+            item_id,
+            3
+        )
+        .is_err());
+
+        assert_noop!(
+            Template::update_listing_price(RuntimeOrigin::signed(buyer), collection_id, item_id, 1),
+            Error::<Test>::NotNftOwner
+        );
+        assert_ok!(Template::update_listing_price(
+            RuntimeOrigin::signed(owner),
+            collection_id,
+            item_id,
+            1000
+        ));
+        System::assert_last_event(Event::ListingPriceUpdated(collection_id, item_id, 1000).into());
+
+        let buyer_balance = Balances::free_balance(buyer);
+        assert_noop!(
+            Template::buy_nft(RuntimeOrigin::signed(owner), collection_id, item_id),
+            Error::<Test>::CannotBuyOwnNft
+        );
+        assert_ok!(Template::buy_nft(RuntimeOrigin::signed(buyer), collection_id, item_id));
+        System::assert_last_event(Event::NftSold(collection_id, item_id, buyer, 1000).into());
+
+        assert_eq!(pallet_uniques::Pallet::<Test>::owner(collection_id, item_id), Some(buyer));
+        assert_eq!(Template::listings((collection_id, item_id)), None);
+        assert_eq!(Balances::free_balance(buyer), buyer_balance - 1000);
+        assert_eq!(Template::accumulated_fees(SettlementAsset::Native), 50);
+    });
+}
+
+#[test]
+fn delisting_unfreezes_nft() {
+    new_test_ext().execute_with(|| {
+        let (collection_id, item_id, owner) = (1, 1, 1);
+        create_and_mint_nft(collection_id, item_id, owner);
+
+        assert_ok!(Template::list_nft_for_sale(
+            RuntimeOrigin::signed(owner),
+            collection_id,
+            item_id,
+            500,
+            SettlementAsset::Native
+        ));
+
+        // A listed NFT cannot be auctioned at the same time
+        assert_noop!(
+            Template::list_nft_for_auction(
+                RuntimeOrigin::signed(owner),
+                collection_id,
+                item_id,
+                AuctionSettings::default(),
+                SettlementAsset::Native
+            ),
+            Error::<Test>::NftAlreadyListed
+        );
+
+        assert_noop!(
+            Template::delist_nft(RuntimeOrigin::signed(2), collection_id, item_id),
+            Error::<Test>::NotNftOwner
+        );
+        assert_ok!(Template::delist_nft(RuntimeOrigin::signed(owner), collection_id, item_id));
+        System::assert_last_event(Event::NftDelisted(collection_id, item_id).into());
+
+        assert_eq!(Template::listings((collection_id, item_id)), None);
+        assert_ok!(pallet_uniques::Pallet::<Test>::transfer(
+            RuntimeOrigin::signed(owner),
+            collection_id,
+            item_id,
+            3
+        ));
+    });
+}
+
+#[test]
+fn cant_list_auctioned_nft_for_sale() {
+    new_test_ext().execute_with(|| {
+        let (collection_id, item_id, owner) = (1, 1, 1);
+        create_and_mint_nft(collection_id, item_id, owner);
+
+        assert_ok!(Template::list_nft_for_auction(
+            RuntimeOrigin::signed(owner),
+            collection_id,
+            item_id,
+            AuctionSettings::default(),
+            SettlementAsset::Native
+        ));
+
+        assert_noop!(
+            Template::list_nft_for_sale(RuntimeOrigin::signed(owner), collection_id, item_id, 500, SettlementAsset::Native),
+            Error::<Test>::NftAlreadyInAuction
+        );
+        assert_noop!(
+            Template::list_nft_for_sale(RuntimeOrigin::signed(2), collection_id, 2, 500, SettlementAsset::Native),
+            Error::<Test>::NftNotFound
+        );
+    });
+}
+
+#[test]
+fn owner_can_accept_offer_on_unlisted_nft() {
+    new_test_ext().execute_with(|| {
+        let (collection_id, item_id, owner, buyer) = (1, 1, 1, 2);
+        create_and_mint_nft(collection_id, item_id, owner);
+
+        assert_ok!(Template::make_offer(
+            RuntimeOrigin::signed(buyer),
+            collection_id,
+            item_id,
+            300,
+            50
+        ));
+        System::assert_last_event(Event::OfferMade(collection_id, item_id, buyer, 300, 50).into());
+        assert_eq!(Balances::reserved_balance(buyer), 300);
+
+        // A new offer replaces the previous one
+        assert_ok!(Template::make_offer(
+            RuntimeOrigin::signed(buyer),
+            collection_id,
+            item_id,
+            400,
+            50
+        ));
+        assert_eq!(Balances::reserved_balance(buyer), 400);
+
+        let buyer_balance = Balances::free_balance(buyer);
+        assert_noop!(
+            Template::accept_offer(RuntimeOrigin::signed(3), collection_id, item_id, buyer),
+            Error::<Test>::NotNftOwner
+        );
+        assert_ok!(Template::accept_offer(
+            RuntimeOrigin::signed(owner),
+            collection_id,
+            item_id,
+            buyer
+        ));
+        System::assert_last_event(Event::OfferAccepted(collection_id, item_id, buyer, 400).into());
+
+        assert_eq!(pallet_uniques::Pallet::<Test>::owner(collection_id, item_id), Some(buyer));
+        assert_eq!(Balances::reserved_balance(buyer), 0);
+        assert_eq!(Balances::free_balance(buyer), buyer_balance);
+        assert_eq!(Template::offers((collection_id, item_id), buyer), None);
+    });
+}
+
+#[test]
+fn accepting_offer_closes_fixed_price_listing() {
+    new_test_ext().execute_with(|| {
+        let (collection_id, item_id, owner, buyer) = (1, 1, 1, 2);
+        create_and_mint_nft(collection_id, item_id, owner);
+
+        assert_ok!(Template::list_nft_for_sale(
+            RuntimeOrigin::signed(owner),
+            collection_id,
+            item_id,
+            1000,
+            SettlementAsset::Native
+        ));
+        assert_ok!(Template::make_offer(
+            RuntimeOrigin::signed(buyer),
+            collection_id,
+            item_id,
+            800,
+            50
+        ));
+        assert_ok!(Template::accept_offer(
+            RuntimeOrigin::signed(owner),
+            collection_id,
+            item_id,
+            buyer
+        ));
+
+        assert_eq!(pallet_uniques::Pallet::<Test>::owner(collection_id, item_id), Some(buyer));
+        assert_eq!(Template::listings((collection_id, item_id)), None);
+    });
+}
+
+#[test]
+fn expired_offer_can_only_be_cancelled() {
+    new_test_ext().execute_with(|| {
+        let (collection_id, item_id, owner, buyer) = (1, 1, 1, 2);
+        create_and_mint_nft(collection_id, item_id, owner);
+
+        assert_noop!(
+            Template::make_offer(RuntimeOrigin::signed(buyer), collection_id, item_id, 300, 1),
+            Error::<Test>::InvalidOfferExpiry
+        );
+        assert_noop!(
+            Template::make_offer(RuntimeOrigin::signed(owner), collection_id, item_id, 300, 50),
+            Error::<Test>::CannotBuyOwnNft
+        );
+        assert_ok!(Template::make_offer(
+            RuntimeOrigin::signed(buyer),
+            collection_id,
+            item_id,
+            300,
+            50
+        ));
+
+        System::set_block_number(50);
+        assert_noop!(
+            Template::accept_offer(RuntimeOrigin::signed(owner), collection_id, item_id, buyer),
+            Error::<Test>::OfferExpired
+        );
+
+        assert_ok!(Template::cancel_offer(RuntimeOrigin::signed(buyer), collection_id, item_id));
+        System::assert_last_event(Event::OfferCancelled(collection_id, item_id, buyer).into());
+        assert_eq!(Balances::reserved_balance(buyer), 0);
+        assert_noop!(
+            Template::cancel_offer(RuntimeOrigin::signed(buyer), collection_id, item_id),
+            Error::<Test>::OfferNotFound
+        );
+    });
+}
+
+#[test]
+fn dutch_auction_price_decays_to_floor() {
+    new_test_ext().execute_with(|| {
+        let (collection_id, item_id, owner) = (1, 1, 1);
+        create_and_mint_nft(collection_id, item_id, owner);
+
+        assert_ok!(Template::list_nft_for_dutch_auction(
+            RuntimeOrigin::signed(owner),
+            collection_id,
+            item_id,
+            1000,
+            400,
+            100,
+            None,
+            SettlementAsset::Native
+        ));
+
+        let auction = Template::auctions((collection_id, item_id)).unwrap();
+        assert_eq!(
+            auction.kind,
+            AuctionKind::Dutch { start_price: 1000, floor_price: 400, decay_per_block: 100 }
+        );
+        assert_eq!(Template::current_price(&auction), Some(1000));
+
+        System::set_block_number(4);
+        assert_eq!(Template::current_price(&auction), Some(700));
+        assert_eq!(Template::min_next_bid(&auction), 700);
+
+        // The price never drops below the floor
+        System::set_block_number(20);
+        assert_eq!(Template::current_price(&auction), Some(400));
+    });
+}
+
+#[test]
+fn first_bid_at_current_price_wins_dutch_auction() {
+    new_test_ext().execute_with(|| {
+        let (collection_id, item_id, owner, bidder) = (1, 1, 1, 2);
+        create_and_mint_nft(collection_id, item_id, owner);
+
+        assert_ok!(Template::list_nft_for_dutch_auction(
+            RuntimeOrigin::signed(owner),
+            collection_id,
+            item_id,
+            1000,
+            400,
+            100,
+            None,
+            SettlementAsset::Native
+        ));
+
+        System::set_block_number(3);
+        assert_noop!(
+            Template::place_bid(RuntimeOrigin::signed(bidder), collection_id, item_id, 700),
+            Error::<Test>::BidTooLow
+        );
+
+        // Bidding above the current price only pays the current price
+        let bidder_balance = Balances::free_balance(bidder);
+        assert_ok!(Template::place_bid(
+            RuntimeOrigin::signed(bidder),
+            collection_id,
+            item_id,
+            900
+        ));
+        System::assert_last_event(
+            Event::AuctionResolved(collection_id, item_id, bidder, 800).into(),
+        );
+
+        assert_eq!(pallet_uniques::Pallet::<Test>::owner(collection_id, item_id), Some(bidder));
+        assert_eq!(Balances::free_balance(bidder), bidder_balance - 800);
+        assert_eq!(Balances::reserved_balance(bidder), 0);
+        assert!(Template::auctions((collection_id, item_id)).is_none());
+        assert!(!Template::is_in_auction((collection_id, item_id)));
+    });
+}
+
+#[test]
+fn cant_list_dutch_auction_with_rising_price() {
+    new_test_ext().execute_with(|| {
+        let (collection_id, item_id, owner) = (1, 1, 1);
+        create_and_mint_nft(collection_id, item_id, owner);
+
+        assert_noop!(
+            Template::list_nft_for_dutch_auction(
+                RuntimeOrigin::signed(owner),
+                collection_id,
+                item_id,
+                400,
+                1000,
+                100,
+                None,
+                SettlementAsset::Native
+            ),
+            Error::<Test>::InvalidDutchAuction
+        );
+        assert_noop!(
+            Template::list_nft_for_dutch_auction(
+                RuntimeOrigin::signed(owner),
+                collection_id,
+                item_id,
+                1000,
+                400,
+                0,
+                None,
+                SettlementAsset::Native
+            ),
+            Error::<Test>::InvalidDutchAuction
+        );
+    });
+}
+
+fn commitment(amount: u128, salt: [u8; 32]) -> sp_core::H256 {
+    BlakeTwo256::hash_of(&(amount, salt))
+}
+
+fn list_sealed(
+    collection_id: u32,
+    item_id: u32,
+    owner: u64,
+    reserve_price: Option<u128>,
+    pricing: SealedBidPricing,
+) {
+    assert_ok!(Template::list_nft_for_sealed_auction(
+        RuntimeOrigin::signed(owner),
+        collection_id,
+        item_id,
+        reserve_price,
+        None,
+        pricing,
+        SettlementAsset::Native
+    ));
+}
+
+#[test]
+fn sealed_second_price_auction_settles_after_reveal() {
+    new_test_ext().execute_with(|| {
+        let (collection_id, item_id, owner) = (1, 1, 1);
+        create_and_mint_nft(collection_id, item_id, owner);
+        list_sealed(collection_id, item_id, owner, None, SealedBidPricing::SecondPrice);
+
+        // Open bids are not accepted
+        assert_noop!(
+            Template::place_bid(RuntimeOrigin::signed(2), collection_id, item_id, 500),
+            Error::<Test>::SealedBidAuction
+        );
+
+        assert_ok!(Template::commit_bid(
+            RuntimeOrigin::signed(2),
+            collection_id,
+            item_id,
+            commitment(500, [2; 32]),
+            600
+        ));
+        System::assert_last_event(
+            Event::SealedBidCommitted(collection_id, item_id, 2, 600).into(),
+        );
+        assert_ok!(Template::commit_bid(
+            RuntimeOrigin::signed(3),
+            collection_id,
+            item_id,
+            commitment(700, [3; 32]),
+            700
+        ));
+        assert_ok!(Template::commit_bid(
+            RuntimeOrigin::signed(4),
+            collection_id,
+            item_id,
+            commitment(900, [4; 32]),
+            300
+        ));
+        assert_eq!(Balances::reserved_balance(2), 600);
+
+        assert_noop!(
+            Template::reveal_bid(RuntimeOrigin::signed(2), collection_id, item_id, 500, [2; 32]),
+            Error::<Test>::NotInRevealPhase
+        );
+
+        // The reveal phase covers the last 5 blocks before block 101
+        System::set_block_number(96);
+        assert_noop!(
+            Template::commit_bid(
+                RuntimeOrigin::signed(5),
+                collection_id,
+                item_id,
+                commitment(800, [5; 32]),
+                800
+            ),
+            Error::<Test>::NotInCommitPhase
+        );
+        assert_noop!(
+            Template::reveal_bid(RuntimeOrigin::signed(2), collection_id, item_id, 500, [0; 32]),
+            Error::<Test>::InvalidReveal
+        );
+        assert_ok!(Template::reveal_bid(
+            RuntimeOrigin::signed(2),
+            collection_id,
+            item_id,
+            500,
+            [2; 32]
+        ));
+        System::assert_last_event(
+            Event::SealedBidRevealed(collection_id, item_id, 2, 500).into(),
+        );
+        assert_ok!(Template::reveal_bid(
+            RuntimeOrigin::signed(3),
+            collection_id,
+            item_id,
+            700,
+            [3; 32]
+        ));
+        assert_noop!(
+            Template::reveal_bid(RuntimeOrigin::signed(3), collection_id, item_id, 700, [3; 32]),
+            Error::<Test>::AlreadyRevealed
+        );
+
+        let winner_balance = Balances::free_balance(3);
+        let unrevealed_balance = Balances::free_balance(4);
+
+        System::set_block_number(101);
+        Template::on_initialize(101);
+
+        // The winner pays the second highest bid
+        assert_eq!(pallet_uniques::Pallet::<Test>::owner(collection_id, item_id), Some(3));
+        System::assert_has_event(Event::AuctionResolved(collection_id, item_id, 3, 500).into());
+        assert_eq!(Balances::reserved_balance(3), 0);
+        assert_eq!(Balances::free_balance(3), winner_balance + 700 - 500);
+        assert_eq!(Balances::reserved_balance(2), 0);
+
+        // The unrevealed deposit goes to the pallet account
+        System::assert_has_event(Event::SealedBidSlashed(collection_id, item_id, 4, 300).into());
+        assert_eq!(Balances::reserved_balance(4), 0);
+        assert_eq!(Balances::free_balance(4), unrevealed_balance);
+        assert_eq!(Template::accumulated_fees(SettlementAsset::Native), 300);
+        assert!(Template::sealed_bids((collection_id, item_id)).is_empty());
+    });
+}
+
+#[test]
+fn sealed_first_price_winner_pays_own_bid() {
+    new_test_ext().execute_with(|| {
+        let (collection_id, item_id, owner) = (1, 1, 1);
+        create_and_mint_nft(collection_id, item_id, owner);
+        list_sealed(collection_id, item_id, owner, None, SealedBidPricing::FirstPrice);
+
+        for (bidder, amount) in [(2, 500u128), (3, 700)] {
+            assert_ok!(Template::commit_bid(
+                RuntimeOrigin::signed(bidder),
+                collection_id,
+                item_id,
+                commitment(amount, [bidder as u8; 32]),
+                amount
+            ));
+        }
+
+        System::set_block_number(96);
+        for (bidder, amount) in [(2, 500u128), (3, 700)] {
+            assert_ok!(Template::reveal_bid(
+                RuntimeOrigin::signed(bidder),
+                collection_id,
+                item_id,
+                amount,
+                [bidder as u8; 32]
+            ));
+        }
+
+        System::set_block_number(101);
+        Template::on_initialize(101);
+
+        assert_eq!(pallet_uniques::Pallet::<Test>::owner(collection_id, item_id), Some(3));
+        System::assert_has_event(Event::AuctionResolved(collection_id, item_id, 3, 700).into());
+        assert_eq!(Template::sales_history((collection_id, item_id))[0].price, 700);
+    });
+}
+
+#[test]
+fn sealed_auction_below_reserve_returns_nft() {
+    new_test_ext().execute_with(|| {
+        let (collection_id, item_id, owner) = (1, 1, 1);
+        create_and_mint_nft(collection_id, item_id, owner);
+        list_sealed(collection_id, item_id, owner, Some(1000), SealedBidPricing::SecondPrice);
+
+        assert_ok!(Template::commit_bid(
+            RuntimeOrigin::signed(2),
+            collection_id,
+            item_id,
+            commitment(500, [2; 32]),
+            500
+        ));
+        System::set_block_number(96);
+        assert_ok!(Template::reveal_bid(
+            RuntimeOrigin::signed(2),
+            collection_id,
+            item_id,
+            500,
+            [2; 32]
+        ));
+
+        System::set_block_number(101);
+        Template::on_initialize(101);
+
+        assert_eq!(pallet_uniques::Pallet::<Test>::owner(collection_id, item_id), Some(owner));
+        assert_eq!(Balances::reserved_balance(2), 0);
+        assert!(!Template::is_in_auction((collection_id, item_id)));
+        System::assert_has_event(Event::ReserveNotMet(collection_id, item_id, 500).into());
+    });
+}
+
+#[test]
+fn cancel_auction_without_bids_is_free() {
+    new_test_ext().execute_with(|| {
+        let (collection_id, item_id, owner) = (1, 1, 1);
+        create_and_mint_nft(collection_id, item_id, owner);
+
+        assert_ok!(Template::list_nft_for_auction(
+            RuntimeOrigin::signed(owner),
+            collection_id,
+            item_id,
+            AuctionSettings::default(),
+            SettlementAsset::Native
+        ));
+
+        // Only the seller can cancel
+        assert_noop!(
+            Template::cancel_auction(RuntimeOrigin::signed(2), collection_id, item_id),
+            Error::<Test>::NotNftOwner
+        );
+
+        let owner_balance = Balances::free_balance(owner);
+        assert_ok!(Template::cancel_auction(RuntimeOrigin::signed(owner), collection_id, item_id));
+        System::assert_last_event(Event::AuctionCancelled(collection_id, item_id, 0).into());
+        assert_eq!(Balances::free_balance(owner), owner_balance);
+
+        assert!(Template::auctions((collection_id, item_id)).is_none());
+        assert!(!Template::is_in_auction((collection_id, item_id)));
+
+        // The NFT is unfrozen and the expiry is unscheduled
+        assert_ok!(pallet_uniques::Pallet::<Test>::transfer(
+            RuntimeOrigin::signed(owner),
+            collection_id,
+            item_id,
+            3
+        ));
+        assert!(Template::auction_expiries(101).is_empty());
+    });
+}
+
+#[test]
+fn cancel_auction_with_bids_charges_penalty() {
+    new_test_ext().execute_with(|| {
+        let (collection_id, item_id, owner) = (1, 1, 1);
+        create_and_mint_nft(collection_id, item_id, owner);
+        create_and_mint_nft(2, item_id, owner);
+
+        for collection in [collection_id, 2] {
+            assert_ok!(Template::list_nft_for_auction(
+                RuntimeOrigin::signed(owner),
+                collection,
+                item_id,
+                AuctionSettings::default(),
+                SettlementAsset::Native
+            ));
+            assert_ok!(Template::place_bid(RuntimeOrigin::signed(2), collection, item_id, 500));
+        }
+
+        // 10% of the top bid goes to the top bidder
+        let owner_balance = Balances::free_balance(owner);
+        let bidder_balance = Balances::free_balance(2);
+        assert_ok!(Template::cancel_auction(RuntimeOrigin::signed(owner), collection_id, item_id));
+        System::assert_last_event(Event::AuctionCancelled(collection_id, item_id, 50).into());
+        assert_eq!(Balances::free_balance(owner), owner_balance - 50);
+        // The bid is claimed back once the auction is gone
+        assert_ok!(Template::claim_refund(RuntimeOrigin::signed(2), collection_id, item_id));
+        assert_eq!(Balances::free_balance(2), bidder_balance + 550);
+        assert!(Template::ranked_bids((collection_id, item_id)).is_empty());
+
+        // Or to the treasury
+        CancellationPenaltyRecipient::set(crate::PenaltyRecipient::Treasury);
+        assert_ok!(Template::cancel_auction(RuntimeOrigin::signed(owner), 2, item_id));
+        assert_ok!(Template::claim_refund(RuntimeOrigin::signed(2), 2, item_id));
+        assert_eq!(Balances::reserved_balance(2), 0);
+        assert_eq!(Template::accumulated_fees(SettlementAsset::Native), 50);
+    });
+}
+
+#[test]
+fn sealed_auction_with_committed_bids_cannot_be_cancelled() {
+    new_test_ext().execute_with(|| {
+        let (collection_id, item_id, owner) = (1, 1, 1);
+        create_and_mint_nft(collection_id, item_id, owner);
+        create_and_mint_nft(2, item_id, owner);
+        list_sealed(collection_id, item_id, owner, None, SealedBidPricing::FirstPrice);
+        list_sealed(2, item_id, owner, None, SealedBidPricing::FirstPrice);
+
+        assert_ok!(Template::commit_bid(
+            RuntimeOrigin::signed(2),
+            collection_id,
+            item_id,
+            commitment(500, [2; 32]),
+            600
+        ));
+        assert_noop!(
+            Template::cancel_auction(RuntimeOrigin::signed(owner), collection_id, item_id),
+            Error::<Test>::SealedBidsCommitted
+        );
+
+        // Free before any bid is committed
+        let owner_balance = Balances::free_balance(owner);
+        assert_ok!(Template::cancel_auction(RuntimeOrigin::signed(owner), 2, item_id));
+        System::assert_last_event(Event::AuctionCancelled(2, item_id, 0).into());
+        assert_eq!(Balances::free_balance(owner), owner_balance);
+    });
+}
+
+#[test]
+fn only_outbid_bids_can_be_retracted_after_cooldown() {
+    new_test_ext().execute_with(|| {
+        let (collection_id, item_id, owner) = (1, 1, 1);
+        create_and_mint_nft(collection_id, item_id, owner);
+
+        assert_ok!(Template::list_nft_for_auction(
+            RuntimeOrigin::signed(owner),
+            collection_id,
+            item_id,
+            AuctionSettings::default(),
+            SettlementAsset::Native
+        ));
+        assert_ok!(Template::place_bid(RuntimeOrigin::signed(2), collection_id, item_id, 100));
+        assert_ok!(Template::place_bid(RuntimeOrigin::signed(3), collection_id, item_id, 200));
+
+        assert_noop!(
+            Template::retract_bid(RuntimeOrigin::signed(3), collection_id, item_id),
+            Error::<Test>::CannotRetractLeadingBid
+        );
+        assert_noop!(
+            Template::retract_bid(RuntimeOrigin::signed(4), collection_id, item_id),
+            Error::<Test>::BidNotFound
+        );
+        assert_noop!(
+            Template::retract_bid(RuntimeOrigin::signed(2), collection_id, item_id),
+            Error::<Test>::RetractionCooldown
+        );
+
+        System::set_block_number(6);
+        assert_ok!(Template::retract_bid(RuntimeOrigin::signed(2), collection_id, item_id));
+        System::assert_last_event(Event::BidRetracted(collection_id, item_id, 2).into());
+        assert_eq!(
+            Template::ranked_bids((collection_id, item_id)).into_inner(),
+            vec![(3, 200)]
+        );
+        assert!(Template::bid_of(2, (collection_id, item_id)).is_none());
+        assert_eq!(Balances::reserved_balance(2), 0);
+    });
+}
+
+fn create_asset(asset_id: u32, holders: &[u64]) {
+    assert_ok!(Assets::force_create(RuntimeOrigin::root(), asset_id, 1, true, 1));
+    for holder in holders {
+        assert_ok!(Assets::mint(RuntimeOrigin::signed(1), asset_id, *holder, 10_000));
+    }
+}
+
+#[test]
+fn auction_settles_in_chosen_asset() {
+    new_test_ext().execute_with(|| {
+        let (collection_id, item_id, owner) = (1, 1, 1);
+        let asset = SettlementAsset::Asset(7);
+        create_and_mint_nft(collection_id, item_id, owner);
+        create_asset(7, &[2, 3]);
+        assert_ok!(Template::set_market_fee(RuntimeOrigin::root(), Permill::from_percent(5)));
+
+        assert_ok!(Template::list_nft_for_auction(
+            RuntimeOrigin::signed(owner),
+            collection_id,
+            item_id,
+            AuctionSettings::default(),
+            asset
+        ));
+        assert_eq!(Template::auctions((collection_id, item_id)).unwrap().asset, asset);
+
+        // Bids are held in the asset, the native balance is untouched
+        assert_ok!(Template::place_bid(RuntimeOrigin::signed(2), collection_id, item_id, 500));
+        assert_eq!(Assets::balance(7, 2), 9_500);
+        assert_eq!(Balances::reserved_balance(2), 0);
+
+        // Outbid bids stay in escrow until the auction settles
+        assert_ok!(Template::place_bid(RuntimeOrigin::signed(3), collection_id, item_id, 600));
+        assert_eq!(Assets::balance(7, 2), 9_500);
+        assert_eq!(Assets::balance(7, 3), 9_400);
+        assert_eq!(Template::held_assets(7), 1_100);
+        assert_ok!(Template::do_try_state());
+
+        assert_ok!(Template::resolve_auction(RuntimeOrigin::signed(owner), collection_id, item_id));
+        assert_eq!(pallet_uniques::Pallet::<Test>::owner(collection_id, item_id), Some(3));
+        // The losing bid is claimed back
+        assert_ok!(Template::claim_refund(RuntimeOrigin::signed(2), collection_id, item_id));
+        assert_eq!(Assets::balance(7, 2), 10_000);
+
+        // The owner receives the price minus the fee
+        assert_eq!(Assets::balance(7, owner), 600 - 30);
+        assert_eq!(Template::accumulated_fees(asset), 30);
+        assert_eq!(Template::accumulated_fees(SettlementAsset::Native), 0);
+        assert_eq!(Assets::balance(7, Template::account_id()), 30);
+        assert_eq!(Template::held_assets(7), 0);
+        assert_ok!(Template::do_try_state());
+
+        assert_ok!(Template::withdraw_fees(RuntimeOrigin::root(), asset));
+        assert_eq!(Assets::balance(7, TreasuryAccount::get()), 30);
+        System::assert_last_event(Event::FeesWithdrawn(asset, TreasuryAccount::get(), 30).into());
+        assert_ok!(Template::do_try_state());
+    });
+}
+
+#[test]
+fn fixed_price_listing_settles_in_chosen_asset() {
+    new_test_ext().execute_with(|| {
+        let (collection_id, item_id, owner) = (1, 1, 1);
+        create_and_mint_nft(collection_id, item_id, owner);
+        create_asset(7, &[2]);
+
+        assert_noop!(
+            Template::list_nft_for_sale(
+                RuntimeOrigin::signed(owner),
+                collection_id,
+                item_id,
+                100,
+                SettlementAsset::Asset(8)
+            ),
+            Error::<Test>::UnknownAsset
+        );
+        assert_ok!(Template::list_nft_for_sale(
+            RuntimeOrigin::signed(owner),
+            collection_id,
+            item_id,
+            100,
+            SettlementAsset::Asset(7)
+        ));
+
+        let native_balance = Balances::free_balance(2);
+        assert_ok!(Template::buy_nft(RuntimeOrigin::signed(2), collection_id, item_id));
+        assert_eq!(Assets::balance(7, 2), 9_900);
+        assert_eq!(Assets::balance(7, owner), 100);
+        assert_eq!(Balances::free_balance(2), native_balance);
+    });
+}
+
+fn set_royalty(collection_id: u32, percent: u32, recipients: Vec<(u64, u32)>) {
+    assert_ok!(Template::set_collection_royalty(
+        RuntimeOrigin::signed(1),
+        collection_id,
+        Perbill::from_percent(percent),
+        recipients.try_into().unwrap()
+    ));
+}
+
+#[test]
+fn royalty_is_split_between_recipients() {
+    new_test_ext().execute_with(|| {
+        let (collection_id, item_id, owner) = (1, 1, 1);
+        create_and_mint_nft(collection_id, item_id, owner);
+        set_royalty(collection_id, 10, vec![(4, 1), (5, 2)]);
+        assert_ok!(Template::set_market_fee(RuntimeOrigin::root(), Permill::from_percent(5)));
+
+        assert_ok!(Template::list_nft_for_sale(
+            RuntimeOrigin::signed(owner),
+            collection_id,
+            item_id,
+            1000,
+            SettlementAsset::Native
+        ));
+
+        let owner_balance = Balances::free_balance(owner);
+        let balance_4 = Balances::free_balance(4);
+        let balance_5 = Balances::free_balance(5);
+
+        assert_ok!(Template::buy_nft(RuntimeOrigin::signed(3), collection_id, item_id));
+
+        // 100 royalty split 1:2, the last recipient gets the rounding remainder
+        assert_eq!(Balances::free_balance(4), balance_4 + 33);
+        assert_eq!(Balances::free_balance(5), balance_5 + 67);
+        // 1000 - 100 royalty - 50 fee
+        assert_eq!(Balances::free_balance(owner), owner_balance + 850);
+        System::assert_has_event(
+            Event::RoyaltyPaid(collection_id, 4, SettlementAsset::Native, 33).into(),
+        );
+        System::assert_has_event(
+            Event::RoyaltyPaid(collection_id, 5, SettlementAsset::Native, 67).into(),
+        );
+    });
+}
+
+#[test]
+fn royalty_terms_are_validated() {
+    new_test_ext().execute_with(|| {
+        let collection_id = 1;
+        create_and_mint_nft(collection_id, 1, 1);
+
+        assert_noop!(
+            Template::set_collection_royalty(
+                RuntimeOrigin::signed(2),
+                collection_id,
+                Perbill::from_percent(5),
+                vec![(2, 1)].try_into().unwrap()
+            ),
+            Error::<Test>::NotCollectionOwner
+        );
+        assert_noop!(
+            Template::set_collection_royalty(
+                RuntimeOrigin::signed(1),
+                collection_id,
+                Perbill::from_percent(11),
+                vec![(1, 1)].try_into().unwrap()
+            ),
+            Error::<Test>::RoyaltyTooHigh
+        );
+        assert_noop!(
+            Template::set_collection_royalty(
+                RuntimeOrigin::signed(1),
+                collection_id,
+                Perbill::from_percent(5),
+                vec![(1, 1), (2, 0)].try_into().unwrap()
+            ),
+            Error::<Test>::InvalidRoyaltyRecipients
+        );
+
+        // Only root can raise the maximum
+        assert_noop!(
+            Template::set_max_royalty(RuntimeOrigin::signed(1), Perbill::from_percent(20)),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_ok!(Template::set_max_royalty(RuntimeOrigin::root(), Perbill::from_percent(20)));
+        set_royalty(collection_id, 15, vec![(1, 1)]);
+        assert_eq!(Template::collection_royalty(collection_id).unwrap().rate, Perbill::from_percent(15));
+
+        // A zero rate removes the terms
+        set_royalty(collection_id, 0, vec![]);
+        assert!(Template::collection_royalty(collection_id).is_none());
+    });
+}
+
+#[test]
+fn lowered_max_royalty_caps_existing_terms() {
+    new_test_ext().execute_with(|| {
+        let (collection_id, item_id, owner) = (1, 1, 1);
+        create_and_mint_nft(collection_id, item_id, owner);
+        set_royalty(collection_id, 10, vec![(4, 1)]);
+        assert_ok!(Template::set_max_royalty(RuntimeOrigin::root(), Perbill::from_percent(2)));
+
+        assert_ok!(Template::list_nft_for_auction(
+            RuntimeOrigin::signed(owner),
+            collection_id,
+            item_id,
+            AuctionSettings::default(),
+            SettlementAsset::Native
+        ));
+        assert_ok!(Template::place_bid(RuntimeOrigin::signed(2), collection_id, item_id, 1000));
+
+        let balance_4 = Balances::free_balance(4);
+        assert_ok!(Template::resolve_auction(RuntimeOrigin::signed(owner), collection_id, item_id));
+
+        assert_eq!(Balances::free_balance(4), balance_4 + 20);
+        System::assert_has_event(
+            Event::RoyaltyPaid(collection_id, 4, SettlementAsset::Native, 20).into(),
+        );
+    });
+}
+
+#[test]
+fn auction_settlement_conserves_issuance() {
+    new_test_ext().execute_with(|| {
+        let (collection_id, item_id, owner) = (1, 1, 1);
+        create_and_mint_nft(collection_id, item_id, owner);
+        set_royalty(collection_id, 10, vec![(4, 1)]);
+        assert_ok!(Template::set_market_fee(RuntimeOrigin::root(), Permill::from_percent(5)));
+
+        assert_ok!(Template::list_nft_for_auction(
+            RuntimeOrigin::signed(owner),
+            collection_id,
+            item_id,
+            AuctionSettings::default(),
+            SettlementAsset::Native
+        ));
+        assert_ok!(Template::place_bid(RuntimeOrigin::signed(2), collection_id, item_id, 1000));
+        assert_eq!(Balances::reserved_balance(2), 1000);
+
+        let issuance = Balances::total_issuance();
+        let (owner_balance, bidder_balance, royalty_balance) =
+            (Balances::free_balance(owner), Balances::free_balance(2), Balances::free_balance(4));
+
+        assert_ok!(Template::resolve_auction(RuntimeOrigin::signed(owner), collection_id, item_id));
+
+        // The held bid is paid out as 100 royalty, 50 fee and 850 to the seller
+        assert_eq!(Balances::total_issuance(), issuance);
+        assert_eq!(Balances::reserved_balance(2), 0);
+        assert_eq!(Balances::free_balance(2), bidder_balance);
+        assert_eq!(Balances::free_balance(4), royalty_balance + 100);
+        assert_eq!(Balances::free_balance(owner), owner_balance + 850);
+        assert_eq!(Balances::free_balance(Template::account_id()), 50);
+        assert_ok!(Template::do_try_state());
+
+        assert_ok!(Template::withdraw_fees(RuntimeOrigin::root(), SettlementAsset::Native));
+        assert_eq!(Balances::total_issuance(), issuance);
+        assert_eq!(Balances::free_balance(Template::account_id()), 0);
+        assert_ok!(Template::do_try_state());
+    });
+}
+
+#[test]
+fn shares_below_existential_deposit_go_to_seller() {
+    new_test_ext().execute_with(|| {
+        let (collection_id, item_id, owner, buyer) = (1, 1, 1, 2);
+        create_and_mint_nft(collection_id, item_id, owner);
+        ExistentialDeposit::set(100);
+
+        // Account 6 does not exist, and neither does the pallet account before any fees
+        set_royalty(collection_id, 5, vec![(6, 1)]);
+        assert_ok!(Template::set_market_fee(RuntimeOrigin::root(), Permill::from_percent(5)));
+
+        assert_ok!(Template::make_offer(
+            RuntimeOrigin::signed(buyer),
+            collection_id,
+            item_id,
+            1000,
+            50
+        ));
+
+        let issuance = Balances::total_issuance();
+        let (owner_balance, buyer_balance) =
+            (Balances::free_balance(owner), Balances::free_balance(buyer));
+
+        assert_ok!(Template::accept_offer(
+            RuntimeOrigin::signed(owner),
+            collection_id,
+            item_id,
+            buyer
+        ));
+
+        // Neither the 50 royalty nor the 50 fee can open an account, so the seller gets them
+        assert_eq!(Balances::free_balance(owner), owner_balance + 1000);
+        assert_eq!(Balances::free_balance(buyer), buyer_balance);
+        assert_eq!(Balances::reserved_balance(buyer), 0);
+        assert_eq!(Balances::free_balance(6), 0);
+        assert_eq!(Template::accumulated_fees(SettlementAsset::Native), 0);
+        assert_eq!(Balances::total_issuance(), issuance);
+        assert!(!System::events().iter().any(|record| matches!(
+            record.event,
+            RuntimeEvent::Template(Event::RoyaltyPaid(..))
+        )));
+        assert_ok!(Template::do_try_state());
+    });
+}
+
+#[test]
+fn lowest_bid_is_refundable_once_outranked() {
+    new_test_ext().execute_with(|| {
+        let (collection_id, item_id, owner) = (1, 1, 1);
+        create_and_mint_nft(collection_id, item_id, owner);
+
+        assert_ok!(Template::list_nft_for_auction(
+            RuntimeOrigin::signed(owner),
+            collection_id,
+            item_id,
+            AuctionSettings::default(),
+            SettlementAsset::Native
+        ));
+
+        // The auction holds at most ten bids
+        for bidder in 10..21u64 {
+            let _ = Balances::deposit_creating(&bidder, 1_000);
+            assert_ok!(Template::place_bid(
+                RuntimeOrigin::signed(bidder),
+                collection_id,
+                item_id,
+                bidder as u128 * 10
+            ));
+        }
+
+        // The outranked bid stays held until it is claimed back
+        System::assert_has_event(Event::BidOutranked(collection_id, item_id, 10, 100).into());
+        assert_eq!(Balances::reserved_balance(10), 100);
+        assert_eq!(
+            Template::pending_refunds(&10),
+            vec![((collection_id, item_id), SettlementAsset::Native, 100)]
+        );
+        assert_ok!(Template::claim_refund(RuntimeOrigin::signed(10), collection_id, item_id));
+        assert_eq!(Balances::reserved_balance(10), 0);
+        assert!(Template::bid_of(10, (collection_id, item_id)).is_none());
+        for bidder in 11..21u64 {
+            assert_eq!(Balances::reserved_balance(bidder), bidder as u128 * 10);
+        }
+        assert_eq!(
+            Template::top_bids(collection_id, item_id, 3),
+            vec![(20, 200), (19, 190), (18, 180)]
+        );
+
+        // Raising a bid only holds the difference
+        assert_ok!(Template::place_bid(RuntimeOrigin::signed(11), collection_id, item_id, 300));
+        assert_eq!(Balances::reserved_balance(11), 300);
+        assert_eq!(Template::top_bids(collection_id, item_id, 1), vec![(11, 300)]);
+        assert_eq!(Template::ranked_bids((collection_id, item_id)).len(), 10);
+    });
+}
+
+#[test]
+fn fallback_bidder_settles_from_held_bid() {
+    new_test_ext().execute_with(|| {
+        let (collection_id, item_id, owner) = (1, 1, 1);
+        create_and_mint_nft(collection_id, item_id, owner);
+
+        assert_ok!(Template::list_nft_for_auction(
+            RuntimeOrigin::signed(owner),
+            collection_id,
+            item_id,
+            AuctionSettings::default(),
+            SettlementAsset::Native
+        ));
+        assert_ok!(Template::place_bid(RuntimeOrigin::signed(2), collection_id, item_id, 500));
+        assert_ok!(Template::place_bid(RuntimeOrigin::signed(3), collection_id, item_id, 600));
+
+        // The leading bid loses its hold behind the pallet's back, and the fallback bidder
+        // has nothing left besides their held bid
+        assert_ok!(<Balances as MutateHold<u64>>::release(
+            &crate::HoldReason::AuctionBid.into(),
+            &3,
+            600,
+            Precision::Exact
+        ));
+        assert_ok!(Balances::force_set_balance(RuntimeOrigin::root(), 2, 1));
+
+        let owner_balance = Balances::free_balance(owner);
+        System::set_block_number(101);
+        Template::on_initialize(101);
+
+        System::assert_has_event(Event::AuctionResolved(collection_id, item_id, 2, 500).into());
+        assert_eq!(pallet_uniques::Pallet::<Test>::owner(collection_id, item_id), Some(2));
+        assert_eq!(Balances::reserved_balance(2), 0);
+        assert_eq!(Balances::free_balance(2), 1);
+        assert_eq!(Balances::free_balance(owner), owner_balance + 500);
+        assert!(Template::ranked_bids((collection_id, item_id)).is_empty());
+    });
+}
+
+#[test]
+fn outbid_bidders_claim_refunds() {
+    new_test_ext().execute_with(|| {
+        let (collection_id, item_id, owner) = (1, 1, 1);
+        create_and_mint_nft(collection_id, item_id, owner);
+
+        assert_ok!(Template::list_nft_for_auction(
+            RuntimeOrigin::signed(owner),
+            collection_id,
+            item_id,
+            AuctionSettings::default(),
+            SettlementAsset::Native
+        ));
+        assert_ok!(Template::place_bid(RuntimeOrigin::signed(2), collection_id, item_id, 500));
+        assert_ok!(Template::place_bid(RuntimeOrigin::signed(3), collection_id, item_id, 600));
+
+        // Bids ranked in a running auction still compete
+        assert!(Template::pending_refunds(&2).is_empty());
+        assert_noop!(
+            Template::claim_refund(RuntimeOrigin::signed(2), collection_id, item_id),
+            Error::<Test>::BidStillActive
+        );
+        assert_noop!(
+            Template::claim_refund(RuntimeOrigin::signed(4), collection_id, item_id),
+            Error::<Test>::NoRefundAvailable
+        );
+
+        assert_ok!(Template::resolve_auction(RuntimeOrigin::signed(owner), collection_id, item_id));
+        assert_eq!(Balances::reserved_balance(2), 500);
+        assert_eq!(
+            Template::pending_refunds(&2),
+            vec![((collection_id, item_id), SettlementAsset::Native, 500)]
+        );
+        assert!(Template::pending_refunds(&3).is_empty());
+
+        let bidder_balance = Balances::free_balance(2);
+        assert_ok!(Template::claim_refund(RuntimeOrigin::signed(2), collection_id, item_id));
+        System::assert_last_event(Event::RefundClaimed(collection_id, item_id, 2, 500).into());
+        assert_eq!(Balances::free_balance(2), bidder_balance + 500);
+        assert_eq!(Balances::reserved_balance(2), 0);
+        assert_noop!(
+            Template::claim_refund(RuntimeOrigin::signed(2), collection_id, item_id),
+            Error::<Test>::NoRefundAvailable
+        );
+    });
+}
+
+#[test]
+fn claim_all_refunds_releases_every_settled_bid() {
+    new_test_ext().execute_with(|| {
+        let owner = 1;
+        for item_id in 1..=3 {
+            create_and_mint_nft(item_id, item_id, owner);
+            assert_ok!(Template::list_nft_for_auction(
+                RuntimeOrigin::signed(owner),
+                item_id,
+                item_id,
+                AuctionSettings::default(),
+                SettlementAsset::Native
+            ));
+            assert_ok!(Template::place_bid(RuntimeOrigin::signed(2), item_id, item_id, 100));
+            assert_ok!(Template::place_bid(RuntimeOrigin::signed(3), item_id, item_id, 200));
+        }
+        assert_ok!(Template::resolve_auction(RuntimeOrigin::signed(owner), 1, 1));
+        assert_ok!(Template::cancel_auction(RuntimeOrigin::signed(owner), 2, 2));
+
+        // The bid on the auction still running stays held
+        assert_eq!(Template::pending_refunds(&2).len(), 2);
+        assert_ok!(Template::claim_all_refunds(RuntimeOrigin::signed(2)));
+        assert_eq!(Balances::reserved_balance(2), 100);
+        assert!(Template::pending_refunds(&2).is_empty());
+        assert_noop!(
+            Template::claim_all_refunds(RuntimeOrigin::signed(2)),
+            Error::<Test>::NoRefundAvailable
+        );
+
+        // Raising the bid still held only holds the difference
+        assert_ok!(Template::place_bid(RuntimeOrigin::signed(4), 3, 3, 300));
+        assert_ok!(Template::place_bid(RuntimeOrigin::signed(2), 3, 3, 400));
+        assert_eq!(Balances::reserved_balance(2), 400);
+        assert_eq!(Template::bid_of(2, (3, 3)).map(|bid| bid.amount), Some(400));
+    });
+}
+
+#[test]
+fn sales_are_archived_with_collection_and_account_stats() {
+    new_test_ext().execute_with(|| {
+        let (collection_id, owner) = (1, 1);
+        create_and_mint_nft(collection_id, 1, owner);
+        assert_ok!(pallet_uniques::Pallet::<Test>::mint(
+            RuntimeOrigin::signed(owner),
+            collection_id,
+            2,
+            owner
+        ));
+
+        // The owner sells the NFT again each time it is handed back, the history keeps the
+        // last three sales
+        let sell = |item_id: u32, buyer: u64, price: u128| {
+            let seller = owner;
+            assert_ok!(Template::list_nft_for_auction(
+                RuntimeOrigin::signed(seller),
+                collection_id,
+                item_id,
+                AuctionSettings::default(),
+                SettlementAsset::Native
+            ));
+            assert_ok!(Template::place_bid(
+                RuntimeOrigin::signed(buyer),
+                collection_id,
+                item_id,
+                price
+            ));
+            assert_ok!(Template::resolve_auction(
+                RuntimeOrigin::signed(seller),
+                collection_id,
+                item_id
+            ));
+            assert_ok!(pallet_uniques::Pallet::<Test>::transfer(
+                RuntimeOrigin::signed(buyer),
+                collection_id,
+                item_id,
+                seller
+            ));
+        };
+        sell(1, 2, 100);
+        sell(1, 3, 300);
+        sell(1, 2, 200);
+        sell(1, 3, 400);
+        sell(2, 2, 50);
+
+        assert!(Template::auctions((collection_id, 1)).is_none());
+        let history = Template::sales_history((collection_id, 1));
+        assert_eq!(
+            history.iter().map(|sale| (sale.seller, sale.buyer, sale.price)).collect::<Vec<_>>(),
+            vec![(owner, 3, 300), (owner, 2, 200), (owner, 3, 400)]
+        );
+        assert_eq!(history[0].asset, SettlementAsset::Native);
+
+        assert_eq!(
+            Template::collection_summary(collection_id, SettlementAsset::Native),
+            CollectionSummary { last_price: 50, floor: 50, volume: 1_050, sales: 5 }
+        );
+        assert_eq!(
+            Template::account_stats(2, SettlementAsset::Native),
+            AccountStats { volume_bought: 350, volume_sold: 0, auctions_won: 3 }
+        );
+        assert_eq!(
+            Template::account_stats(owner, SettlementAsset::Native),
+            AccountStats { volume_bought: 0, volume_sold: 1_050, auctions_won: 0 }
+        );
+        assert_eq!(Template::account_stats(2, SettlementAsset::Asset(7)), AccountStats::default());
+    });
+}
+
+#[test]
+fn collection_stats_aggregate_sales_by_bucket() {
+    new_test_ext().execute_with(|| {
+        let (collection_id, item_id, owner) = (1, 1, 1);
+        create_and_mint_nft(collection_id, item_id, owner);
+        assert!(Template::collection_stats(collection_id, 100).is_empty());
+
+        let sell = |buyer: u64, price: u128| {
+            assert_ok!(Template::list_nft_for_auction(
+                RuntimeOrigin::signed(owner),
+                collection_id,
+                item_id,
+                AuctionSettings::default(),
+                SettlementAsset::Native
+            ));
+            assert_ok!(Template::place_bid(
+                RuntimeOrigin::signed(buyer),
+                collection_id,
+                item_id,
+                price
+            ));
+            assert_ok!(Template::resolve_auction(
+                RuntimeOrigin::signed(owner),
+                collection_id,
+                item_id
+            ));
+            assert_ok!(pallet_uniques::Pallet::<Test>::transfer(
+                RuntimeOrigin::signed(buyer),
+                collection_id,
+                item_id,
+                owner
+            ));
+        };
+
+        // Buckets are ten blocks long
+        sell(2, 100);
+        sell(3, 300);
+        sell(2, 500);
+        System::set_block_number(12);
+        sell(3, 200);
+        System::set_block_number(25);
+        sell(2, 400);
+
+        assert_eq!(
+            Template::collection_stats(collection_id, 100),
+            vec![(
+                SettlementAsset::Native,
+                CollectionStats { floor: 100, median: 300, volume: 1_500, sales: 5 }
+            )]
+        );
+        // A window reaching into a bucket counts the whole bucket
+        assert_eq!(
+            Template::collection_stats(collection_id, 10),
+            vec![(
+                SettlementAsset::Native,
+                CollectionStats { floor: 200, median: 300, volume: 600, sales: 2 }
+            )]
+        );
+
+        // Only the latest three buckets are kept
+        System::set_block_number(31);
+        sell(3, 50);
+        assert_eq!(
+            Template::sales_bucket_starts(collection_id, SettlementAsset::Native).into_inner(),
+            vec![10, 20, 30]
+        );
+        assert_eq!(Template::sales_bucket((collection_id, SettlementAsset::Native, 0)), None);
+        assert_eq!(
+            Template::collection_stats(collection_id, 1_000),
+            vec![(
+                SettlementAsset::Native,
+                CollectionStats { floor: 50, median: 200, volume: 650, sales: 3 }
+            )]
+        );
+
+        // A full bucket keeps a sample of four prices
+        for price in [60, 70, 80, 90] {
+            sell(2, price);
+        }
+        let bucket = Template::sales_bucket((collection_id, SettlementAsset::Native, 30)).unwrap();
+        assert_eq!((bucket.sales, bucket.volume, bucket.prices.len()), (5, 350, 4));
+        assert!(bucket.prices.iter().all(|price| [50, 60, 70, 80, 90].contains(price)));
+    });
+}
+
+#[test]
+fn escrowed_proceeds_are_released_after_dispute_window() {
+    new_test_ext().execute_with(|| {
+        let (collection_id, item_id, owner) = (1, 1, 1);
+        create_and_mint_nft(collection_id, item_id, owner);
+        EscrowPeriod::set(10);
+        assert_ok!(Template::set_market_fee(RuntimeOrigin::root(), Permill::from_percent(5)));
+
+        assert_ok!(Template::list_nft_for_auction(
+            RuntimeOrigin::signed(owner),
+            collection_id,
+            item_id,
+            AuctionSettings::default(),
+            SettlementAsset::Native
+        ));
+        assert_ok!(Template::place_bid(RuntimeOrigin::signed(2), collection_id, item_id, 500));
+
+        let owner_balance = Balances::free_balance(owner);
+        assert_ok!(Template::resolve_auction(RuntimeOrigin::signed(owner), collection_id, item_id));
+        System::assert_has_event(
+            Event::ProceedsEscrowed(collection_id, item_id, owner, 475, 11).into(),
+        );
+        assert_eq!(pallet_uniques::Pallet::<Test>::owner(collection_id, item_id), Some(2));
+        assert_eq!(Balances::free_balance(owner), owner_balance);
+        assert_eq!(Balances::free_balance(Template::account_id()), 500);
+        assert_ok!(Template::do_try_state());
+
+        // The NFT cannot go up for auction again while its proceeds are in escrow
+        assert_noop!(
+            Template::list_nft_for_auction(
+                RuntimeOrigin::signed(2),
+                collection_id,
+                item_id,
+                AuctionSettings::default(),
+                SettlementAsset::Native
+            ),
+            Error::<Test>::NftInEscrow
+        );
+        assert_noop!(
+            Template::release_escrow(RuntimeOrigin::signed(3), collection_id, item_id),
+            Error::<Test>::EscrowNotDue
+        );
+
+        System::set_block_number(11);
+        assert_noop!(
+            Template::open_dispute(RuntimeOrigin::signed(2), collection_id, item_id),
+            Error::<Test>::DisputeWindowClosed
+        );
+        assert_ok!(Template::release_escrow(RuntimeOrigin::signed(3), collection_id, item_id));
+        System::assert_last_event(Event::EscrowReleased(collection_id, item_id, owner, 475).into());
+        assert_eq!(Balances::free_balance(owner), owner_balance + 475);
+        // The fee is collected once the escrow is released
+        assert_eq!(Template::accumulated_fees(SettlementAsset::Native), 25);
+        assert!(Template::escrows((collection_id, item_id)).is_none());
+        assert_ok!(Template::do_try_state());
+    });
+}
+
+// Sell an NFT at auction to account 2 with its proceeds escrowed
+fn sell_into_escrow(collection_id: u32, item_id: u32, owner: u64) {
+    create_and_mint_nft(collection_id, item_id, owner);
+    EscrowPeriod::set(10);
+    assert_ok!(Template::list_nft_for_auction(
+        RuntimeOrigin::signed(owner),
+        collection_id,
+        item_id,
+        AuctionSettings::default(),
+        SettlementAsset::Native
+    ));
+    assert_ok!(Template::place_bid(RuntimeOrigin::signed(2), collection_id, item_id, 500));
+    assert_ok!(Template::resolve_auction(RuntimeOrigin::signed(owner), collection_id, item_id));
+    assert!(Template::escrows((collection_id, item_id)).is_some());
+}
+
+#[test]
+fn nft_in_escrow_cannot_be_listed_for_sale() {
+    new_test_ext().execute_with(|| {
+        sell_into_escrow(1, 1, 1);
+
+        assert_noop!(
+            Template::list_nft_for_sale(
+                RuntimeOrigin::signed(2),
+                1,
+                1,
+                1_000,
+                SettlementAsset::Native
+            ),
+            Error::<Test>::NftInEscrow
+        );
+    });
+}
+
+#[test]
+fn nft_in_escrow_cannot_be_sold_through_an_offer() {
+    new_test_ext().execute_with(|| {
+        sell_into_escrow(1, 1, 1);
+        assert_ok!(Template::make_offer(RuntimeOrigin::signed(3), 1, 1, 800, 50));
+
+        assert_noop!(
+            Template::accept_offer(RuntimeOrigin::signed(2), 1, 1, 3),
+            Error::<Test>::NftInEscrow
+        );
+
+        System::set_block_number(11);
+        assert_ok!(Template::release_escrow(RuntimeOrigin::signed(4), 1, 1));
+        assert_ok!(Template::accept_offer(RuntimeOrigin::signed(2), 1, 1, 3));
+        assert_eq!(pallet_uniques::Pallet::<Test>::owner(1, 1), Some(3));
+    });
+}
+
+#[test]
+fn disputed_escrow_is_resolved_by_dispute_origin() {
+    new_test_ext().execute_with(|| {
+        let (collection_id, item_id, owner) = (1, 1, 1);
+        create_and_mint_nft(collection_id, item_id, owner);
+        EscrowPeriod::set(10);
+        set_royalty(collection_id, 10, vec![(4, 1)]);
+        assert_ok!(Template::set_market_fee(RuntimeOrigin::root(), Permill::from_percent(5)));
+
+        assert_ok!(Template::list_nft_for_auction(
+            RuntimeOrigin::signed(owner),
+            collection_id,
+            item_id,
+            AuctionSettings::default(),
+            SettlementAsset::Native
+        ));
+        assert_ok!(Template::place_bid(RuntimeOrigin::signed(2), collection_id, item_id, 500));
+        let buyer_balance = Balances::total_balance(&2);
+        assert_ok!(Template::resolve_auction(RuntimeOrigin::signed(owner), collection_id, item_id));
+
+        assert_noop!(
+            Template::open_dispute(RuntimeOrigin::signed(3), collection_id, item_id),
+            Error::<Test>::NotEscrowBuyer
+        );
+        assert_noop!(
+            Template::resolve_dispute(RuntimeOrigin::root(), collection_id, item_id, true),
+            Error::<Test>::EscrowNotDisputed
+        );
+        assert_ok!(Template::open_dispute(RuntimeOrigin::signed(2), collection_id, item_id));
+        System::assert_last_event(Event::DisputeOpened(collection_id, item_id, 2).into());
+
+        // A disputed escrow waits for the dispute origin, even past the window
+        System::set_block_number(20);
+        assert_noop!(
+            Template::release_escrow(RuntimeOrigin::signed(owner), collection_id, item_id),
+            Error::<Test>::EscrowDisputed
+        );
+        assert_noop!(
+            Template::resolve_dispute(RuntimeOrigin::signed(owner), collection_id, item_id, false),
+            sp_runtime::DispatchError::BadOrigin
+        );
+
+        // The 425 proceeds are escrowed with the 25 fee, the 50 royalty is paid out
+        assert_eq!(
+            Template::escrows((collection_id, item_id)).map(|escrow| (escrow.amount, escrow.fee)),
+            Some((425, 25))
+        );
+        assert_eq!(Template::accumulated_fees(SettlementAsset::Native), 0);
+        assert_ok!(Template::do_try_state());
+
+        // The buyer has to hold the NFT to return it
+        assert_ok!(pallet_uniques::Pallet::<Test>::transfer(
+            RuntimeOrigin::signed(2),
+            collection_id,
+            item_id,
+            3
+        ));
+        assert_noop!(
+            Template::resolve_dispute(RuntimeOrigin::root(), collection_id, item_id, true),
+            Error::<Test>::NftNotReturnable
+        );
+        assert_ok!(pallet_uniques::Pallet::<Test>::transfer(
+            RuntimeOrigin::signed(3),
+            collection_id,
+            item_id,
+            2
+        ));
+
+        // Proceeds and fee go back to the buyer and the NFT to the seller, the royalty stays paid
+        assert_ok!(Template::resolve_dispute(RuntimeOrigin::root(), collection_id, item_id, true));
+        System::assert_last_event(Event::EscrowRefunded(collection_id, item_id, 2, 450).into());
+        assert_eq!(Balances::total_balance(&2), buyer_balance - 50);
+        assert_eq!(pallet_uniques::Pallet::<Test>::owner(collection_id, item_id), Some(owner));
+        assert_eq!(Template::accumulated_fees(SettlementAsset::Native), 0);
+        assert!(Template::escrows((collection_id, item_id)).is_none());
+        assert_ok!(Template::do_try_state());
+    });
+}
+
+fn bundle(
+    items: Vec<(u32, u32)>,
+) -> frame_support::BoundedVec<(u32, u32), <Test as crate::Config>::MaxBundleSize> {
+    items.try_into().unwrap()
+}
+
+#[test]
+fn bundle_auction_sells_all_items_to_winner() {
+    new_test_ext().execute_with(|| {
+        let (collection_id, owner) = (1, 1);
+        create_and_mint_nft(collection_id, 1, owner);
+        for item_id in 2..=3 {
+            assert_ok!(pallet_uniques::Pallet::<Test>::mint(
+                RuntimeOrigin::signed(owner),
+                collection_id,
+                item_id,
+                owner
+            ));
+        }
+
+        assert_ok!(Template::list_bundle_for_auction(
+            RuntimeOrigin::signed(owner),
+            bundle(vec![(1, 1), (1, 2), (1, 3)]),
+            None,
+            None,
+            None,
+            None,
+            SettlementAsset::Native
+        ));
+        System::assert_last_event(Event::BundleListed(collection_id, 1, owner, 3).into());
+
+        // Any item of the bundle resolves to the auction keyed by the first one
+        assert_eq!(Template::auction_key((1, 3)), (1, 1));
+        assert!(Template::is_in_auction((1, 2)));
+        assert_noop!(
+            Template::list_nft_for_auction(
+                RuntimeOrigin::signed(owner),
+                collection_id,
+                2,
+                AuctionSettings::default(),
+                SettlementAsset::Native
+            ),
+            Error::<Test>::NftAlreadyInAuction
+        );
+        assert_noop!(
+            pallet_uniques::Pallet::<Test>::transfer(RuntimeOrigin::signed(owner), 1, 3, 4),
+            pallet_uniques::Error::<Test>::Frozen
+        );
+
+        assert_ok!(Template::place_bid(RuntimeOrigin::signed(2), collection_id, 1, 500));
+        assert_ok!(Template::resolve_auction(RuntimeOrigin::signed(owner), collection_id, 1));
+        System::assert_has_event(Event::AuctionResolved(collection_id, 1, 2, 500).into());
+        for item_id in 1..=3 {
+            assert_eq!(pallet_uniques::Pallet::<Test>::owner(collection_id, item_id), Some(2));
+            assert!(Template::bundle_of((collection_id, item_id)).is_none());
+        }
+        assert!(Template::bundle_items((collection_id, 1)).is_empty());
+        assert!(!Template::is_in_auction((1, 2)));
+
+        // The items can be traded on their own again
+        assert_ok!(pallet_uniques::Pallet::<Test>::transfer(RuntimeOrigin::signed(2), 1, 3, 4));
+    });
+}
+
+#[test]
+fn bundle_auction_without_bids_thaws_all_items_on_expiry() {
+    new_test_ext().execute_with(|| {
+        let (collection_id, owner) = (1, 1);
+        create_and_mint_nft(collection_id, 1, owner);
+        assert_ok!(pallet_uniques::Pallet::<Test>::mint(
+            RuntimeOrigin::signed(owner),
+            collection_id,
+            2,
+            owner
+        ));
+        assert_ok!(Template::list_bundle_for_auction(
+            RuntimeOrigin::signed(owner),
+            bundle(vec![(1, 1), (1, 2)]),
+            None,
+            None,
+            None,
+            None,
+            SettlementAsset::Native
+        ));
+
+        System::set_block_number(101);
+        Template::on_initialize(101);
+
+        System::assert_last_event(Event::AuctionFailed(collection_id, 1).into());
+        assert!(Template::bundle_of((collection_id, 2)).is_none());
+        for item_id in 1..=2 {
+            assert_ok!(pallet_uniques::Pallet::<Test>::transfer(
+                RuntimeOrigin::signed(owner),
+                collection_id,
+                item_id,
+                4
+            ));
+        }
+    });
+}
+
+#[test]
+fn bundle_listing_is_validated_and_cancelled_together() {
+    new_test_ext().execute_with(|| {
+        let owner = 1;
+        create_and_mint_nft(1, 1, owner);
+        create_and_mint_nft(2, 1, owner);
+        assert_ok!(pallet_uniques::Pallet::<Test>::mint(RuntimeOrigin::signed(owner), 1, 2, owner));
+        assert_ok!(pallet_uniques::Pallet::<Test>::mint(RuntimeOrigin::signed(owner), 1, 3, 3));
+
+        let list = |items: Vec<(u32, u32)>| {
+            Template::list_bundle_for_auction(
+                RuntimeOrigin::signed(owner),
+                bundle(items),
+                None,
+                None,
+                None,
+                None,
+                SettlementAsset::Native,
+            )
+        };
+        assert_noop!(list(vec![(1, 1)]), Error::<Test>::InvalidBundle);
+        assert_noop!(list(vec![(1, 1), (1, 1)]), Error::<Test>::InvalidBundle);
+        assert_noop!(list(vec![(1, 1), (2, 1)]), Error::<Test>::InvalidBundle);
+        assert_noop!(list(vec![(1, 1), (1, 3)]), Error::<Test>::NotNftOwner);
+
+        assert_ok!(list(vec![(1, 1), (1, 2)]));
+        assert_ok!(Template::cancel_auction(RuntimeOrigin::signed(owner), 1, 1));
+        assert!(Template::bundle_of((1, 2)).is_none());
+        assert!(!Template::is_in_auction((1, 2)));
+        assert_ok!(pallet_uniques::Pallet::<Test>::transfer(RuntimeOrigin::signed(owner), 1, 2, 4));
+    });
+}
+
+#[test]
+fn max_bids_defend_the_lead_up_to_their_maximum() {
+    new_test_ext().execute_with(|| {
+        let (collection_id, item_id, owner) = (1, 1, 1);
+        create_and_mint_nft(collection_id, item_id, owner);
+
+        assert_ok!(Template::list_nft_for_auction(
+            RuntimeOrigin::signed(owner),
+            collection_id,
+            item_id,
+            AuctionSettings {
+                min_increment: Some(BidIncrement::Absolute(10)),
+                ..Default::default()
+            },
+            SettlementAsset::Native
+        ));
+        let bidder_balance = Balances::free_balance(3);
+
+        // Only the maximum is held, the bid itself is the least needed to lead
+        assert_ok!(Template::place_max_bid(RuntimeOrigin::signed(2), collection_id, item_id, 500));
+        System::assert_last_event(Event::MaxBidPlaced(collection_id, item_id, 2).into());
+        assert_eq!(Balances::reserved_balance(2), 500);
+        assert_eq!(Template::ranked_bids((collection_id, item_id)).into_inner(), vec![(2, 1)]);
+
+        // Lower bids are answered one increment over, without revealing the maximum
+        assert_ok!(Template::place_bid(RuntimeOrigin::signed(3), collection_id, item_id, 100));
+        System::assert_last_event(Event::BidPlaced(collection_id, item_id, 2, 110).into());
+        assert_ok!(Template::place_max_bid(RuntimeOrigin::signed(4), collection_id, item_id, 300));
+        assert_eq!(
+            Template::ranked_bids((collection_id, item_id)).into_inner(),
+            vec![(2, 310), (4, 300), (3, 100)]
+        );
+        let auction = Template::auctions((collection_id, item_id)).unwrap();
+        assert_eq!((auction.highest_bidder, auction.highest_bid), (Some(2), 310));
+
+        // A higher maximum takes the lead after the outbid one bids all of its own
+        assert_ok!(Template::place_max_bid(RuntimeOrigin::signed(3), collection_id, item_id, 800));
+        System::assert_has_event(Event::BidPlaced(collection_id, item_id, 2, 500).into());
+        System::assert_has_event(Event::BidPlaced(collection_id, item_id, 3, 510).into());
+        assert_eq!(Balances::reserved_balance(3), 800);
+
+        // The leader can raise their maximum without bidding against themselves
+        assert_noop!(
+            Template::place_max_bid(RuntimeOrigin::signed(3), collection_id, item_id, 800),
+            Error::<Test>::BidTooLow
+        );
+        assert_ok!(Template::place_max_bid(RuntimeOrigin::signed(3), collection_id, item_id, 900));
+        let auction = Template::auctions((collection_id, item_id)).unwrap();
+        assert_eq!((auction.highest_bidder, auction.highest_bid), (Some(3), 510));
+        assert_eq!(Balances::reserved_balance(3), 900);
+
+        // The winner pays their bid and gets the rest of the maximum back
+        assert_ok!(Template::resolve_auction(RuntimeOrigin::signed(owner), collection_id, item_id));
+        assert_eq!(pallet_uniques::Pallet::<Test>::owner(collection_id, item_id), Some(3));
+        assert_eq!(Balances::reserved_balance(3), 0);
+        assert_eq!(Balances::free_balance(3), bidder_balance - 510);
+        assert!(MaxBids::<Test>::get((collection_id, item_id)).is_none());
+        assert_eq!(
+            Template::pending_refunds(&2),
+            vec![((collection_id, item_id), SettlementAsset::Native, 500)]
+        );
+    });
+}
+
+#[test]
+fn equal_max_bids_keep_the_earlier_one_ahead() {
+    new_test_ext().execute_with(|| {
+        let (collection_id, item_id, owner) = (1, 1, 1);
+        create_and_mint_nft(collection_id, item_id, owner);
+
+        assert_ok!(Template::list_nft_for_auction(
+            RuntimeOrigin::signed(owner),
+            collection_id,
+            item_id,
+            AuctionSettings { reserve_price: Some(200), ..Default::default() },
+            SettlementAsset::Native
+        ));
+
+        // A maximum bid reaching the reserve opens at the reserve
+        assert_ok!(Template::place_max_bid(RuntimeOrigin::signed(2), collection_id, item_id, 300));
+        assert_eq!(Template::auctions((collection_id, item_id)).unwrap().highest_bid, 200);
+
+        assert_ok!(Template::place_max_bid(RuntimeOrigin::signed(3), collection_id, item_id, 300));
+        assert_eq!(
+            Template::ranked_bids((collection_id, item_id)).into_inner(),
+            vec![(2, 300), (3, 300)]
+        );
+        assert!(MaxBids::<Test>::get((collection_id, item_id)).is_none());
+
+        // Only English auctions take maximum bids
+        create_and_mint_nft(2, 1, owner);
+        assert_ok!(Template::list_nft_for_dutch_auction(
+            RuntimeOrigin::signed(owner),
+            2,
+            1,
+            1_000,
+            100,
+            10,
+            None,
+            SettlementAsset::Native
+        ));
+        assert_noop!(
+            Template::place_max_bid(RuntimeOrigin::signed(2), 2, 1, 1_000),
+            Error::<Test>::MaxBidNotSupported
+        );
+    });
+}
+
+#[test]
+fn gated_auctions_only_take_eligible_bidders() {
+    new_test_ext().execute_with(|| {
+        let owner = 1;
+        let rules = [
+            BidderAccess::Allowlist(vec![3].try_into().unwrap()),
+            BidderAccess::CollectionHolders(10),
+            BidderAccess::ProfileHolders,
+        ];
+        // Account 3 holds an NFT of collection 10, account 2 has a profile
+        create_and_mint_nft(10, 1, 3);
+        for (item_id, access) in (1..).zip(rules) {
+            create_and_mint_nft(item_id, item_id, owner);
+            assert_ok!(Template::list_nft_for_auction(
+                RuntimeOrigin::signed(owner),
+                item_id,
+                item_id,
+                AuctionSettings {
+                    buy_now_price: Some(1_000),
+                    access: Some(access.clone()),
+                    ..Default::default()
+                },
+                SettlementAsset::Native
+            ));
+            assert_eq!(Template::auction_access((item_id, item_id)), Some(access));
+        }
+
+        for (item_id, eligible, other) in [(1, 3, 2), (2, 3, 2), (3, 2, 3)] {
+            assert_noop!(
+                Template::place_bid(RuntimeOrigin::signed(other), item_id, item_id, 100),
+                Error::<Test>::NotEligibleBidder
+            );
+            assert_noop!(
+                Template::place_max_bid(RuntimeOrigin::signed(other), item_id, item_id, 100),
+                Error::<Test>::NotEligibleBidder
+            );
+            assert_noop!(
+                Template::buy_now(RuntimeOrigin::signed(other), item_id, item_id),
+                Error::<Test>::NotEligibleBidder
+            );
+            assert_ok!(Template::place_bid(RuntimeOrigin::signed(eligible), item_id, item_id, 100));
+        }
+
+        // The rule goes with the auction
+        assert_ok!(Template::resolve_auction(RuntimeOrigin::signed(owner), 1, 1));
+        assert!(Template::auction_access((1, 1)).is_none());
+    });
+}
+
+#[test]
+fn unsold_gated_auction_relisted_ungated_takes_any_bidder() {
+    new_test_ext().execute_with(|| {
+        let owner = 1;
+        create_and_mint_nft(1, 1, owner);
+        assert_ok!(Template::list_nft_for_auction(
+            RuntimeOrigin::signed(owner),
+            1,
+            1,
+            AuctionSettings {
+                access: Some(BidderAccess::Allowlist(vec![3].try_into().unwrap())),
+                ..Default::default()
+            },
+            SettlementAsset::Native
+        ));
+
+        // Expires with no bids
+        System::set_block_number(101);
+        Template::on_initialize(101);
+        System::assert_last_event(Event::AuctionFailed(1, 1).into());
+        assert!(Template::auction_access((1, 1)).is_none());
+
+        assert_ok!(Template::list_nft_for_auction(
+            RuntimeOrigin::signed(owner),
+            1,
+            1,
+            AuctionSettings::default(),
+            SettlementAsset::Native
+        ));
+        assert_ok!(Template::place_bid(RuntimeOrigin::signed(2), 1, 1, 100));
+    });
+}
+
+#[test]
+fn market_params_are_set_by_admin_within_bounds() {
+    new_test_ext().execute_with(|| {
+        let params = MarketParameters {
+            max_bids_per_auction: 2,
+            auction_timeout: 50,
+            max_batch_listing_size: 1,
+        };
+        assert_noop!(
+            Template::set_market_params(RuntimeOrigin::signed(1), params.clone()),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        for invalid in [
+            MarketParameters { max_bids_per_auction: 0, ..params.clone() },
+            MarketParameters { max_bids_per_auction: 11, ..params.clone() },
+            MarketParameters { auction_timeout: 9, ..params.clone() },
+            MarketParameters { auction_timeout: 1001, ..params.clone() },
+            MarketParameters { max_batch_listing_size: 0, ..params.clone() },
+            MarketParameters { max_batch_listing_size: 11, ..params.clone() },
+        ] {
+            assert_noop!(
+                Template::set_market_params(RuntimeOrigin::root(), invalid),
+                Error::<Test>::InvalidMarketParams
+            );
+        }
+
+        assert_ok!(Template::set_market_params(RuntimeOrigin::root(), params.clone()));
+        System::assert_last_event(Event::MarketParamsUpdated(params.clone()).into());
+        assert_eq!(Template::market_params(), params);
+
+        // Auctions run for the new default and rank only the two highest bids
+        let (collection_id, item_id, owner) = (1, 1, 1);
+        create_and_mint_nft(collection_id, item_id, owner);
+        assert_ok!(Template::list_nft_for_auction(
+            RuntimeOrigin::signed(owner),
+            collection_id,
+            item_id,
+            AuctionSettings::default(),
+            SettlementAsset::Native
+        ));
+        assert_eq!(Template::auctions((collection_id, item_id)).unwrap().end_block, 51);
+        for (bidder, amount) in [(2, 100), (3, 200), (4, 300)] {
+            assert_ok!(Template::place_bid(
+                RuntimeOrigin::signed(bidder),
+                collection_id,
+                item_id,
+                amount
+            ));
+        }
+        System::assert_has_event(Event::BidOutranked(collection_id, item_id, 2, 100).into());
+        assert_eq!(
+            Template::ranked_bids((collection_id, item_id)).into_inner(),
+            vec![(4, 300), (3, 200)]
+        );
+
+        // Batches list a single NFT
+        create_and_mint_nft(2, 1, owner);
+        assert_ok!(pallet_uniques::Pallet::<Test>::mint(RuntimeOrigin::signed(owner), 2, 2, owner));
+        assert_noop!(
+            Template::batch_list_nfts_for_auction(
+                RuntimeOrigin::signed(owner),
+                BatchListingInfo {
+                    nfts: vec![(2, 1), (2, 2)].try_into().unwrap(),
+                    min_bid: None,
+                    custom_timeout: None,
+                    asset: SettlementAsset::Native,
+                }
+            ),
+            Error::<Test>::BatchTooLarge
+        );
+    });
+}
+
+#[test]
+fn genesis_seeds_market_params() {
+    let genesis = crate::GenesisConfig::<Test> {
+        max_bids_per_auction: 3,
+        auction_timeout: 20,
+        market_fee: Permill::from_percent(5),
+        ..Default::default()
+    };
+    let mut ext: sp_io::TestExternalities = genesis.build_storage().unwrap().into();
+    ext.execute_with(|| {
+        assert_eq!(
+            Template::market_params(),
+            MarketParameters {
+                max_bids_per_auction: 3,
+                auction_timeout: 20,
+                max_batch_listing_size: 10,
+            }
+        );
+        assert_eq!(Template::market_fee(), Permill::from_percent(5));
+        assert_eq!(Template::max_royalty(), Perbill::from_percent(10));
+    });
+}
@@ -36,7 +36,7 @@ pub trait WeightInfo {
 	fn list_nft_for_auction() -> Weight;
 	fn place_bid() -> Weight;
 	fn resolve_auction() -> Weight;
-	fn set_fee_percentage() -> Weight;
+	fn set_market_fee() -> Weight;
 	fn on_initialize() -> Weight;
 }
 
@@ -83,8 +83,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `Uniques::Class` (r:1 w:0)
 	/// Proof: `Uniques::Class` (`max_values`: None, `max_size`: Some(178), added: 2653, mode: `MaxEncodedLen`)
-	/// Storage: `Template::FeePercentage` (r:1 w:0)
-	/// Proof: `Template::FeePercentage` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Template::MarketFee` (r:1 w:0)
+	/// Proof: `Template::MarketFee` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// Storage: `Template::AccumulatedFees` (r:1 w:1)
 	/// Proof: `Template::AccumulatedFees` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// Storage: `Uniques::Account` (r:0 w:2)
@@ -104,9 +104,9 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(7_u64))
 			.saturating_add(T::DbWeight::get().writes(9_u64))
 	}
-	/// Storage: `Template::FeePercentage` (r:0 w:1)
-	/// Proof: `Template::FeePercentage` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
-	fn set_fee_percentage() -> Weight {
+	/// Storage: `Template::MarketFee` (r:0 w:1)
+	/// Proof: `Template::MarketFee` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn set_market_fee() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `0`
 		//  Estimated: `0`
@@ -122,8 +122,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `Uniques::Class` (r:1 w:0)
 	/// Proof: `Uniques::Class` (`max_values`: None, `max_size`: Some(178), added: 2653, mode: `MaxEncodedLen`)
-	/// Storage: `Template::FeePercentage` (r:1 w:0)
-	/// Proof: `Template::FeePercentage` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Template::MarketFee` (r:1 w:0)
+	/// Proof: `Template::MarketFee` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// Storage: `Template::AccumulatedFees` (r:1 w:1)
 	/// Proof: `Template::AccumulatedFees` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// Storage: `Uniques::Account` (r:0 w:2)
@@ -187,8 +187,8 @@ impl WeightInfo for () {
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `Uniques::Class` (r:1 w:0)
 	/// Proof: `Uniques::Class` (`max_values`: None, `max_size`: Some(178), added: 2653, mode: `MaxEncodedLen`)
-	/// Storage: `Template::FeePercentage` (r:1 w:0)
-	/// Proof: `Template::FeePercentage` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Template::MarketFee` (r:1 w:0)
+	/// Proof: `Template::MarketFee` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// Storage: `Template::AccumulatedFees` (r:1 w:1)
 	/// Proof: `Template::AccumulatedFees` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// Storage: `Uniques::Account` (r:0 w:2)
@@ -208,9 +208,9 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(7_u64))
 			.saturating_add(RocksDbWeight::get().writes(9_u64))
 	}
-	/// Storage: `Template::FeePercentage` (r:0 w:1)
-	/// Proof: `Template::FeePercentage` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
-	fn set_fee_percentage() -> Weight {
+	/// Storage: `Template::MarketFee` (r:0 w:1)
+	/// Proof: `Template::MarketFee` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn set_market_fee() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `0`
 		//  Estimated: `0`
@@ -226,8 +226,8 @@ impl WeightInfo for () {
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: `Uniques::Class` (r:1 w:0)
 	/// Proof: `Uniques::Class` (`max_values`: None, `max_size`: Some(178), added: 2653, mode: `MaxEncodedLen`)
-	/// Storage: `Template::FeePercentage` (r:1 w:0)
-	/// Proof: `Template::FeePercentage` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Template::MarketFee` (r:1 w:0)
+	/// Proof: `Template::MarketFee` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// Storage: `Template::AccumulatedFees` (r:1 w:1)
 	/// Proof: `Template::AccumulatedFees` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
 	/// Storage: `Uniques::Account` (r:0 w:2)
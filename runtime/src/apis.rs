@@ -39,7 +39,7 @@ use sp_runtime::{
     ApplyExtrinsicResult,
 };
 use sp_version::RuntimeVersion;
-use crate::BlockNumber;
+use crate::{AssetId, BlockNumber};
 use crate::Template;
 
 // Local module imports
@@ -311,27 +311,39 @@ impl_runtime_apis! {
 	    AccountId,
 	    Balance,
 	    BlockNumber,
+	    AssetId,
 	> for Runtime {
 	    fn get_auction_info(
 	        collection_id: <Runtime as pallet_uniques::Config>::CollectionId,
 	        item_id: <Runtime as pallet_uniques::Config>::ItemId,
-	    ) -> Option<pallet_template_runtime_api::AuctionInfo<AccountId, Balance, BlockNumber>> {
-	        Template::auctions((collection_id, item_id)).map(|info| {
-	            pallet_template_runtime_api::AuctionInfo {
-	                owner: info.owner,
-	                start_block: info.start_block,
-	                highest_bid: info.highest_bid,
-	                highest_bidder: info.highest_bidder,
-	                ended: info.ended,
-	            }
-	        })
+	    ) -> Option<pallet_template_runtime_api::AuctionInfo<AccountId, Balance, BlockNumber, AssetId>> {
+	        Template::auctions(Template::auction_key((collection_id, item_id))).map(to_api_auction_info)
 	    }
 	
 	    fn get_bids(
 	        collection_id: <Runtime as pallet_uniques::Config>::CollectionId,
 	        item_id: <Runtime as pallet_uniques::Config>::ItemId,
 	    ) -> Vec<(AccountId, Balance)> {
-	        Template::bids((collection_id, item_id)).into_inner()
+	        Template::ranked_bids(Template::auction_key((collection_id, item_id))).into_inner()
+	    }
+
+	    fn get_top_bids(
+	        collection_id: <Runtime as pallet_uniques::Config>::CollectionId,
+	        item_id: <Runtime as pallet_uniques::Config>::ItemId,
+	        k: u32,
+	    ) -> Vec<(AccountId, Balance)> {
+	        let (collection_id, item_id) = Template::auction_key((collection_id, item_id));
+	        Template::top_bids(collection_id, item_id, k)
+	    }
+
+	    fn get_bundle_items(
+	        collection_id: <Runtime as pallet_uniques::Config>::CollectionId,
+	        item_id: <Runtime as pallet_uniques::Config>::ItemId,
+	    ) -> Vec<(
+	        <Runtime as pallet_uniques::Config>::CollectionId,
+	        <Runtime as pallet_uniques::Config>::ItemId,
+	    )> {
+	        Template::bundle_items(Template::auction_key((collection_id, item_id))).into_inner()
 	    }
 	
 	    fn is_in_auction(
@@ -341,38 +353,141 @@ impl_runtime_apis! {
 	        Template::is_in_auction((collection_id, item_id))
 	    }
 	
-	    fn get_fee_percentage() -> u8 {
-	        Template::fee_percentage()
+	    fn get_fee_percentage() -> sp_runtime::Permill {
+	        Template::market_fee()
+	    }
+
+	    fn quote_fees(
+	        collection_id: <Runtime as pallet_uniques::Config>::CollectionId,
+	        item_id: <Runtime as pallet_uniques::Config>::ItemId,
+	        amount: Balance,
+	        seller: AccountId,
+	    ) -> pallet_template_runtime_api::FeeQuote<Balance> {
+	        Template::quote_fees(&collection_id, &item_id, amount, &seller)
 	    }
 	
-	    fn get_accumulated_fees() -> Balance {
-	        Template::accumulated_fees()
+	    fn get_accumulated_fees(
+	        asset: pallet_template_runtime_api::SettlementAsset<AssetId>,
+	    ) -> Balance {
+	        Template::accumulated_fees(asset)
 	    }
 	
         fn get_active_auctions() -> Vec<(
             (<Runtime as pallet_uniques::Config>::CollectionId, <Runtime as pallet_uniques::Config>::ItemId),
-            pallet_template_runtime_api::AuctionInfo<AccountId, Balance, BlockNumber>
+            pallet_template_runtime_api::AuctionInfo<AccountId, Balance, BlockNumber, AssetId>
         )> {
             use frame_support::storage::IterableStorageMap;
             
             pallet_template::Auctions::<Runtime>::iter()
                 .filter_map(|(key, auction_info)| {
                     if !auction_info.ended {
-                        Some((
-                            key,
-                            pallet_template_runtime_api::AuctionInfo {
-                                owner: auction_info.owner,
-                                start_block: auction_info.start_block,
-                                highest_bid: auction_info.highest_bid,
-                                highest_bidder: auction_info.highest_bidder,
-                                ended: auction_info.ended,
-                            }
-                        ))
+                        Some((key, to_api_auction_info(auction_info)))
                     } else {
                         None
                     }
                 })
                 .collect()
         }
+
+        fn get_min_next_bid(
+            collection_id: <Runtime as pallet_uniques::Config>::CollectionId,
+            item_id: <Runtime as pallet_uniques::Config>::ItemId,
+        ) -> Option<Balance> {
+            Template::auctions(Template::auction_key((collection_id, item_id)))
+                .filter(|info| !info.ended)
+                .map(|info| Template::min_next_bid(&info))
+        }
+
+        fn get_listing(
+            collection_id: <Runtime as pallet_uniques::Config>::CollectionId,
+            item_id: <Runtime as pallet_uniques::Config>::ItemId,
+        ) -> Option<pallet_template_runtime_api::ListingInfo<AccountId, Balance, AssetId>> {
+            Template::listings((collection_id, item_id))
+        }
+
+        fn get_active_listings() -> Vec<(
+            (<Runtime as pallet_uniques::Config>::CollectionId, <Runtime as pallet_uniques::Config>::ItemId),
+            pallet_template_runtime_api::ListingInfo<AccountId, Balance, AssetId>
+        )> {
+            pallet_template::Listings::<Runtime>::iter().collect()
+        }
+
+        fn get_offers(
+            collection_id: <Runtime as pallet_uniques::Config>::CollectionId,
+            item_id: <Runtime as pallet_uniques::Config>::ItemId,
+        ) -> Vec<(AccountId, pallet_template_runtime_api::OfferInfo<Balance, BlockNumber>)> {
+            pallet_template::Offers::<Runtime>::iter_prefix((collection_id, item_id)).collect()
+        }
+
+        fn pending_refunds(
+            account: AccountId,
+        ) -> Vec<(
+            (<Runtime as pallet_uniques::Config>::CollectionId, <Runtime as pallet_uniques::Config>::ItemId),
+            pallet_template_runtime_api::SettlementAsset<AssetId>,
+            Balance,
+        )> {
+            Template::pending_refunds(&account)
+        }
+
+        fn get_sales_history(
+            collection_id: <Runtime as pallet_uniques::Config>::CollectionId,
+            item_id: <Runtime as pallet_uniques::Config>::ItemId,
+        ) -> Vec<pallet_template_runtime_api::SaleRecord<AccountId, Balance, BlockNumber, AssetId>> {
+            Template::sales_history((collection_id, item_id)).into_inner()
+        }
+
+        fn get_collection_summary(
+            collection_id: <Runtime as pallet_uniques::Config>::CollectionId,
+            asset: pallet_template_runtime_api::SettlementAsset<AssetId>,
+        ) -> pallet_template_runtime_api::CollectionSummary<Balance> {
+            Template::collection_summary(collection_id, asset)
+        }
+
+        fn get_account_stats(
+            account: AccountId,
+            asset: pallet_template_runtime_api::SettlementAsset<AssetId>,
+        ) -> pallet_template_runtime_api::AccountStats<Balance> {
+            Template::account_stats(account, asset)
+        }
+
+        fn collection_stats(
+            collection_id: <Runtime as pallet_uniques::Config>::CollectionId,
+            window: BlockNumber,
+        ) -> Vec<(
+            pallet_template_runtime_api::SettlementAsset<AssetId>,
+            pallet_template_runtime_api::CollectionStats<Balance>,
+        )> {
+            Template::collection_stats(collection_id, window)
+        }
 	}
 }
+
+/// Converts the pallet's auction record into the runtime API representation,
+/// hiding the reserve price behind a met / not met flag.
+fn to_api_auction_info(
+    info: pallet_template::AuctionInfo<AccountId, Balance, BlockNumber, AssetId>,
+) -> pallet_template_runtime_api::AuctionInfo<AccountId, Balance, BlockNumber, AssetId> {
+    let reserve_met = info
+        .reserve_price
+        .map(|_| Template::meets_reserve(&info, info.highest_bid));
+
+    let remaining_blocks = Template::remaining_blocks(&info);
+    let current_price = Template::current_price(&info);
+
+    pallet_template_runtime_api::AuctionInfo {
+        owner: info.owner,
+        start_block: info.start_block,
+        end_block: info.end_block,
+        remaining_blocks,
+        highest_bid: info.highest_bid,
+        highest_bidder: info.highest_bidder,
+        ended: info.ended,
+        reserve_met,
+        min_increment: info.min_increment,
+        extended_by: info.extended_by,
+        buy_now_price: info.buy_now_price,
+        kind: info.kind,
+        current_price,
+        asset: info.asset,
+    }
+}
@@ -31,4 +31,9 @@ frame_benchmarking::define_benchmarks!(
 	[pallet_timestamp, Timestamp]
 	[pallet_sudo, Sudo]
 	[pallet_template, Template]
+	[pallet_collective, Council]
+	[pallet_treasury, Treasury]
+	[pallet_democracy, Democracy]
+	[pallet_scheduler, Scheduler]
+	[pallet_preimage, Preimage]
 );
@@ -26,31 +26,36 @@
 // Substrate and Polkadot dependencies
 use frame_support::{
 	derive_impl, parameter_types,
-	traits::{AsEnsureOriginWithArg, ConstBool, ConstU128, ConstU32, ConstU64, ConstU8, VariantCountOf, Everything, InstanceFilter},
+	traits::{AsEnsureOriginWithArg, ConstBool, ConstU128, ConstU32, ConstU64, ConstU8, Contains, VariantCountOf, Everything, InstanceFilter},
 	weights::{
 		constants::{RocksDbWeight, WEIGHT_REF_TIME_PER_SECOND}, Weight,
 	},
 };
-use frame_system::{limits::{BlockLength, BlockWeights}, EnsureRoot, EnsureSigned};
+use frame_system::{limits::{BlockLength, BlockWeights}, EnsureRoot, EnsureSigned, EnsureWithSuccess};
 use pallet_transaction_payment::{ConstFeeMultiplier, Multiplier};
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
-use sp_runtime::{generic, Perbill, SaturatedConversion, RuntimeDebug, traits::BlakeTwo256, MultiSigner, MultiSignature };
+use sp_runtime::{generic, Perbill, Permill, SaturatedConversion, RuntimeDebug, traits::{BlakeTwo256, IdentityLookup}, MultiSigner, MultiSignature };
 use sp_version::RuntimeVersion;
 use sp_core::sr25519::Signature;
 use frame_support::PalletId;
-use frame_support::traits::{Currency, OnUnbalanced, Imbalance};
+use frame_support::traits::{
+    fungible::{self, HoldConsideration, NativeFromLeft, NativeOrWithId, UnionOf},
+    tokens::{pay::PayAssetFromAccount, UnityAssetBalanceConversion},
+    EitherOfDiverse, EqualPrivilegeOnly, LinearStoragePrice, OnUnbalanced, Imbalance,
+};
 use frame_support::weights::ConstantMultiplier;
 use pallet_identity::legacy::IdentityInfo;
-use pallet_transaction_payment::CurrencyAdapter;
+use pallet_transaction_payment::FungibleAdapter;
+use sp_runtime::traits::AccountIdConversion;
 use codec::{Encode, Decode, MaxEncodedLen};
 use crate::Timestamp;
 
 use crate::UncheckedExtrinsic;
 
 use super::{
-	AccountId, Aura, Balance, Balances, Block, BlockNumber, Hash, Nonce, PalletInfo, Runtime,
+	AccountId, AssetId, Assets, Aura, Balance, Balances, Block, BlockNumber, Hash, Nonce, PalletInfo, Runtime,
 	RuntimeCall, RuntimeEvent, RuntimeFreezeReason, RuntimeHoldReason, RuntimeOrigin, RuntimeTask,
-	System, EXISTENTIAL_DEPOSIT, SLOT_DURATION, VERSION, MILLI_UNIT
+	System, Template, Treasury, Preimage, Scheduler, OriginCaller, DAYS, EXISTENTIAL_DEPOSIT, HOURS, MINUTES, SLOT_DURATION, VERSION, UNIT, MILLI_UNIT
 };
 
 const NORMAL_DISPATCH_RATIO: Perbill = Perbill::from_percent(75);
@@ -149,32 +154,25 @@ parameter_types! {
 	pub FeeMultiplier: Multiplier = Multiplier::from_rational(0u128, 1u128); 
 }
 
-// Define your target wallet
-parameter_types! {
-    pub FeeRecipient: AccountId = AccountId::from([
-        // Replace with your actual account bytes
-        1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16,
-        17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32
-    ]);
-}
-
-pub struct FeeToWallet;
-impl OnUnbalanced<pallet_balances::NegativeImbalance<Runtime>> for FeeToWallet {
-    fn on_nonzero_unbalanced(amount: pallet_balances::NegativeImbalance<Runtime>) {
-        let recipient = FeeRecipient::get();
+/// Pays transaction fees and tips into the marketplace account, where they are distributed
+/// with the marketplace fees
+pub struct FeesToMarketplace;
+impl OnUnbalanced<fungible::Credit<AccountId, Balances>> for FeesToMarketplace {
+    fn on_nonzero_unbalanced(amount: fungible::Credit<AccountId, Balances>) {
         let fee_value = amount.peek();
-        
-        // Simply drop the negative imbalance (this burns the tokens from the fee payer)
-        drop(amount);
-        
-        // Mint the same amount to the recipient
-        let _ = <Balances as Currency<AccountId>>::deposit_creating(&recipient, fee_value);
+
+        // A fee the account cannot take is burned
+        if <Balances as fungible::Balanced<AccountId>>::resolve(&Template::account_id(), amount)
+            .is_ok()
+        {
+            Template::note_fees(pallet_template_runtime_api::SettlementAsset::Native, fee_value);
+        }
     }
 }
 
 impl pallet_transaction_payment::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
-	type OnChargeTransaction = CurrencyAdapter<Balances, ()>;
+	type OnChargeTransaction = FungibleAdapter<Balances, FeesToMarketplace>;
 	type OperationalFeeMultiplier = ConstU8<5>;
 	type WeightToFee = ConstantMultiplier<Balance, ConstU128<0>>;
 	type LengthToFee =  ConstantMultiplier<Balance, ConstU128<0>>;
@@ -280,12 +278,24 @@ impl pallet_uniques::Config for Runtime {
 
     type WeightInfo = pallet_uniques::weights::SubstrateWeight<Runtime>;
 
+    #[cfg(feature = "runtime-benchmarks")]
     type Helper = ();
 }
 
 parameter_types! {
-    pub const RoyaltyPercentage: u8 = 10; // 10% royalty
     pub const TemplatePalletId: PalletId = PalletId(*b"ex/auctn");
+    pub TreasuryAccount: AccountId = Treasury::account_id();
+    pub RewardsAccount: AccountId = PalletId(*b"ex/rewrd").into_account_truncating();
+    pub const CancellationPenaltyRecipient: pallet_template::PenaltyRecipient =
+        pallet_template::PenaltyRecipient::TopBidder;
+}
+
+/// Accounts with a profile in the profiles pallet, which auctions can be gated on
+pub struct ProfileHolders;
+impl Contains<AccountId> for ProfileHolders {
+    fn contains(who: &AccountId) -> bool {
+        profiles::Profiles::<Runtime>::contains_key(who)
+    }
 }
 
 /// Configure the pallet-template in pallets/template.
@@ -294,6 +304,11 @@ impl pallet_template::Config for Runtime {
 	
 	// Use the Balances pallet as the Currency implementation
 	type Currency = Balances;
+	type RuntimeHoldReason = RuntimeHoldReason;
+
+	// Auctions and listings can also settle in any asset of the Assets pallet
+	type AssetId = u32;
+	type Assets = Assets;
 	
 	// Set maximum number of bids per auction
 	type MaxBidsPerAuction = ConstU32<100>;
@@ -301,13 +316,73 @@ impl pallet_template::Config for Runtime {
 	// Set number of blocks after which auction auto-resolves
 	type AuctionTimeoutBlocks = ConstU32<100>; // 100 blocks as per your requirement
 
-	type RoyaltyPercentage = RoyaltyPercentage;
+	// Bounds for seller-chosen auction durations
+	type MinAuctionDuration = ConstU32<10>;
+	type MaxAuctionDuration = ConstU32<{ 30 * DAYS }>;
+
+	// Bids in the last minute extend the auction by a minute, for at most an hour in total
+	type SoftCloseWindow = ConstU32<{ MINUTES }>;
+	type SoftCloseExtension = ConstU32<{ MINUTES }>;
+	type MaxSoftCloseExtension = ConstU32<{ HOURS }>;
+
+	// Buy-now goes away once a bid passes 80% of the buy-now price
+	type BuyNowBidThreshold = ConstU8<80>;
+
+	// Sealed bids are revealed during the last ten minutes of the auction
+	type SealedBidRevealPeriod = ConstU32<{ 10 * MINUTES }>;
+
+	// Cancelling an auction with bids costs the seller 5% of the top bid, paid to the top bidder
+	type CancellationPenalty = ConstU8<5>;
+	type CancellationPenaltyRecipient = CancellationPenaltyRecipient;
+
+	// Outbid bids can be retracted once they are ten minutes old
+	type BidRetractionCooldown = ConstU32<{ 10 * MINUTES }>;
+
+	// A collection's royalty can be split between up to five accounts
+	type MaxRoyaltyRecipients = ConstU32<5>;
 
     type PalletId = TemplatePalletId;
 
     type WeightInfo = pallet_template::weights::SubstrateWeight<Runtime>;    
 
     type MaxBatchListingSize = ConstU32<10>;
+
+    // Expiry schedule bounds: auctions ending per block and how many are settled per block
+    type MaxAuctionsPerBlock = ConstU32<100>;
+    type MaxResolutionsPerBlock = ConstU32<20>;
+
+    // A bidder claims back at most fifty bids per call
+    type MaxRefundsPerClaim = ConstU32<50>;
+
+    // The last twenty auction sales of each NFT are kept on-chain
+    type MaxSalesHistory = ConstU32<20>;
+
+    // Collection sales are aggregated hourly for a week, sampling up to 32 prices an hour
+    type StatsBucketLength = ConstU32<HOURS>;
+    type MaxStatsBuckets = ConstU32<{ 7 * 24 }>;
+    type MaxPricesPerBucket = ConstU32<32>;
+
+    // Auction proceeds stay in escrow for a day, disputes are resolved by root or the council
+    type EscrowPeriod = ConstU32<DAYS>;
+    type DisputeOrigin = RootOrCouncilMajority;
+
+    // A bundle auction sells up to twenty NFTs as one lot
+    type MaxBundleSize = ConstU32<20>;
+
+    // Auctions can be gated on having a profile, or on an allowlist of up to a hundred accounts
+    type ProfileHolders = ProfileHolders;
+    type MaxAllowlistLen = ConstU32<100>;
+
+    // Marketplace parameters, fees and maximum royalty are set by root or the council, with up
+    // to ten volume tiers of seller fees per settlement asset
+    type AdminOrigin = RootOrCouncilMajority;
+    type MaxFeeTiers = ConstU32<10>;
+
+    // Withdrawn and distributed fees go to the treasury, distributed fees also to a rewards pot
+    type TreasuryAccount = TreasuryAccount;
+    type RewardsAccount = RewardsAccount;
+    // Fees of at most ten settlement assets are distributed per block
+    type MaxFeeAssetsPerDistribution = ConstU32<10>;
 }
 
 
@@ -542,6 +617,7 @@ impl hardware_info::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type HardwareInfoInterval = HardwareInfoInterval;
     type MaxHardwareHistoryEntries = MaxHardwareHistoryEntries;
+    type AdminOrigin = RootOrCouncilMajority;
     type PalletId = HardwarePalletId;
     type WeightInfo = hardware_info::weights::SubstrateWeight<Runtime>;
 }
@@ -566,12 +642,12 @@ impl pallet_identity::Config for Runtime {
 	type SubAccountDeposit = SubAccountDeposit;
 	type MaxSubAccounts = MaxSubAccounts;
 	type MaxRegistrars = MaxRegistrars;
-	type Slashed = (); //Treasury;
-	type ForceOrigin = EnsureRoot<AccountId>;
-	type RegistrarOrigin = EnsureRoot<AccountId>;
+	type Slashed = Treasury;
+	type ForceOrigin = RootOrCouncilMajority;
+	type RegistrarOrigin = RootOrCouncilMajority;
 	type OffchainSignature = MultiSignature;
 	type SigningPublicKey = MultiSigner;
-	type UsernameAuthorityOrigin = EnsureRoot<AccountId>;
+	type UsernameAuthorityOrigin = RootOrCouncilMajority;
 	type PendingUsernameExpiration = ConstU32<{ 7 * (((60_000/1000) * 60) * 24) }>;
 	type MaxSuffixLength = ConstU32<7>;
 	type MaxUsernameLength = ConstU32<32>;
@@ -589,4 +665,176 @@ impl profiles::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type TimeProvider = Timestamp;
     type MaxUsernameLength = MaxProfileUsernameLength;
-}
\ No newline at end of file
+}
+
+parameter_types! {
+    pub const CouncilMotionDuration: BlockNumber = 3 * DAYS;
+    pub const CouncilMaxProposals: u32 = 100;
+    pub const CouncilMaxMembers: u32 = 100;
+    pub MaxProposalWeight: Weight = Perbill::from_percent(50) * RuntimeBlockWeights::get().max_block;
+}
+
+/// The council, whose motions approve treasury spends
+pub type CouncilCollective = pallet_collective::Instance1;
+
+impl pallet_collective::Config<CouncilCollective> for Runtime {
+    type RuntimeOrigin = RuntimeOrigin;
+    type Proposal = RuntimeCall;
+    type RuntimeEvent = RuntimeEvent;
+    type MotionDuration = CouncilMotionDuration;
+    type MaxProposals = CouncilMaxProposals;
+    type MaxMembers = CouncilMaxMembers;
+    type DefaultVote = pallet_collective::PrimeDefaultVote;
+    type WeightInfo = pallet_collective::weights::SubstrateWeight<Runtime>;
+    type SetMembersOrigin = EnsureRoot<AccountId>;
+    type MaxProposalWeight = MaxProposalWeight;
+    type DisapproveOrigin = EnsureRoot<AccountId>;
+    type KillOrigin = EnsureRoot<AccountId>;
+    type Consideration = ();
+}
+
+/// A majority of the council
+pub type CouncilMajority = pallet_collective::EnsureProportionMoreThan<AccountId, CouncilCollective, 1, 2>;
+
+/// Origin of the administrative calls: root, or a majority of the council. Root comes from
+/// sudo until its key is removed, and from democracy referenda after
+pub type RootOrCouncilMajority = EitherOfDiverse<EnsureRoot<AccountId>, CouncilMajority>;
+
+/// Native currency and assets, which the treasury can spend alike
+pub type NativeAndAssets =
+    UnionOf<Balances, Assets, NativeFromLeft, NativeOrWithId<AssetId>, AccountId>;
+
+parameter_types! {
+    pub const TreasuryPalletId: PalletId = PalletId(*b"py/trsry");
+    pub const SpendPeriod: BlockNumber = DAYS;
+    pub const TreasuryBurn: Permill = Permill::zero();
+    pub const MaxApprovals: u32 = 100;
+    pub const PayoutPeriod: BlockNumber = 30 * DAYS;
+    pub const MaxSpend: Balance = 100_000 * UNIT;
+}
+
+impl pallet_treasury::Config for Runtime {
+    type Currency = Balances;
+    type RejectOrigin = RootOrCouncilMajority;
+    type RuntimeEvent = RuntimeEvent;
+    type SpendPeriod = SpendPeriod;
+    // Burning is left to the fee distribution policy of the marketplace
+    type Burn = TreasuryBurn;
+    type PalletId = TreasuryPalletId;
+    type BurnDestination = ();
+    type WeightInfo = pallet_treasury::weights::SubstrateWeight<Runtime>;
+    type SpendFunds = ();
+    type MaxApprovals = MaxApprovals;
+    // A council motion spends at most 100k units at once, assets count one for one against it
+    type SpendOrigin = EnsureWithSuccess<CouncilMajority, AccountId, MaxSpend>;
+    type AssetKind = NativeOrWithId<AssetId>;
+    type Beneficiary = AccountId;
+    type BeneficiaryLookup = IdentityLookup<AccountId>;
+    type Paymaster = PayAssetFromAccount<NativeAndAssets, pallet_treasury::TreasuryAccountId<Runtime>>;
+    type BalanceConverter = UnityAssetBalanceConversion;
+    type PayoutPeriod = PayoutPeriod;
+    type BlockNumberProvider = System;
+    #[cfg(feature = "runtime-benchmarks")]
+    type BenchmarkHelper = TreasuryArguments;
+}
+
+/// Spend arguments for the treasury benchmarks
+#[cfg(feature = "runtime-benchmarks")]
+pub struct TreasuryArguments;
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_treasury::ArgumentsFactory<NativeOrWithId<AssetId>, AccountId> for TreasuryArguments {
+    fn create_asset_kind(seed: u32) -> NativeOrWithId<AssetId> {
+        NativeOrWithId::WithId(seed)
+    }
+
+    fn create_beneficiary(seed: [u8; 32]) -> AccountId {
+        AccountId::from(seed)
+    }
+}
+
+parameter_types! {
+    pub MaximumSchedulerWeight: Weight = Perbill::from_percent(80) * RuntimeBlockWeights::get().max_block;
+    pub const PreimageBaseDeposit: Balance = UNIT;
+    pub const PreimageByteDeposit: Balance = MILLI_UNIT;
+    pub const PreimageHoldReason: RuntimeHoldReason =
+        RuntimeHoldReason::Preimage(pallet_preimage::HoldReason::Preimage);
+}
+
+impl pallet_preimage::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type WeightInfo = pallet_preimage::weights::SubstrateWeight<Runtime>;
+    type Currency = Balances;
+    type ManagerOrigin = EnsureRoot<AccountId>;
+    type Consideration = HoldConsideration<
+        AccountId,
+        Balances,
+        PreimageHoldReason,
+        LinearStoragePrice<PreimageBaseDeposit, PreimageByteDeposit, Balance>,
+    >;
+}
+
+impl pallet_scheduler::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type RuntimeOrigin = RuntimeOrigin;
+    type PalletsOrigin = OriginCaller;
+    type RuntimeCall = RuntimeCall;
+    type MaximumWeight = MaximumSchedulerWeight;
+    type ScheduleOrigin = EnsureRoot<AccountId>;
+    type OriginPrivilegeCmp = EqualPrivilegeOnly;
+    type MaxScheduledPerBlock = ConstU32<50>;
+    type WeightInfo = pallet_scheduler::weights::SubstrateWeight<Runtime>;
+    type Preimages = Preimage;
+    type BlockNumberProvider = System;
+}
+
+parameter_types! {
+    pub const LaunchPeriod: BlockNumber = 7 * DAYS;
+    pub const VotingPeriod: BlockNumber = 7 * DAYS;
+    pub const FastTrackVotingPeriod: BlockNumber = 3 * HOURS;
+    pub const EnactmentPeriod: BlockNumber = DAYS;
+    pub const CooloffPeriod: BlockNumber = 7 * DAYS;
+    pub const MinimumDeposit: Balance = 100 * UNIT;
+}
+
+// Referenda dispatch as root, which lets governance do everything sudo does. The council puts
+// external proposals to a vote, fast-tracks and cancels referenda, and any member can veto an
+// external proposal once
+impl pallet_democracy::Config for Runtime {
+    type WeightInfo = pallet_democracy::weights::SubstrateWeight<Runtime>;
+    type RuntimeEvent = RuntimeEvent;
+    type Scheduler = Scheduler;
+    type Preimages = Preimage;
+    type Currency = Balances;
+    type EnactmentPeriod = EnactmentPeriod;
+    type LaunchPeriod = LaunchPeriod;
+    type VotingPeriod = VotingPeriod;
+    type VoteLockingPeriod = EnactmentPeriod;
+    type MinimumDeposit = MinimumDeposit;
+    type InstantAllowed = ConstBool<true>;
+    type FastTrackVotingPeriod = FastTrackVotingPeriod;
+    type CooloffPeriod = CooloffPeriod;
+    type MaxVotes = ConstU32<100>;
+    type MaxProposals = ConstU32<100>;
+    type MaxDeposits = ConstU32<100>;
+    type MaxBlacklisted = ConstU32<100>;
+    type ExternalOrigin = pallet_collective::EnsureProportionAtLeast<AccountId, CouncilCollective, 1, 2>;
+    type ExternalMajorityOrigin =
+        pallet_collective::EnsureProportionAtLeast<AccountId, CouncilCollective, 3, 4>;
+    type ExternalDefaultOrigin =
+        pallet_collective::EnsureProportionAtLeast<AccountId, CouncilCollective, 1, 1>;
+    type SubmitOrigin = EnsureSigned<AccountId>;
+    type FastTrackOrigin =
+        pallet_collective::EnsureProportionAtLeast<AccountId, CouncilCollective, 2, 3>;
+    type InstantOrigin =
+        pallet_collective::EnsureProportionAtLeast<AccountId, CouncilCollective, 1, 1>;
+    type CancellationOrigin =
+        pallet_collective::EnsureProportionAtLeast<AccountId, CouncilCollective, 2, 3>;
+    type BlacklistOrigin = EnsureRoot<AccountId>;
+    type CancelProposalOrigin = EitherOfDiverse<
+        EnsureRoot<AccountId>,
+        pallet_collective::EnsureProportionAtLeast<AccountId, CouncilCollective, 1, 1>,
+    >;
+    type VetoOrigin = pallet_collective::EnsureMember<AccountId, CouncilCollective>;
+    type PalletsOrigin = OriginCaller;
+    type Slash = Treasury;
+}
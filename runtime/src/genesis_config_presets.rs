@@ -15,7 +15,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use crate::{AccountId, BalancesConfig, RuntimeGenesisConfig, SudoConfig};
+use crate::{AccountId, BalancesConfig, CouncilConfig, RuntimeGenesisConfig, SudoConfig, TemplateConfig};
 use alloc::{vec, vec::Vec};
 use frame_support::build_struct_json_patch;
 use serde_json::Value;
@@ -23,6 +23,7 @@ use sp_consensus_aura::sr25519::AuthorityId as AuraId;
 use sp_consensus_grandpa::AuthorityId as GrandpaId;
 use sp_genesis_builder::{self, PresetId};
 use sp_keyring::Sr25519Keyring;
+use sp_runtime::{Perbill, Permill};
 
 // Returns the genesis config presets populated with given parameters.
 fn testnet_genesis(
@@ -45,6 +46,16 @@ fn testnet_genesis(
 			authorities: initial_authorities.iter().map(|x| (x.1.clone(), 1)).collect::<Vec<_>>(),
 		},
 		sudo: SudoConfig { key: Some(root) },
+		council: CouncilConfig {
+			members: endowed_accounts.iter().take(2).cloned().collect::<Vec<_>>(),
+		},
+		template: TemplateConfig {
+			max_bids_per_auction: 100,
+			auction_timeout: 100,
+			max_batch_listing_size: 10,
+			market_fee: Permill::zero(),
+			max_royalty: Perbill::from_percent(10),
+		},
 	})
 }
 
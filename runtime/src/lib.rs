@@ -73,10 +73,10 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	//   `spec_version`, and `authoring_version` are the same between Wasm and native.
 	// This value is set to 100 to notify Polkadot-JS App (https://polkadot.js.org/apps) to use
 	//   the compatible custom types.
-	spec_version: 102,
+	spec_version: 103,
 	impl_version: 1,
 	apis: apis::RUNTIME_API_VERSIONS,
-	transaction_version: 1,
+	transaction_version: 2,
 	system_version: 1,
 };
 
@@ -138,6 +138,7 @@ pub type BlockNumber = u32;
 // Make sure these types are exported from your runtime
 pub type CollectionId = <Runtime as pallet_uniques::Config>::CollectionId;
 pub type ItemId = <Runtime as pallet_uniques::Config>::ItemId;
+pub type AssetId = <Runtime as pallet_assets::Config>::AssetId;
 
 /// The address format for describing accounts.
 pub type Address = MultiAddress<AccountId, ()>;
@@ -179,7 +180,18 @@ pub type BlockId = generic::BlockId<Block>;
 ///
 /// This can be a tuple of types, each implementing `OnRuntimeUpgrade`.
 #[allow(unused_parens)]
-type Migrations = (pallet_example_offchain_worker::migrations::MigrateToV2<Runtime>, pallet_template::migrations::MigrateToV2<Runtime>);
+type Migrations = (pallet_example_offchain_worker::migrations::MigrateToV2<Runtime>, pallet_template::migrations::MigrateToV2<Runtime>, pallet_template::migrations::MigrateToV3<Runtime, Balances>);
+
+frame_support::parameter_types! {
+	pub const SudoPalletName: &'static str = "Sudo";
+}
+
+/// Clears the storage of the sudo pallet. Once governance has taken over and `Sudo::remove_key`
+/// was called, add it to `Migrations` in the upgrade that drops `Sudo` from the runtime.
+pub type RemoveSudo = frame_support::migrations::RemovePallet<
+	SudoPalletName,
+	<Runtime as frame_system::Config>::DbWeight,
+>;
 
 /// Executive: handles dispatch to the various modules.
 pub type Executive = frame_executive::Executive<
@@ -257,4 +269,19 @@ mod runtime {
 
 	#[runtime::pallet_index(15)]
 	pub type Profiles = profiles;
+
+	#[runtime::pallet_index(16)]
+	pub type Council = pallet_collective<Instance1>;
+
+	#[runtime::pallet_index(17)]
+	pub type Treasury = pallet_treasury;
+
+	#[runtime::pallet_index(18)]
+	pub type Preimage = pallet_preimage;
+
+	#[runtime::pallet_index(19)]
+	pub type Scheduler = pallet_scheduler;
+
+	#[runtime::pallet_index(20)]
+	pub type Democracy = pallet_democracy;
 }
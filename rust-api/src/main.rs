@@ -59,6 +59,97 @@ pub struct BatchRequest {
 pub struct ListNftRequest {
     pub collection_id: u32,
     pub item_id: u32,
+    pub reserve_price: Option<u128>,
+    pub min_increment: Option<BidIncrement>,
+    pub duration: Option<u32>,
+    pub buy_now_price: Option<u128>,
+    /// Asset to settle in, the native currency when omitted
+    pub asset_id: Option<u32>,
+    /// Who may bid, anyone when omitted
+    pub access: Option<BidderAccess>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ListBundleRequest {
+    /// NFTs of one collection sold as a single lot, keyed by the first
+    pub items: Vec<(u32, u32)>,
+    pub reserve_price: Option<u128>,
+    pub min_increment: Option<BidIncrement>,
+    pub duration: Option<u32>,
+    pub buy_now_price: Option<u128>,
+    /// Asset to settle in, the native currency when omitted
+    pub asset_id: Option<u32>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ListNftForDutchAuctionRequest {
+    pub collection_id: u32,
+    pub item_id: u32,
+    pub start_price: u128,
+    pub floor_price: u128,
+    pub decay_per_block: u128,
+    pub duration: Option<u32>,
+    /// Asset to settle in, the native currency when omitted
+    pub asset_id: Option<u32>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub enum BidIncrement {
+    Absolute(u128),
+    Percentage(u8),
+}
+
+#[derive(Serialize, Deserialize)]
+pub enum BidderAccess {
+    /// Only the listed accounts, as SS58 addresses
+    Allowlist(Vec<String>),
+    /// Only accounts owning an NFT of the collection
+    CollectionHolders(u32),
+    /// Only accounts that have a profile
+    ProfileHolders,
+}
+
+#[derive(Deserialize)]
+pub struct AssetQuery {
+    /// Asset the fees were collected in, the native currency when omitted
+    pub asset_id: Option<u32>,
+}
+
+fn settlement_asset(
+    asset_id: Option<u32>,
+) -> polkadot::runtime_types::primitives::SettlementAsset<u32> {
+    match asset_id {
+        Some(id) => polkadot::runtime_types::primitives::SettlementAsset::Asset(id),
+        None => polkadot::runtime_types::primitives::SettlementAsset::Native,
+    }
+}
+
+fn bidder_access(
+    access: BidderAccess,
+) -> Result<polkadot::runtime_types::primitives::BidderAccess<AccountId32, u32>, String> {
+    use polkadot::runtime_types::primitives::BidderAccess as Access;
+    Ok(match access {
+        BidderAccess::Allowlist(accounts) => Access::Allowlist(
+            polkadot::runtime_types::bounded_collections::bounded_vec::BoundedVec(
+                accounts
+                    .iter()
+                    .map(|account| AccountId32::from_str(account))
+                    .collect::<Result<Vec<_>, _>>()
+                    .map_err(|_| "Invalid account address".to_string())?,
+            ),
+        ),
+        BidderAccess::CollectionHolders(collection_id) => Access::CollectionHolders(collection_id),
+        BidderAccess::ProfileHolders => Access::ProfileHolders,
+    })
+}
+
+impl From<BidIncrement> for polkadot::runtime_types::primitives::BidIncrement<u128> {
+    fn from(increment: BidIncrement) -> Self {
+        match increment {
+            BidIncrement::Absolute(amount) => Self::Absolute(amount),
+            BidIncrement::Percentage(percent) => Self::Percentage(percent),
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize)]
@@ -68,6 +159,106 @@ pub struct PlaceBidRequest {
     pub bid_amount: u128,
 }
 
+#[derive(Serialize, Deserialize)]
+pub struct MarketParamsRequest {
+    pub max_bids_per_auction: u32,
+    /// Blocks an auction runs when the seller does not choose a duration
+    pub auction_timeout: u32,
+    pub max_batch_listing_size: u32,
+}
+
+/// Shares of the distributed fees in parts per million, adding up to 1000000. Omitting the
+/// shares removes the policy
+#[derive(Serialize, Deserialize)]
+pub struct FeeDistributionRequest {
+    pub shares: Option<FeeShares>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct FeeShares {
+    pub treasury: u32,
+    pub burn: u32,
+    pub rewards: u32,
+    /// Blocks between distributions
+    pub period: u32,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ProposeSpendRequest {
+    /// Asset to spend, the native currency when omitted
+    pub asset_id: Option<u32>,
+    pub amount: u128,
+    pub beneficiary: String,
+    /// Number of council members whose approval passes the motion
+    pub threshold: u32,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ProposeMotionRequest {
+    /// Hex-encoded call, as built by the `auction_*` RPC methods
+    pub call: String,
+    /// Number of council members whose approval passes the motion
+    pub threshold: u32,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct CouncilVoteRequest {
+    pub proposal_hash: String,
+    pub index: u32,
+    pub approve: bool,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct CloseMotionRequest {
+    pub proposal_hash: String,
+    pub index: u32,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct PlaceMaxBidRequest {
+    pub collection_id: u32,
+    pub item_id: u32,
+    pub max_amount: u128,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ListNftForSaleRequest {
+    pub collection_id: u32,
+    pub item_id: u32,
+    pub price: u128,
+    /// Asset to settle in, the native currency when omitted
+    pub asset_id: Option<u32>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct MakeOfferRequest {
+    pub collection_id: u32,
+    pub item_id: u32,
+    pub amount: u128,
+    pub expires_at: u32,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct AcceptOfferRequest {
+    pub collection_id: u32,
+    pub item_id: u32,
+    pub buyer: String,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct RoyaltyRecipient {
+    pub account: String,
+    pub weight: u32,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct SetCollectionRoyaltyRequest {
+    pub collection_id: u32,
+    /// Royalty rate in parts per billion of the sale price
+    pub rate_ppb: u32,
+    pub recipients: Vec<RoyaltyRecipient>,
+}
+
 #[derive(Serialize, Deserialize)]
 pub struct AuctionResponse {
     pub tx_hash: String,
@@ -77,6 +268,8 @@ pub struct AuctionResponse {
 pub struct AuctionInfo {
     pub owner: String,
     pub start_block: u64,
+    pub end_block: u64,
+    pub remaining_blocks: u64,
     pub highest_bid: u128,
     pub highest_bidder: Option<String>,
     pub ended: bool,
@@ -607,10 +800,26 @@ pub async fn list_nft_for_auction(
         }
     }
 
+    let access = payload
+        .access
+        .map(bidder_access)
+        .transpose()
+        .map_err(|error| (StatusCode::BAD_REQUEST, Json(ErrorResponse { error })))?;
+
     // Create the transaction
-    let list_tx = polkadot::tx()
-        .template()
-        .list_nft_for_auction(payload.collection_id, payload.item_id);
+    let list_tx = polkadot::tx().template().list_nft_for_auction(
+        payload.collection_id,
+        payload.item_id,
+        polkadot::runtime_types::primitives::AuctionSettings {
+            reserve_price: payload.reserve_price,
+            min_increment: payload.min_increment.map(Into::into),
+            duration: payload.duration,
+            buy_now_price: payload.buy_now_price,
+            access,
+            __ignore: std::marker::PhantomData,
+        },
+        settlement_asset(payload.asset_id),
+    );
 
     // Submit transaction
     let tx_progress = state
@@ -644,6 +853,106 @@ pub async fn list_nft_for_auction(
     }))
 }
 
+// List NFTs of one collection for auction as a single lot
+pub async fn list_bundle_for_auction(
+    State(state): State<AppState>,
+    Json(payload): Json<ListBundleRequest>,
+) -> Result<Json<AuctionResponse>, (StatusCode, Json<ErrorResponse>)> {
+    let list_tx = polkadot::tx().template().list_bundle_for_auction(
+        polkadot::runtime_types::bounded_collections::bounded_vec::BoundedVec(payload.items),
+        payload.reserve_price,
+        payload.min_increment.map(Into::into),
+        payload.duration,
+        payload.buy_now_price,
+        settlement_asset(payload.asset_id),
+    );
+
+    let tx_progress = state
+        .client
+        .tx()
+        .sign_and_submit_then_watch_default(&list_tx, &state.wallet_keypair)
+        .await
+        .map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: format!("Failed to submit bundle listing: {}", e),
+                }),
+            )
+        })?;
+
+    let events = tx_progress
+        .wait_for_finalized_success()
+        .await
+        .map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: format!("Bundle listing transaction failed: {}", e),
+                }),
+            )
+        })?;
+
+    Ok(Json(AuctionResponse {
+        tx_hash: format!("{:?}", events.extrinsic_hash()),
+    }))
+}
+
+// List NFT for a Dutch auction
+pub async fn list_nft_for_dutch_auction(
+    State(state): State<AppState>,
+    Json(payload): Json<ListNftForDutchAuctionRequest>,
+) -> Result<Json<AuctionResponse>, (StatusCode, Json<ErrorResponse>)> {
+    let keypair = get_keypair_from_keyring(&"alice").map_err(|e| {
+        (
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse {
+                error: e.to_string(),
+            }),
+        )
+    })?;
+
+    let list_tx = polkadot::tx().template().list_nft_for_dutch_auction(
+        payload.collection_id,
+        payload.item_id,
+        payload.start_price,
+        payload.floor_price,
+        payload.decay_per_block,
+        payload.duration,
+        settlement_asset(payload.asset_id),
+    );
+
+    let tx_progress = state
+        .client
+        .tx()
+        .sign_and_submit_then_watch_default(&list_tx, &keypair)
+        .await
+        .map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: format!("Failed to submit transaction: {}", e),
+                }),
+            )
+        })?;
+
+    let events = tx_progress
+        .wait_for_finalized_success()
+        .await
+        .map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: format!("Transaction failed: {}", e),
+                }),
+            )
+        })?;
+
+    Ok(Json(AuctionResponse {
+        tx_hash: format!("{:?}", events.extrinsic_hash()),
+    }))
+}
+
 // Place bid on auction
 pub async fn place_bid(
     State(state): State<AppState>,
@@ -695,6 +1004,57 @@ pub async fn place_bid(
     }))
 }
 
+// Place a maximum bid, bid on automatically up to the hidden maximum
+pub async fn place_max_bid(
+    State(state): State<AppState>,
+    Json(payload): Json<PlaceMaxBidRequest>,
+) -> Result<Json<AuctionResponse>, (StatusCode, Json<ErrorResponse>)> {
+    let keypair = get_keypair_from_keyring(&"alice").map_err(|e| {
+        (
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse {
+                error: e.to_string(),
+            }),
+        )
+    })?;
+
+    let bid_tx = polkadot::tx().template().place_max_bid(
+        payload.collection_id,
+        payload.item_id,
+        payload.max_amount,
+    );
+
+    let tx_progress = state
+        .client
+        .tx()
+        .sign_and_submit_then_watch_default(&bid_tx, &keypair)
+        .await
+        .map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: format!("Failed to submit max bid: {}", e),
+                }),
+            )
+        })?;
+
+    let events = tx_progress
+        .wait_for_finalized_success()
+        .await
+        .map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: format!("Max bid transaction failed: {}", e),
+                }),
+            )
+        })?;
+
+    Ok(Json(AuctionResponse {
+        tx_hash: format!("{:?}", events.extrinsic_hash()),
+    }))
+}
+
 // Resolve auction
 pub async fn resolve_auction(
     State(state): State<AppState>,
@@ -735,60 +1095,686 @@ pub async fn resolve_auction(
     }))
 }
 
-// Get auction info (query storage)
-pub async fn get_auction_info(
+// Buy NFT at its buy-now price
+pub async fn buy_now(
     State(state): State<AppState>,
     Path((collection_id, item_id)): Path<(u32, u32)>,
-) -> Result<Json<Option<AuctionInfo>>, (StatusCode, Json<ErrorResponse>)> {
-    let storage_query = polkadot::storage().template().auctions_iter();
+) -> Result<Json<AuctionResponse>, (StatusCode, Json<ErrorResponse>)> {
+    let keypair = get_keypair_from_keyring(&"alice").map_err(|e| {
+        (
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse {
+                error: e.to_string(),
+            }),
+        )
+    })?;
 
-    let mut auctions = Vec::new();
+    let buy_tx = polkadot::tx().template().buy_now(collection_id, item_id);
 
-    let mut iter = state
+    let tx_progress = state
         .client
-        .storage()
-        .at_latest()
+        .tx()
+        .sign_and_submit_then_watch_default(&buy_tx, &keypair)
         .await
         .map_err(|e| {
-            println!("[ERROR] Failed to get latest block: {:?}", e);
             (
                 StatusCode::INTERNAL_SERVER_ERROR,
                 Json(ErrorResponse {
-                    error: format!("Failed to query storage: {}", e),
+                    error: format!("Failed to submit purchase: {}", e),
                 }),
             )
-        })?
-        .iter(storage_query)
+        })?;
+
+    let events = tx_progress
+        .wait_for_finalized_success()
         .await
         .map_err(|e| {
-            println!("[ERROR] Failed to create storage iterator: {:?}", e);
             (
                 StatusCode::INTERNAL_SERVER_ERROR,
                 Json(ErrorResponse {
-                    error: format!("Failed to iterate storage: {}", e),
+                    error: format!("Purchase transaction failed: {}", e),
                 }),
             )
         })?;
 
-    let mut count = 0;
-    while let Some(result) = iter.next().await {
-        match result {
-            Ok(kv_pair) => {
-                count += 1;
-                let key_bytes = kv_pair.key_bytes;
-                let auction_info = kv_pair.value;
-
-                println!("[INFO] Processing auction #{}", count);
-                println!("[DEBUG] Raw key: 0x{}", hex::encode(key_bytes.clone()));
+    Ok(Json(AuctionResponse {
+        tx_hash: format!("{:?}", events.extrinsic_hash()),
+    }))
+}
 
-                match decode_auction_key(&key_bytes) {
-                    Ok((collection_id, item_id)) => {
-                        let auction_with_key = AuctionWithKey {
-                            collection_id,
-                            item_id,
-                            auction_info: AuctionInfo {
-                                owner: auction_info.owner.to_string(),
+pub async fn cancel_auction(
+    State(state): State<AppState>,
+    Path((collection_id, item_id)): Path<(u32, u32)>,
+) -> Result<Json<AuctionResponse>, (StatusCode, Json<ErrorResponse>)> {
+    let keypair = get_keypair_from_keyring(&"alice").map_err(|e| {
+        (
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse {
+                error: e.to_string(),
+            }),
+        )
+    })?;
+
+    let cancel_tx = polkadot::tx().template().cancel_auction(collection_id, item_id);
+
+    let tx_progress = state
+        .client
+        .tx()
+        .sign_and_submit_then_watch_default(&cancel_tx, &keypair)
+        .await
+        .map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: format!("Failed to submit cancellation: {}", e),
+                }),
+            )
+        })?;
+
+    let events = tx_progress
+        .wait_for_finalized_success()
+        .await
+        .map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: format!("Cancellation transaction failed: {}", e),
+                }),
+            )
+        })?;
+
+    Ok(Json(AuctionResponse {
+        tx_hash: format!("{:?}", events.extrinsic_hash()),
+    }))
+}
+
+pub async fn retract_bid(
+    State(state): State<AppState>,
+    Path((collection_id, item_id)): Path<(u32, u32)>,
+) -> Result<Json<AuctionResponse>, (StatusCode, Json<ErrorResponse>)> {
+    let keypair = get_keypair_from_keyring(&"alice").map_err(|e| {
+        (
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse {
+                error: e.to_string(),
+            }),
+        )
+    })?;
+
+    let retract_tx = polkadot::tx().template().retract_bid(collection_id, item_id);
+
+    let tx_progress = state
+        .client
+        .tx()
+        .sign_and_submit_then_watch_default(&retract_tx, &keypair)
+        .await
+        .map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: format!("Failed to submit retraction: {}", e),
+                }),
+            )
+        })?;
+
+    let events = tx_progress
+        .wait_for_finalized_success()
+        .await
+        .map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: format!("Retraction transaction failed: {}", e),
+                }),
+            )
+        })?;
+
+    Ok(Json(AuctionResponse {
+        tx_hash: format!("{:?}", events.extrinsic_hash()),
+    }))
+}
+
+// Claim back a bid that no longer competes
+pub async fn claim_refund(
+    State(state): State<AppState>,
+    Path((collection_id, item_id)): Path<(u32, u32)>,
+) -> Result<Json<AuctionResponse>, (StatusCode, Json<ErrorResponse>)> {
+    let keypair = get_keypair_from_keyring(&"alice").map_err(|e| {
+        (
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse {
+                error: e.to_string(),
+            }),
+        )
+    })?;
+
+    let claim_tx = polkadot::tx().template().claim_refund(collection_id, item_id);
+
+    let tx_progress = state
+        .client
+        .tx()
+        .sign_and_submit_then_watch_default(&claim_tx, &keypair)
+        .await
+        .map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: format!("Failed to submit refund claim: {}", e),
+                }),
+            )
+        })?;
+
+    let events = tx_progress
+        .wait_for_finalized_success()
+        .await
+        .map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: format!("Refund claim transaction failed: {}", e),
+                }),
+            )
+        })?;
+
+    Ok(Json(AuctionResponse {
+        tx_hash: format!("{:?}", events.extrinsic_hash()),
+    }))
+}
+
+// Claim back every bid that no longer competes, up to the per-call limit
+pub async fn claim_all_refunds(
+    State(state): State<AppState>,
+) -> Result<Json<AuctionResponse>, (StatusCode, Json<ErrorResponse>)> {
+    let keypair = get_keypair_from_keyring(&"alice").map_err(|e| {
+        (
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse {
+                error: e.to_string(),
+            }),
+        )
+    })?;
+
+    let claim_tx = polkadot::tx().template().claim_all_refunds();
+
+    let tx_progress = state
+        .client
+        .tx()
+        .sign_and_submit_then_watch_default(&claim_tx, &keypair)
+        .await
+        .map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: format!("Failed to submit refund claims: {}", e),
+                }),
+            )
+        })?;
+
+    let events = tx_progress
+        .wait_for_finalized_success()
+        .await
+        .map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: format!("Refund claims transaction failed: {}", e),
+                }),
+            )
+        })?;
+
+    Ok(Json(AuctionResponse {
+        tx_hash: format!("{:?}", events.extrinsic_hash()),
+    }))
+}
+
+// Dispute an auction sale while its proceeds are in escrow
+pub async fn open_dispute(
+    State(state): State<AppState>,
+    Path((collection_id, item_id)): Path<(u32, u32)>,
+) -> Result<Json<AuctionResponse>, (StatusCode, Json<ErrorResponse>)> {
+    let keypair = get_keypair_from_keyring(&"alice").map_err(|e| {
+        (
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse {
+                error: e.to_string(),
+            }),
+        )
+    })?;
+
+    let dispute_tx = polkadot::tx().template().open_dispute(collection_id, item_id);
+
+    let tx_progress = state
+        .client
+        .tx()
+        .sign_and_submit_then_watch_default(&dispute_tx, &keypair)
+        .await
+        .map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: format!("Failed to submit dispute: {}", e),
+                }),
+            )
+        })?;
+
+    let events = tx_progress
+        .wait_for_finalized_success()
+        .await
+        .map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: format!("Dispute transaction failed: {}", e),
+                }),
+            )
+        })?;
+
+    Ok(Json(AuctionResponse {
+        tx_hash: format!("{:?}", events.extrinsic_hash()),
+    }))
+}
+
+// Release undisputed escrowed proceeds to the seller
+pub async fn release_escrow(
+    State(state): State<AppState>,
+    Path((collection_id, item_id)): Path<(u32, u32)>,
+) -> Result<Json<AuctionResponse>, (StatusCode, Json<ErrorResponse>)> {
+    let keypair = get_keypair_from_keyring(&"alice").map_err(|e| {
+        (
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse {
+                error: e.to_string(),
+            }),
+        )
+    })?;
+
+    let release_tx = polkadot::tx().template().release_escrow(collection_id, item_id);
+
+    let tx_progress = state
+        .client
+        .tx()
+        .sign_and_submit_then_watch_default(&release_tx, &keypair)
+        .await
+        .map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: format!("Failed to submit escrow release: {}", e),
+                }),
+            )
+        })?;
+
+    let events = tx_progress
+        .wait_for_finalized_success()
+        .await
+        .map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: format!("Escrow release transaction failed: {}", e),
+                }),
+            )
+        })?;
+
+    Ok(Json(AuctionResponse {
+        tx_hash: format!("{:?}", events.extrinsic_hash()),
+    }))
+}
+
+// List NFT for sale at a fixed price
+pub async fn list_nft_for_sale(
+    State(state): State<AppState>,
+    Json(payload): Json<ListNftForSaleRequest>,
+) -> Result<Json<AuctionResponse>, (StatusCode, Json<ErrorResponse>)> {
+    let keypair = get_keypair_from_keyring(&"alice").map_err(|e| {
+        (
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse {
+                error: e.to_string(),
+            }),
+        )
+    })?;
+
+    let list_tx = polkadot::tx().template().list_nft_for_sale(
+        payload.collection_id,
+        payload.item_id,
+        payload.price,
+        settlement_asset(payload.asset_id),
+    );
+
+    let tx_progress = state
+        .client
+        .tx()
+        .sign_and_submit_then_watch_default(&list_tx, &keypair)
+        .await
+        .map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: format!("Failed to submit listing: {}", e),
+                }),
+            )
+        })?;
+
+    let events = tx_progress
+        .wait_for_finalized_success()
+        .await
+        .map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: format!("Listing transaction failed: {}", e),
+                }),
+            )
+        })?;
+
+    Ok(Json(AuctionResponse {
+        tx_hash: format!("{:?}", events.extrinsic_hash()),
+    }))
+}
+
+// Buy NFT at its listed price
+pub async fn buy_nft(
+    State(state): State<AppState>,
+    Path((collection_id, item_id)): Path<(u32, u32)>,
+) -> Result<Json<AuctionResponse>, (StatusCode, Json<ErrorResponse>)> {
+    let keypair = get_keypair_from_keyring(&"alice").map_err(|e| {
+        (
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse {
+                error: e.to_string(),
+            }),
+        )
+    })?;
+
+    let buy_tx = polkadot::tx().template().buy_nft(collection_id, item_id);
+
+    let tx_progress = state
+        .client
+        .tx()
+        .sign_and_submit_then_watch_default(&buy_tx, &keypair)
+        .await
+        .map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: format!("Failed to submit purchase: {}", e),
+                }),
+            )
+        })?;
+
+    let events = tx_progress
+        .wait_for_finalized_success()
+        .await
+        .map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: format!("Purchase transaction failed: {}", e),
+                }),
+            )
+        })?;
+
+    Ok(Json(AuctionResponse {
+        tx_hash: format!("{:?}", events.extrinsic_hash()),
+    }))
+}
+
+// Set the royalty terms of an owned collection
+pub async fn set_collection_royalty(
+    State(state): State<AppState>,
+    Json(payload): Json<SetCollectionRoyaltyRequest>,
+) -> Result<Json<AuctionResponse>, (StatusCode, Json<ErrorResponse>)> {
+    let keypair = get_keypair_from_keyring(&"alice").map_err(|e| {
+        (
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse {
+                error: e.to_string(),
+            }),
+        )
+    })?;
+
+    let recipients = payload
+        .recipients
+        .iter()
+        .map(|recipient| {
+            AccountId32::from_str(&recipient.account).map(|account| (account, recipient.weight))
+        })
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|_| {
+            (
+                StatusCode::BAD_REQUEST,
+                Json(ErrorResponse {
+                    error: "Invalid account address".to_string(),
+                }),
+            )
+        })?;
+
+    let royalty_tx = polkadot::tx().template().set_collection_royalty(
+        payload.collection_id,
+        polkadot::runtime_types::sp_arithmetic::per_things::Perbill(payload.rate_ppb),
+        polkadot::runtime_types::bounded_collections::bounded_vec::BoundedVec(recipients),
+    );
+
+    let tx_progress = state
+        .client
+        .tx()
+        .sign_and_submit_then_watch_default(&royalty_tx, &keypair)
+        .await
+        .map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: format!("Failed to submit royalty terms: {}", e),
+                }),
+            )
+        })?;
+
+    let events = tx_progress
+        .wait_for_finalized_success()
+        .await
+        .map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: format!("Royalty transaction failed: {}", e),
+                }),
+            )
+        })?;
+
+    Ok(Json(AuctionResponse {
+        tx_hash: format!("{:?}", events.extrinsic_hash()),
+    }))
+}
+
+// Make an offer on an NFT
+pub async fn make_offer(
+    State(state): State<AppState>,
+    Json(payload): Json<MakeOfferRequest>,
+) -> Result<Json<AuctionResponse>, (StatusCode, Json<ErrorResponse>)> {
+    let keypair = get_keypair_from_keyring(&"alice").map_err(|e| {
+        (
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse {
+                error: e.to_string(),
+            }),
+        )
+    })?;
+
+    let offer_tx = polkadot::tx().template().make_offer(
+        payload.collection_id,
+        payload.item_id,
+        payload.amount,
+        payload.expires_at,
+    );
+
+    let tx_progress = state
+        .client
+        .tx()
+        .sign_and_submit_then_watch_default(&offer_tx, &keypair)
+        .await
+        .map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: format!("Failed to submit offer: {}", e),
+                }),
+            )
+        })?;
+
+    let events = tx_progress
+        .wait_for_finalized_success()
+        .await
+        .map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: format!("Offer transaction failed: {}", e),
+                }),
+            )
+        })?;
+
+    Ok(Json(AuctionResponse {
+        tx_hash: format!("{:?}", events.extrinsic_hash()),
+    }))
+}
+
+// Accept an offer on an owned NFT
+pub async fn accept_offer(
+    State(state): State<AppState>,
+    Json(payload): Json<AcceptOfferRequest>,
+) -> Result<Json<AuctionResponse>, (StatusCode, Json<ErrorResponse>)> {
+    let keypair = get_keypair_from_keyring(&"alice").map_err(|e| {
+        (
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse {
+                error: e.to_string(),
+            }),
+        )
+    })?;
+
+    let buyer = AccountId32::from_str(&payload.buyer).map_err(|_| {
+        (
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse {
+                error: "Invalid account address".to_string(),
+            }),
+        )
+    })?;
+
+    let accept_tx = polkadot::tx().template().accept_offer(
+        payload.collection_id,
+        payload.item_id,
+        buyer,
+    );
+
+    let tx_progress = state
+        .client
+        .tx()
+        .sign_and_submit_then_watch_default(&accept_tx, &keypair)
+        .await
+        .map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: format!("Failed to accept offer: {}", e),
+                }),
+            )
+        })?;
+
+    let events = tx_progress
+        .wait_for_finalized_success()
+        .await
+        .map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: format!("Accept offer transaction failed: {}", e),
+                }),
+            )
+        })?;
+
+    Ok(Json(AuctionResponse {
+        tx_hash: format!("{:?}", events.extrinsic_hash()),
+    }))
+}
+
+// Get auction info (query storage)
+pub async fn get_auction_info(
+    State(state): State<AppState>,
+    Path((collection_id, item_id)): Path<(u32, u32)>,
+) -> Result<Json<Option<AuctionInfo>>, (StatusCode, Json<ErrorResponse>)> {
+    let storage_query = polkadot::storage().template().auctions_iter();
+
+    let mut auctions = Vec::new();
+
+    // Needed to work out how many blocks each auction has left
+    let current_block = state
+        .client
+        .blocks()
+        .at_latest()
+        .await
+        .map_err(|e| {
+            println!("[ERROR] Failed to get latest block: {:?}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: format!("Failed to query latest block: {}", e),
+                }),
+            )
+        })?
+        .number();
+
+    let mut iter = state
+        .client
+        .storage()
+        .at_latest()
+        .await
+        .map_err(|e| {
+            println!("[ERROR] Failed to get latest block: {:?}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: format!("Failed to query storage: {}", e),
+                }),
+            )
+        })?
+        .iter(storage_query)
+        .await
+        .map_err(|e| {
+            println!("[ERROR] Failed to create storage iterator: {:?}", e);
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: format!("Failed to iterate storage: {}", e),
+                }),
+            )
+        })?;
+
+    let mut count = 0;
+    while let Some(result) = iter.next().await {
+        match result {
+            Ok(kv_pair) => {
+                count += 1;
+                let key_bytes = kv_pair.key_bytes;
+                let auction_info = kv_pair.value;
+
+                println!("[INFO] Processing auction #{}", count);
+                println!("[DEBUG] Raw key: 0x{}", hex::encode(key_bytes.clone()));
+
+                match decode_auction_key(&key_bytes) {
+                    Ok((collection_id, item_id)) => {
+                        let auction_with_key = AuctionWithKey {
+                            collection_id,
+                            item_id,
+                            auction_info: AuctionInfo {
+                                owner: auction_info.owner.to_string(),
                                 start_block: auction_info.start_block as u64,
+                                end_block: auction_info.end_block as u64,
+                                remaining_blocks: auction_info
+                                    .end_block
+                                    .saturating_sub(current_block)
+                                    as u64,
                                 highest_bid: auction_info.highest_bid,
                                 highest_bidder: auction_info.highest_bidder.map(|h| h.to_string()),
                                 ended: auction_info.ended,
@@ -851,20 +1837,23 @@ fn decode_auction_key(key: &[u8]) -> Result<(u32, u32), &'static str> {
 }
 
 // Admin functions (require root/sudo)
-pub async fn set_fee_percentage(
+// The fee is given in parts per million, 25000 being 2.5%
+pub async fn set_market_fee(
     State(state): State<AppState>,
-    Path(fee): Path<u8>,
+    Path(fee): Path<u32>,
 ) -> Result<Json<AuctionResponse>, (StatusCode, Json<ErrorResponse>)> {
-    if fee > 100 {
+    if fee > 1_000_000 {
         return Err((
             StatusCode::BAD_REQUEST,
             Json(ErrorResponse {
-                error: "Fee percentage cannot exceed 100%".to_string(),
+                error: "Fee cannot exceed 100%".to_string(),
             }),
         ));
     }
 
-    let set_fee_tx = polkadot::tx().template().set_fee_percentage(fee);
+    let set_fee_tx = polkadot::tx()
+        .template()
+        .set_market_fee(polkadot::runtime_types::sp_arithmetic::per_things::Permill(fee));
 
     let tx_progress = state
         .client
@@ -897,9 +1886,100 @@ pub async fn set_fee_percentage(
     }))
 }
 
+pub async fn set_market_params(
+    State(state): State<AppState>,
+    Json(payload): Json<MarketParamsRequest>,
+) -> Result<Json<AuctionResponse>, (StatusCode, Json<ErrorResponse>)> {
+    let params_tx = polkadot::tx().template().set_market_params(
+        polkadot::runtime_types::pallet_template::pallet::MarketParameters {
+            max_bids_per_auction: payload.max_bids_per_auction,
+            auction_timeout: payload.auction_timeout,
+            max_batch_listing_size: payload.max_batch_listing_size,
+        },
+    );
+
+    let tx_progress = state
+        .client
+        .tx()
+        .sign_and_submit_then_watch_default(&params_tx, &state.wallet_keypair)
+        .await
+        .map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: format!("Failed to set market params: {}", e),
+                }),
+            )
+        })?;
+
+    let events = tx_progress
+        .wait_for_finalized_success()
+        .await
+        .map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: format!("Set market params transaction failed: {}", e),
+                }),
+            )
+        })?;
+
+    Ok(Json(AuctionResponse {
+        tx_hash: format!("{:?}", events.extrinsic_hash()),
+    }))
+}
+
+pub async fn set_fee_distribution(
+    State(state): State<AppState>,
+    Json(payload): Json<FeeDistributionRequest>,
+) -> Result<Json<AuctionResponse>, (StatusCode, Json<ErrorResponse>)> {
+    use polkadot::runtime_types::sp_arithmetic::per_things::Permill;
+
+    let policy = payload.shares.map(|shares| {
+        polkadot::runtime_types::pallet_template::pallet::FeeDistribution {
+            treasury: Permill(shares.treasury),
+            burn: Permill(shares.burn),
+            rewards: Permill(shares.rewards),
+            period: shares.period,
+        }
+    });
+    let policy_tx = polkadot::tx().template().set_fee_distribution(policy);
+
+    let tx_progress = state
+        .client
+        .tx()
+        .sign_and_submit_then_watch_default(&policy_tx, &state.wallet_keypair)
+        .await
+        .map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: format!("Failed to set fee distribution: {}", e),
+                }),
+            )
+        })?;
+
+    let events = tx_progress
+        .wait_for_finalized_success()
+        .await
+        .map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: format!("Set fee distribution transaction failed: {}", e),
+                }),
+            )
+        })?;
+
+    Ok(Json(AuctionResponse {
+        tx_hash: format!("{:?}", events.extrinsic_hash()),
+    }))
+}
+
+// Moves the accumulated fees into the treasury, spending them takes a council motion
 pub async fn withdraw_fees(
     State(state): State<AppState>,
-    Path(to): Path<String>,
+    Query(query): Query<AssetQuery>,
 ) -> Result<Json<AuctionResponse>, (StatusCode, Json<ErrorResponse>)> {
     let keypair = get_keypair_from_keyring(&"alice").map_err(|e| {
         (
@@ -910,27 +1990,103 @@ pub async fn withdraw_fees(
         )
     })?;
 
-    let to_account = AccountId32::from_str(&to).map_err(|_| {
+    let withdraw_tx = polkadot::tx()
+        .template()
+        .withdraw_fees(settlement_asset(query.asset_id));
+
+    let tx_progress = state
+        .client
+        .tx()
+        .sign_and_submit_then_watch_default(&withdraw_tx, &keypair)
+        .await
+        .map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: format!("Failed to withdraw fees: {}", e),
+                }),
+            )
+        })?;
+
+    let events = tx_progress
+        .wait_for_finalized_success()
+        .await
+        .map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: format!("Withdraw transaction failed: {}", e),
+                }),
+            )
+        })?;
+
+    Ok(Json(AuctionResponse {
+        tx_hash: format!("{:?}", events.extrinsic_hash()),
+    }))
+}
+
+// Upper bounds on the weight and length of the call of a council motion being closed
+const MOTION_WEIGHT_BOUND: u64 = 100_000_000_000;
+const MOTION_PROOF_BOUND: u64 = 1_000_000;
+const MOTION_LENGTH_BOUND: u32 = 10_000;
+
+fn parse_proposal_hash(
+    hash: &str,
+) -> Result<subxt::utils::H256, (StatusCode, Json<ErrorResponse>)> {
+    subxt::utils::H256::from_str(hash).map_err(|_| {
         (
             StatusCode::BAD_REQUEST,
             Json(ErrorResponse {
-                error: "Invalid account address".to_string(),
+                error: "Invalid proposal hash".to_string(),
+            }),
+        )
+    })
+}
+
+// Submits a council motion to spend from the treasury, signed by the API wallet
+pub async fn propose_treasury_spend(
+    State(state): State<AppState>,
+    Json(payload): Json<ProposeSpendRequest>,
+) -> Result<Json<AuctionResponse>, (StatusCode, Json<ErrorResponse>)> {
+    use polkadot::runtime_types::frame_support::traits::tokens::fungible::union_of::NativeOrWithId;
+    use subxt::ext::codec::Encode;
+
+    let beneficiary = AccountId32::from_str(&payload.beneficiary).map_err(|_| {
+        (
+            StatusCode::BAD_REQUEST,
+            Json(ErrorResponse {
+                error: "Invalid beneficiary address".to_string(),
             }),
         )
     })?;
+    let asset_kind = match payload.asset_id {
+        Some(id) => NativeOrWithId::WithId(id),
+        None => NativeOrWithId::Native,
+    };
 
-    let withdraw_tx = polkadot::tx().template().withdraw_fees(to_account);
+    let spend = polkadot::runtime_types::solochain_template_runtime::RuntimeCall::Treasury(
+        polkadot::runtime_types::pallet_treasury::pallet::Call::spend {
+            asset_kind: Box::new(asset_kind),
+            amount: payload.amount,
+            beneficiary: Box::new(beneficiary),
+            valid_from: None,
+        },
+    );
+    let length_bound = spend.encoded_size() as u32;
+    let propose_tx = polkadot::tx()
+        .council()
+        .propose(payload.threshold, spend, length_bound);
 
     let tx_progress = state
         .client
         .tx()
-        .sign_and_submit_then_watch_default(&withdraw_tx, &keypair)
+        .sign_and_submit_then_watch_default(&propose_tx, &state.wallet_keypair)
         .await
         .map_err(|e| {
             (
                 StatusCode::INTERNAL_SERVER_ERROR,
                 Json(ErrorResponse {
-                    error: format!("Failed to withdraw fees: {}", e),
+                    error: format!("Failed to propose spend: {}", e),
                 }),
             )
         })?;
@@ -942,7 +2098,149 @@ pub async fn withdraw_fees(
             (
                 StatusCode::INTERNAL_SERVER_ERROR,
                 Json(ErrorResponse {
-                    error: format!("Withdraw transaction failed: {}", e),
+                    error: format!("Propose spend transaction failed: {}", e),
+                }),
+            )
+        })?;
+
+    Ok(Json(AuctionResponse {
+        tx_hash: format!("{:?}", events.extrinsic_hash()),
+    }))
+}
+
+// Submits a council motion for an administrative call, signed by the API wallet
+pub async fn propose_motion(
+    State(state): State<AppState>,
+    Json(payload): Json<ProposeMotionRequest>,
+) -> Result<Json<AuctionResponse>, (StatusCode, Json<ErrorResponse>)> {
+    use polkadot::runtime_types::solochain_template_runtime::RuntimeCall;
+    use subxt::ext::codec::Decode;
+
+    let call = hex::decode(payload.call.trim_start_matches("0x"))
+        .ok()
+        .and_then(|bytes| RuntimeCall::decode(&mut &bytes[..]).ok())
+        .ok_or_else(|| {
+            (
+                StatusCode::BAD_REQUEST,
+                Json(ErrorResponse {
+                    error: "Invalid call data".to_string(),
+                }),
+            )
+        })?;
+    let length_bound = (payload.call.trim_start_matches("0x").len() / 2) as u32;
+    let propose_tx = polkadot::tx()
+        .council()
+        .propose(payload.threshold, call, length_bound);
+
+    let tx_progress = state
+        .client
+        .tx()
+        .sign_and_submit_then_watch_default(&propose_tx, &state.wallet_keypair)
+        .await
+        .map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: format!("Failed to propose motion: {}", e),
+                }),
+            )
+        })?;
+
+    let events = tx_progress
+        .wait_for_finalized_success()
+        .await
+        .map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: format!("Propose motion transaction failed: {}", e),
+                }),
+            )
+        })?;
+
+    Ok(Json(AuctionResponse {
+        tx_hash: format!("{:?}", events.extrinsic_hash()),
+    }))
+}
+
+pub async fn vote_on_motion(
+    State(state): State<AppState>,
+    Json(payload): Json<CouncilVoteRequest>,
+) -> Result<Json<AuctionResponse>, (StatusCode, Json<ErrorResponse>)> {
+    let proposal_hash = parse_proposal_hash(&payload.proposal_hash)?;
+    let vote_tx = polkadot::tx()
+        .council()
+        .vote(proposal_hash, payload.index, payload.approve);
+
+    let tx_progress = state
+        .client
+        .tx()
+        .sign_and_submit_then_watch_default(&vote_tx, &state.wallet_keypair)
+        .await
+        .map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: format!("Failed to vote: {}", e),
+                }),
+            )
+        })?;
+
+    let events = tx_progress
+        .wait_for_finalized_success()
+        .await
+        .map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: format!("Vote transaction failed: {}", e),
+                }),
+            )
+        })?;
+
+    Ok(Json(AuctionResponse {
+        tx_hash: format!("{:?}", events.extrinsic_hash()),
+    }))
+}
+
+// Closes a motion once it has enough votes or its voting period is over
+pub async fn close_motion(
+    State(state): State<AppState>,
+    Json(payload): Json<CloseMotionRequest>,
+) -> Result<Json<AuctionResponse>, (StatusCode, Json<ErrorResponse>)> {
+    let proposal_hash = parse_proposal_hash(&payload.proposal_hash)?;
+    let close_tx = polkadot::tx().council().close(
+        proposal_hash,
+        payload.index,
+        polkadot::runtime_types::sp_weights::weight_v2::Weight {
+            ref_time: MOTION_WEIGHT_BOUND,
+            proof_size: MOTION_PROOF_BOUND,
+        },
+        MOTION_LENGTH_BOUND,
+    );
+
+    let tx_progress = state
+        .client
+        .tx()
+        .sign_and_submit_then_watch_default(&close_tx, &state.wallet_keypair)
+        .await
+        .map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: format!("Failed to close motion: {}", e),
+                }),
+            )
+        })?;
+
+    let events = tx_progress
+        .wait_for_finalized_success()
+        .await
+        .map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(ErrorResponse {
+                    error: format!("Close motion transaction failed: {}", e),
                 }),
             )
         })?;
@@ -971,18 +2269,57 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .route("/api/balance/{account}", get(get_balance))
         .route("/api/block/latest", get(get_latest_block))
         .route("/api/auction/list", post(list_nft_for_auction))
+        .route("/api/auction/list-dutch", post(list_nft_for_dutch_auction))
+        .route("/api/auction/list-bundle", post(list_bundle_for_auction))
         .route("/api/auction/bid", post(place_bid))
+        .route("/api/auction/max-bid", post(place_max_bid))
         .route(
             "/api/auction/resolve/{collection_id}/{item_id}",
             post(resolve_auction),
         )
+        .route(
+            "/api/auction/buy-now/{collection_id}/{item_id}",
+            post(buy_now),
+        )
+        .route(
+            "/api/auction/cancel/{collection_id}/{item_id}",
+            post(cancel_auction),
+        )
+        .route(
+            "/api/auction/retract-bid/{collection_id}/{item_id}",
+            post(retract_bid),
+        )
+        .route(
+            "/api/auction/claim-refund/{collection_id}/{item_id}",
+            post(claim_refund),
+        )
+        .route("/api/auction/claim-all-refunds", post(claim_all_refunds))
+        .route(
+            "/api/escrow/dispute/{collection_id}/{item_id}",
+            post(open_dispute),
+        )
+        .route(
+            "/api/escrow/release/{collection_id}/{item_id}",
+            post(release_escrow),
+        )
+        .route("/api/sale/list", post(list_nft_for_sale))
+        .route("/api/sale/buy/{collection_id}/{item_id}", post(buy_nft))
+        .route("/api/collection/royalty", post(set_collection_royalty))
+        .route("/api/offer/make", post(make_offer))
+        .route("/api/offer/accept", post(accept_offer))
         .route(
             "/api/auction/info/{collection_id}/{item_id}",
             get(get_auction_info),
         )
         // .route(path("/api/auction/{collection_id}/{item_id}"), get(get_all_auctions(state.clone())))
-        .route("/api/auction/set-fee/{fee}", post(set_fee_percentage))
-        .route("/api/auction/withdraw-fees/{to}", post(withdraw_fees))
+        .route("/api/auction/set-fee/{fee}", post(set_market_fee))
+        .route("/api/auction/market-params", post(set_market_params))
+        .route("/api/auction/fee-distribution", post(set_fee_distribution))
+        .route("/api/auction/withdraw-fees", post(withdraw_fees))
+        .route("/api/treasury/propose-spend", post(propose_treasury_spend))
+        .route("/api/council/propose", post(propose_motion))
+        .route("/api/council/vote", post(vote_on_motion))
+        .route("/api/council/close", post(close_motion))
         .layer(CorsLayer::permissive())
         .with_state(app_state);
 